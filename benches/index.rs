@@ -0,0 +1,43 @@
+#![feature(test)]
+
+extern crate test;
+
+use std::path::PathBuf;
+
+use futures::executor::block_on;
+use memfs::{
+    directory::{
+        Get,
+        GetExt,
+        GetType,
+    },
+    file_system::Options,
+    FileSystem,
+};
+use test::Bencher;
+
+const DEPTH: usize = 64;
+
+fn deep(options: Options<u32>) -> (FileSystem<(), u32>, PathBuf) {
+    let fs = FileSystem::with_options(options);
+    let path = (0..DEPTH).fold(PathBuf::from("/"), |path, i| path.join(format!("dir-{i}")));
+    let file = path.join("file");
+
+    block_on(fs.get_file_default(&file)).expect("deep file is created");
+
+    (fs, file)
+}
+
+#[bench]
+fn get_deep(b: &mut Bencher) {
+    let (fs, path) = deep(Options::default());
+
+    b.iter(|| block_on(fs.get(&path, GetType::File)));
+}
+
+#[bench]
+fn lookup_deep_indexed(b: &mut Bencher) {
+    let (fs, path) = deep(Options::default().index(true));
+
+    b.iter(|| block_on(fs.lookup(&path)));
+}
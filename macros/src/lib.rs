@@ -0,0 +1,101 @@
+#![deny(
+    future_incompatible,
+    missing_copy_implementations,
+    missing_debug_implementations,
+    nonstandard_style,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![deny(
+    rust_2018_compatibility,
+    rust_2018_idioms
+)]
+#![deny(
+    clippy::cargo,
+    clippy::nursery,
+    clippy::pedantic
+)]
+#![allow(clippy::multiple_crate_versions)]
+
+use std::{
+    env,
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input,
+    Error,
+    LitStr,
+};
+
+// Macros
+
+/// Embeds the directory at the given path (relative to the crate manifest) at
+/// compile time, expanding to an `async` block which resolves to a
+/// `Result<FileSystem<(), &'static [u8]>, memfs::Error>`.
+#[proc_macro]
+pub fn embed_dir(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    let root = env::var_os("CARGO_MANIFEST_DIR")
+        .map_or_else(PathBuf::new, PathBuf::from)
+        .join(path.value());
+
+    match entries(&root) {
+        Ok(entries) => quote! {
+            async {
+                let fs: ::core::result::Result<
+                    ::memfs::FileSystem<(), &'static [u8]>,
+                    ::memfs::Error,
+                > = ::memfs::memfs! { #entries }.await;
+
+                fs
+            }
+        }
+        .into(),
+        Err(err) => Error::new(path.span(), format!("embed_dir!: {}: {err}", root.display()))
+            .to_compile_error()
+            .into(),
+    }
+}
+
+// Functions
+
+fn entries(dir: &Path) -> io::Result<TokenStream2> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    paths.sort();
+
+    #[allow(clippy::match_bool)]
+    let entries = paths
+        .iter()
+        .map(|path| {
+            let name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "name is not UTF-8"))?;
+
+            match fs::metadata(path)?.is_dir() {
+                true => entries(path).map(|children| quote! { #name => { #children } }),
+                _ => path
+                    .to_str()
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "path is not UTF-8"))
+                    .map(|path| {
+                        quote! { #name => file(::core::include_bytes!(#path) as &'static [u8]) }
+                    }),
+            }
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    Ok(quote! { #(#entries),* })
+}
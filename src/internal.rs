@@ -1,4 +1,23 @@
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "tokio")]
+pub mod compat;
+pub mod diff;
 pub mod directory;
+pub mod error;
 pub mod file;
 pub mod file_system;
+pub mod fs;
+#[cfg(all(feature = "fuse", target_os = "linux"))]
+pub mod fuse;
+#[cfg(feature = "inodes")]
+pub mod inodes;
+#[cfg(feature = "local")]
+pub mod local;
+mod macros;
 pub mod node;
+pub mod path;
+#[cfg(feature = "tower")]
+pub mod service;
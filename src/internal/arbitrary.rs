@@ -0,0 +1,136 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::PathBuf,
+};
+
+use futures::executor::block_on;
+use proptest::{
+    collection::btree_map,
+    prelude::*,
+    sample::select,
+};
+
+use super::{
+    directory::get_ext::GetExt,
+    file_system::FileSystem,
+    node::{
+        data::ValueType,
+        data_ext::DataExt,
+    },
+};
+
+// Tree
+
+#[derive(Clone, Debug)]
+pub enum Tree<F> {
+    Directory(BTreeMap<String, Self>),
+    File(F),
+}
+
+// Tree - Methods
+
+impl<F> Tree<F>
+where
+    F: ValueType + Clone,
+{
+    #[must_use]
+    pub fn build<D>(&self) -> FileSystem<D, F>
+    where
+        D: ValueType + Default,
+        F: Default,
+    {
+        let fs = FileSystem::new();
+        let mut pending = vec![(PathBuf::from("/"), self)];
+
+        block_on(async {
+            while let Some((path, tree)) = pending.pop() {
+                match tree {
+                    Self::Directory(children) => {
+                        let _ = fs.get_dir_default(&path).await;
+
+                        pending.extend(children.iter().map(|(name, tree)| (path.join(name), tree)));
+                    }
+                    Self::File(value) => {
+                        if let Ok(file) = fs.get_file_default(&path).await {
+                            let _ = file.write(|mut current| *current = value.clone()).await;
+                        }
+                    }
+                }
+            }
+        });
+
+        fs
+    }
+
+    #[must_use]
+    pub fn paths(&self) -> Vec<PathBuf> {
+        let mut paths = Vec::new();
+        let mut pending = vec![(PathBuf::from("/"), self)];
+
+        while let Some((path, tree)) = pending.pop() {
+            if let Self::Directory(children) = tree {
+                pending.extend(children.iter().map(|(name, tree)| (path.join(name), tree)));
+            }
+
+            paths.push(path);
+        }
+
+        paths.sort();
+        paths
+    }
+}
+
+// Strategies
+
+pub fn tree<F, S>(values: S, depth: u32, width: usize) -> impl Strategy<Value = Tree<F>>
+where
+    F: fmt::Debug + Clone + 'static,
+    S: Strategy<Value = F> + 'static,
+{
+    let size = u32::try_from(width.saturating_pow(depth)).unwrap_or(u32::MAX);
+    let width_u32 = u32::try_from(width).unwrap_or(u32::MAX);
+
+    values
+        .prop_map(Tree::File)
+        .prop_recursive(depth, size, width_u32, move |inner| {
+            btree_map(name(), inner, 0..=width).prop_map(Tree::Directory)
+        })
+        .prop_flat_map(move |tree| match tree {
+            Tree::Directory(_) => Just(tree).boxed(),
+            Tree::File(_) => btree_map(name(), Just(tree), 0..=width)
+                .prop_map(Tree::Directory)
+                .boxed(),
+        })
+}
+
+pub fn file_system<D, F, S>(
+    values: S,
+    depth: u32,
+    width: usize,
+) -> impl Strategy<Value = FileSystem<D, F>>
+where
+    D: ValueType + Default + fmt::Debug,
+    F: ValueType + Default + fmt::Debug + Clone + 'static,
+    S: Strategy<Value = F> + 'static,
+{
+    tree(values, depth, width).prop_map(|tree| tree.build())
+}
+
+pub fn valid_path<F>(tree: &Tree<F>) -> impl Strategy<Value = PathBuf>
+where
+    F: ValueType + Clone,
+{
+    select(tree.paths())
+}
+
+pub fn invalid_path<F>(tree: &Tree<F>) -> impl Strategy<Value = PathBuf>
+where
+    F: ValueType + Clone,
+{
+    (select(tree.paths()), "[A-Z]{1,8}").prop_map(|(path, name)| path.join(name))
+}
+
+fn name() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9_]{0,7}"
+}
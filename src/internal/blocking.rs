@@ -0,0 +1,306 @@
+use std::{
+    ffi::OsString,
+    io,
+    ops::Deref,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use async_lock::{
+    RwLockReadGuard,
+    RwLockWriteGuard,
+};
+use futures::executor::block_on;
+
+use super::{
+    directory::{
+        count::Count,
+        get_ext::{
+            GetDirectoryError,
+            GetExt,
+            GetFileError,
+        },
+    },
+    node::{
+        child::Child,
+        data::ValueType,
+        data_ext::{
+            DataExt,
+            WriteError,
+        },
+        located::Located,
+        named::Named,
+        permissions::Permissions,
+        root::Root,
+    },
+};
+
+// FileSystem
+
+#[derive(Debug)]
+pub struct FileSystem<D, F>(Directory<D, F>)
+where
+    D: ValueType,
+    F: ValueType;
+
+// FileSystem - Standard Traits
+
+impl<D, F> Default for FileSystem<D, F>
+where
+    D: ValueType + Default,
+    F: ValueType,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D, F> Deref for FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    type Target = Directory<D, F>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<D, F> From<super::file_system::FileSystem<D, F>> for FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn from(fs: super::file_system::FileSystem<D, F>) -> Self {
+        Self(Directory(fs.0))
+    }
+}
+
+// FileSystem - Methods
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + Default,
+    F: ValueType,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from(super::file_system::FileSystem::new())
+    }
+}
+
+// Directory
+
+#[derive(Debug)]
+pub struct Directory<D, F>(super::directory::Directory<D, F>)
+where
+    D: ValueType,
+    F: ValueType;
+
+// Directory - Standard Traits
+
+impl<D, F> Clone for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<D, F> From<super::directory::Directory<D, F>> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn from(dir: super::directory::Directory<D, F>) -> Self {
+        Self(dir)
+    }
+}
+
+// Directory - Methods
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub fn count(&self) -> io::Result<usize> {
+        block_on(self.0.count())
+    }
+
+    pub fn count_dir(&self) -> io::Result<usize> {
+        block_on(self.0.count_dir())
+    }
+
+    pub fn count_file(&self) -> io::Result<usize> {
+        block_on(self.0.count_file())
+    }
+
+    pub fn get_dir<P>(&self, path: P) -> Result<Option<Self>, GetDirectoryError>
+    where
+        P: AsRef<Path> + Send,
+    {
+        block_on(self.0.get_dir(path)).map(|dir| dir.map(Self))
+    }
+
+    pub fn get_dir_default<P>(&self, path: P) -> Result<Self, GetDirectoryError>
+    where
+        P: AsRef<Path> + Send,
+        D: Default,
+        F: Default,
+    {
+        block_on(self.0.get_dir_default(path)).map(Self)
+    }
+
+    pub fn get_file<P>(&self, path: P) -> Result<Option<File<D, F>>, GetFileError>
+    where
+        P: AsRef<Path> + Send,
+    {
+        block_on(self.0.get_file(path)).map(|file| file.map(File))
+    }
+
+    pub fn get_file_default<P>(&self, path: P) -> Result<File<D, F>, GetFileError>
+    where
+        P: AsRef<Path> + Send,
+        D: Default,
+        F: Default,
+    {
+        block_on(self.0.get_file_default(path)).map(File)
+    }
+
+    #[must_use]
+    pub fn into_async(self) -> super::directory::Directory<D, F> {
+        self.0
+    }
+
+    #[must_use]
+    pub fn is_root(&self) -> bool {
+        block_on(self.0.is_root())
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Option<OsString> {
+        block_on(self.0.name())
+    }
+
+    #[must_use]
+    pub fn parent(&self) -> Option<Self> {
+        block_on(self.0.parent()).map(Self)
+    }
+
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        block_on(self.0.path())
+    }
+
+    #[must_use]
+    pub fn readonly(&self) -> bool {
+        block_on(self.0.readonly())
+    }
+
+    pub fn read<T, R>(&self, f: R) -> T
+    where
+        R: FnOnce(RwLockReadGuard<'_, D>) -> T + Send,
+    {
+        block_on(self.0.read(f))
+    }
+
+    pub fn set_readonly(&self, readonly: bool) {
+        block_on(self.0.set_readonly(readonly));
+    }
+
+    pub fn write<T, W>(&self, f: W) -> Result<T, WriteError>
+    where
+        T: Send,
+        W: FnOnce(RwLockWriteGuard<'_, D>) -> T + Send,
+    {
+        block_on(self.0.write(f))
+    }
+}
+
+// File
+
+#[derive(Debug)]
+pub struct File<D, F>(super::file::File<D, F>)
+where
+    D: ValueType,
+    F: ValueType;
+
+// File - Standard Traits
+
+impl<D, F> Clone for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<D, F> From<super::file::File<D, F>> for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn from(file: super::file::File<D, F>) -> Self {
+        Self(file)
+    }
+}
+
+// File - Methods
+
+impl<D, F> File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub fn into_async(self) -> super::file::File<D, F> {
+        self.0
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Option<OsString> {
+        block_on(self.0.name())
+    }
+
+    #[must_use]
+    pub fn parent(&self) -> Option<Directory<D, F>> {
+        block_on(self.0.parent()).map(Directory)
+    }
+
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        block_on(self.0.path())
+    }
+
+    #[must_use]
+    pub fn readonly(&self) -> bool {
+        block_on(self.0.readonly())
+    }
+
+    pub fn read<T, R>(&self, f: R) -> T
+    where
+        R: FnOnce(RwLockReadGuard<'_, F>) -> T + Send,
+    {
+        block_on(self.0.read(f))
+    }
+
+    pub fn set_readonly(&self, readonly: bool) {
+        block_on(self.0.set_readonly(readonly));
+    }
+
+    pub fn write<T, W>(&self, f: W) -> Result<T, WriteError>
+    where
+        T: Send,
+        W: FnOnce(RwLockWriteGuard<'_, F>) -> T + Send,
+    {
+        block_on(self.0.write(f))
+    }
+}
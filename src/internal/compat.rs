@@ -0,0 +1 @@
+pub mod tokio_fs;
@@ -0,0 +1,316 @@
+use std::{
+    collections::VecDeque,
+    ffi::OsString,
+    io::{
+        Error,
+        ErrorKind,
+        Result,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use super::super::{
+    directory::{
+        attach::Attach,
+        count::Count,
+        create::Create,
+        create_all::CreateError,
+        get::{
+            Get,
+            GetType,
+        },
+        rename::{
+            Rename,
+            RenameError,
+        },
+        Directory,
+    },
+    file_system::FileSystem,
+    fs::{
+        self,
+        from_get_directory_error,
+        from_get_error,
+        from_write_error,
+        Metadata,
+    },
+    node::{
+        child::Child,
+        data::ValueType,
+        permissions::Permissions,
+        Node,
+    },
+};
+
+// Handle
+
+#[derive(Debug)]
+pub struct Handle<D, F>(Directory<D, F>)
+where
+    D: ValueType,
+    F: ValueType;
+
+// Handle - Standard Traits
+
+impl<D, F> Clone for Handle<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+// Handle - Methods
+
+impl<D, F> Handle<D, F>
+where
+    D: ValueType + Default,
+    F: ValueType + Clone + Default + AsRef<[u8]> + From<Vec<u8>>,
+{
+    #[must_use]
+    pub fn new(fs: &FileSystem<D, F>) -> Self {
+        Self(fs.0.clone())
+    }
+
+    pub async fn copy<P, Q>(&self, from: P, to: Q) -> Result<u64>
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        let contents = self.read(from).await?;
+
+        self.write(to, &contents).await?;
+
+        Ok(contents.len() as u64)
+    }
+
+    pub async fn create_dir<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_path_buf();
+
+        match self.0.create_dir(&path, D::default()).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(from_create_error(err)),
+        }
+    }
+
+    pub async fn create_dir_all<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        fs::create_dir_all(&self.0, path.as_ref().to_path_buf()).await
+    }
+
+    pub async fn metadata<P>(&self, path: P) -> Result<Metadata>
+    where
+        P: AsRef<Path>,
+    {
+        fs::metadata(&self.0, path.as_ref().to_path_buf()).await
+    }
+
+    pub async fn read<P>(&self, path: P) -> Result<Vec<u8>>
+    where
+        P: AsRef<Path>,
+    {
+        fs::read(&self.0, path.as_ref().to_path_buf())
+            .await
+            .map(|value| value.as_ref().to_vec())
+    }
+
+    pub async fn read_dir<P>(&self, path: P) -> Result<ReadDir>
+    where
+        P: AsRef<Path>,
+    {
+        let entries = fs::read_dir(&self.0, path.as_ref().to_path_buf()).await?;
+
+        Ok(ReadDir(
+            entries
+                .into_iter()
+                .map(|entry| DirEntry {
+                    metadata: entry.metadata(),
+                    name: entry.file_name().to_os_string(),
+                    path: entry.path().to_path_buf(),
+                })
+                .collect(),
+        ))
+    }
+
+    pub async fn read_to_string<P>(&self, path: P) -> Result<String>
+    where
+        P: AsRef<Path>,
+    {
+        String::from_utf8(self.read(path).await?)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err))
+    }
+
+    pub async fn remove_dir<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        self.remove(path.as_ref(), Removal::Directory).await
+    }
+
+    pub async fn remove_dir_all<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        self.remove(path.as_ref(), Removal::DirectoryAll).await
+    }
+
+    pub async fn remove_file<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        self.remove(path.as_ref(), Removal::File).await
+    }
+
+    pub async fn rename<P, Q>(&self, from: P, to: Q) -> Result<()>
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        let from = from.as_ref().to_path_buf();
+        let to = to.as_ref().to_path_buf();
+
+        match self.0.rename(from, to).await {
+            Ok(()) => Ok(()),
+            Err(RenameError::NotFound) => Err(ErrorKind::NotFound.into()),
+            Err(RenameError::GetDirectory(err)) => Err(from_get_directory_error(err)),
+            Err(RenameError::NotEmpty) => Err(ErrorKind::DirectoryNotEmpty.into()),
+            Err(RenameError::UnexpectedFile) => Err(ErrorKind::NotADirectory.into()),
+            Err(RenameError::UnexpectedDirectory) => Err(ErrorKind::IsADirectory.into()),
+            Err(RenameError::Write(err)) => Err(from_write_error(err)),
+            Err(err @ (RenameError::Cycle | RenameError::InvalidPath)) => {
+                Err(Error::new(ErrorKind::InvalidInput, err))
+            }
+        }
+    }
+
+    pub async fn try_exists<P>(&self, path: P) -> Result<bool>
+    where
+        P: AsRef<Path>,
+    {
+        match self.metadata(path).await {
+            Ok(_) => Ok(true),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub async fn write<P, C>(&self, path: P, contents: C) -> Result<()>
+    where
+        P: AsRef<Path>,
+        C: AsRef<[u8]>,
+    {
+        let value = F::from(contents.as_ref().to_vec());
+
+        fs::write(&self.0, path.as_ref().to_path_buf(), value).await
+    }
+
+    async fn remove(&self, path: &Path, removal: Removal) -> Result<()> {
+        let name = path
+            .file_name()
+            .ok_or_else(|| Error::from(ErrorKind::InvalidInput))?;
+        let node = match self.0.get(path, GetType::default()).await {
+            Ok(Some(node)) => node,
+            Ok(None) => return Err(ErrorKind::NotFound.into()),
+            Err(err) => return Err(from_get_error(err)),
+        };
+
+        match (&node, removal) {
+            (Node::Directory(_), Removal::File) => return Err(ErrorKind::IsADirectory.into()),
+            (Node::File(_), Removal::Directory | Removal::DirectoryAll) => {
+                return Err(ErrorKind::NotADirectory.into())
+            }
+            (Node::Directory(dir), Removal::Directory) if dir.count().await? > 0 => {
+                return Err(ErrorKind::DirectoryNotEmpty.into())
+            }
+            _ => {}
+        }
+
+        let Some(parent) = node.parent().await else {
+            return Err(ErrorKind::InvalidInput.into());
+        };
+
+        if parent.readonly().await {
+            return Err(ErrorKind::PermissionDenied.into());
+        }
+
+        match parent.detach(name).await.map_err(from_write_error)? {
+            Some(_) => Ok(()),
+            _ => Err(ErrorKind::NotFound.into()),
+        }
+    }
+}
+
+// Functions
+
+fn from_create_error(err: CreateError) -> Error {
+    match err {
+        CreateError::AlreadyExists(_) => ErrorKind::AlreadyExists.into(),
+        CreateError::Get(err) => from_get_error(err),
+        CreateError::GetDirectory(err) => from_get_directory_error(err),
+        CreateError::IntermediateNotFound(_) => ErrorKind::NotFound.into(),
+        CreateError::InvalidPath(_) => ErrorKind::InvalidInput.into(),
+        CreateError::UnexpectedFile(_) => ErrorKind::NotADirectory.into(),
+        CreateError::UnexpectedDirectory(_) => ErrorKind::IsADirectory.into(),
+        CreateError::Write(err) => from_write_error(err),
+    }
+}
+
+// Removal
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Removal {
+    Directory,
+    DirectoryAll,
+    File,
+}
+
+// ReadDir
+
+#[derive(Debug)]
+pub struct ReadDir(VecDeque<DirEntry>);
+
+// ReadDir - Methods
+
+impl ReadDir {
+    #[allow(clippy::unused_async)]
+    pub async fn next_entry(&mut self) -> Result<Option<DirEntry>> {
+        Ok(self.0.pop_front())
+    }
+}
+
+// DirEntry
+
+#[derive(Debug)]
+pub struct DirEntry {
+    metadata: Metadata,
+    name: OsString,
+    path: PathBuf,
+}
+
+// DirEntry - Methods
+
+impl DirEntry {
+    #[must_use]
+    pub fn file_name(&self) -> OsString {
+        self.name.clone()
+    }
+
+    #[allow(clippy::unused_async)]
+    pub async fn metadata(&self) -> Result<Metadata> {
+        Ok(self.metadata)
+    }
+
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        self.path.clone()
+    }
+}
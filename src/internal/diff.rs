@@ -0,0 +1,206 @@
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    fmt::{
+        self,
+        Write,
+    },
+    io,
+    ops::Deref,
+    path::PathBuf,
+};
+
+use super::{
+    directory::{
+        get_ext::{
+            GetExt,
+            GetFileError,
+        },
+        Directory,
+    },
+    file::File,
+    node::{
+        data::{
+            Data,
+            ValueType,
+        },
+        Node,
+    },
+};
+
+// Diff
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Diff(pub(crate) BTreeMap<PathBuf, Change>);
+
+// Diff - Standard Traits
+
+impl Deref for Diff {
+    type Target = BTreeMap<PathBuf, Change>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (path, change) in &self.0 {
+            writeln!(f, "{} {}", change.marker(), path.display())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl IntoIterator for Diff {
+    type IntoIter = <BTreeMap<PathBuf, Change> as IntoIterator>::IntoIter;
+    type Item = (PathBuf, Change);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// Diff - Methods
+
+impl Diff {
+    pub async fn render_with<D, F, R>(
+        &self,
+        a: &Directory<D, F>,
+        b: &Directory<D, F>,
+        values: R,
+    ) -> Result<String, GetFileError>
+    where
+        D: ValueType,
+        F: ValueType,
+        R: Fn(&F, &F) -> String,
+    {
+        let mut output = String::new();
+
+        for (path, change) in &self.0 {
+            let _ = writeln!(output, "{} {}", change.marker(), path.display());
+
+            if *change != Change::DataChanged {
+                continue;
+            }
+
+            let path = path.strip_prefix("/").unwrap_or(path);
+            let (a, b) = (file(a.get_file(path).await)?, file(b.get_file(path).await)?);
+            let (Some(a), Some(b)) = (a, b) else {
+                continue;
+            };
+
+            let (a, b) = (a.data().await, b.data().await);
+            let (a, b) = (a.read().await, b.read().await);
+
+            for line in values(&a, &b).lines() {
+                let _ = writeln!(output, "    {line}");
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+// Change
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Change {
+    Added,
+    Removed,
+    TypeChanged,
+    DataChanged,
+}
+
+// Change - Methods
+
+impl Change {
+    #[must_use]
+    pub const fn marker(self) -> char {
+        match self {
+            Self::Added => '+',
+            Self::Removed => '-',
+            Self::TypeChanged => '!',
+            Self::DataChanged => '~',
+        }
+    }
+}
+
+// Diff - Functions
+
+pub async fn diff<D, F, C>(
+    a: &Directory<D, F>,
+    b: &Directory<D, F>,
+    comparator: C,
+) -> io::Result<Diff>
+where
+    D: ValueType + PartialEq,
+    F: ValueType,
+    C: Fn(&F, &F) -> bool,
+{
+    let mut changes = BTreeMap::new();
+    let mut pending = vec![(PathBuf::from("/"), a.clone(), b.clone())];
+
+    while let Some((path, a, b)) = pending.pop() {
+        let differs = {
+            let (a, b) = (a.data().await, b.data().await);
+            let (a, b) = (a.read().await, b.read().await);
+
+            *a != *b
+        };
+
+        if differs {
+            changes.insert(path.clone(), Change::DataChanged);
+        }
+
+        let a = a.entries().await?.into_iter().collect::<BTreeMap<_, _>>();
+        let b = b.entries().await?.into_iter().collect::<BTreeMap<_, _>>();
+        let names = a.keys().chain(b.keys()).collect::<BTreeSet<_>>();
+
+        for name in names {
+            let path = path.join(&**name);
+
+            match (a.get(name), b.get(name)) {
+                (Some(Node::Directory(a)), Some(Node::Directory(b))) => {
+                    pending.push((path, a.clone(), b.clone()));
+                }
+                (Some(Node::File(a)), Some(Node::File(b))) => {
+                    let (a, b) = (a.data().await, b.data().await);
+                    let (a, b) = (a.read().await, b.read().await);
+
+                    if !comparator(&a, &b) {
+                        changes.insert(path, Change::DataChanged);
+                    }
+                }
+                (Some(_), Some(_)) => {
+                    changes.insert(path, Change::TypeChanged);
+                }
+                (Some(_), None) => {
+                    changes.insert(path, Change::Removed);
+                }
+                (None, Some(_)) => {
+                    changes.insert(path, Change::Added);
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    Ok(Diff(changes))
+}
+
+fn file<D, F>(
+    result: Result<Option<File<D, F>>, GetFileError>,
+) -> Result<Option<File<D, F>>, GetFileError>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    match result {
+        Err(GetFileError::UnexpectedDirectory) => Ok(None),
+        result => result,
+    }
+}
@@ -1,34 +1,118 @@
+pub mod attach;
 pub mod count;
+pub mod create;
+pub mod create_all;
+pub mod entry;
+pub mod filter;
+pub mod find;
+pub mod fold;
+#[cfg(feature = "im")]
+pub mod freeze;
 pub mod get;
+pub mod get_clamped;
 pub mod get_ext;
+pub mod get_many;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod list;
+pub mod memory;
+pub mod merge;
+pub mod probe;
+pub mod rename;
+pub mod retain;
+pub mod render;
+pub mod scope;
+pub mod search;
+pub mod segments;
+pub mod size;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+pub mod stats;
+pub mod transform;
+pub mod tree;
+pub mod visit;
+pub mod walk;
+#[cfg(feature = "zip")]
+pub mod zip;
 
 use std::{
-    collections::HashMap,
+    collections::{
+        hash_map::RandomState,
+        BTreeMap,
+        HashMap,
+    },
+    ffi::{
+        OsStr,
+        OsString,
+    },
+    hash::BuildHasher,
+    io,
     ops::Deref,
     path::{
         Component,
         Path,
+        PathBuf,
     },
     sync::{
+        atomic::{
+            AtomicU64,
+            AtomicUsize,
+            Ordering,
+        },
         Arc,
+        RwLock as StdRwLock,
         Weak,
     },
+    time::SystemTime,
 };
 
-use async_lock::RwLock;
+use async_lock::{
+    Mutex,
+    RwLock,
+};
 use async_trait::async_trait;
 use futures::FutureExt;
+use indexmap::IndexMap;
 
 use self::{
     count::Count,
+    entry::{
+        Entry,
+        OccupiedEntry,
+        VacantEntry,
+    },
     get::{
         Get,
         GetError,
+        GetErrorKind,
         GetType,
     },
+    list::{
+        List,
+        Listed,
+    },
 };
 use super::{
-    file::File,
+    file::{
+        history::Versions,
+        File,
+    },
+    file_system::{
+        access::Action,
+        backend::Stored,
+        context::Context,
+        hooks::{
+            Event,
+            Hooks,
+        },
+        index::Index,
+        journal::{
+            Append,
+            Record,
+        },
+        options::Order,
+        undo::Operation,
+    },
     node::{
         child::Child,
         data::{
@@ -36,8 +120,39 @@ use super::{
             Value,
             ValueType,
         },
+        data_ext::WriteError,
+        digest::{
+            hash,
+            Digest,
+            Digests,
+        },
+        identified::{
+            Identified,
+            NodeId,
+        },
+        located::Located,
+        meta::{
+            Meta,
+            Metas,
+        },
         named::Named,
+        observe::{
+            Access,
+            Observe,
+        },
+        parent::Parent,
+        permissions::Permissions,
         root::Root,
+        timestamps::{
+            Times,
+            Timestamps,
+        },
+        versioned::{
+            bump,
+            next_generation,
+            Versioned,
+        },
+        xattrs::Xattrs,
         Node,
     },
 };
@@ -45,7 +160,7 @@ use super::{
 // Directory
 
 #[derive(Debug)]
-pub struct Directory<D, F>(pub(crate) Arc<RwLock<Internal<D, F>>>)
+pub struct Directory<D, F>(pub(crate) Arc<Shared<D, F>>)
 where
     D: ValueType,
     F: ValueType;
@@ -62,12 +177,29 @@ where
     }
 }
 
+impl<D, F> Eq for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+}
+
+impl<D, F> PartialEq for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+    }
+}
+
 impl<D, F> Deref for Directory<D, F>
 where
     D: ValueType,
     F: ValueType,
 {
-    type Target = Arc<RwLock<Internal<D, F>>>;
+    type Target = Arc<Shared<D, F>>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -96,16 +228,16 @@ where
     D: ValueType,
     F: ValueType,
 {
-    async fn count(&self) -> usize {
+    async fn count(&self) -> io::Result<usize> {
         self.count_predicate(|_| true).await
     }
 
-    async fn count_dir(&self) -> usize {
+    async fn count_dir(&self) -> io::Result<usize> {
         self.count_predicate(|child| matches!(child, Node::Directory(_)))
             .await
     }
 
-    async fn count_file(&self) -> usize {
+    async fn count_file(&self) -> io::Result<usize> {
         self.count_predicate(|child| matches!(child, Node::File(_)))
             .await
     }
@@ -128,313 +260,1793 @@ where
     D: ValueType,
     F: ValueType,
 {
-    async fn get<P>(&self, path: P, get_type: GetType) -> Result<Option<Node<D, F>>, GetError>
+    async fn get<P>(&self, path: P, _: GetType) -> Result<Option<Node<D, F>>, GetError>
     where
         P: AsRef<Path> + Send,
     {
-        self.get(path, GetAction::ReturnNone, get_type).await
+        self.get(path, None::<Create<Parents<D>, Init<D, F>>>).await
     }
 
     async fn get_default<P>(&self, path: P, get_type: GetType) -> Result<Node<D, F>, GetError>
     where
         P: AsRef<Path> + Send,
+        D: Default,
+        F: Default,
+    {
+        self.get_or_create_with(path, D::default, move || match get_type {
+            GetType::Directory => Stored::Directory(D::default()),
+            GetType::File => Stored::File(F::default()),
+        })
+        .await
+    }
+
+    async fn get_or_create_with<P, B, C>(
+        &self,
+        path: P,
+        parents: B,
+        create: C,
+    ) -> Result<Node<D, F>, GetError>
+    where
+        P: AsRef<Path> + Send,
+        B: Fn() -> D + Send + Sync,
+        C: FnOnce() -> Stored<D, F> + Send,
     {
-        match self.get(path, GetAction::CreateDefault, get_type).await {
+        let path = path.as_ref();
+
+        match self.get(path, Some((parents, create))).await {
             Ok(Some(node)) => Ok(node),
-            Ok(None) => Err(GetError::Other),
+            Ok(None) => Err(GetError::new(
+                GetErrorKind::Other,
+                path,
+                path.components().count().saturating_sub(1),
+            )),
             Err(err) => Err(err),
         }
     }
 }
 
 #[async_trait]
-impl<D, F> Named for Directory<D, F>
+impl<D, F> Digest for Directory<D, F>
 where
     D: ValueType,
-    F: ValueType,
+    F: ValueType + AsRef<[u8]>,
 {
-    async fn name(&self) -> Option<String> {
-        self.read()
-            .map(|this| this.parent.as_ref().map(|parent| parent.0.clone()))
-            .await
+    async fn tree_hash(&self) -> u64 {
+        let generation = self.generation().await;
+
+        if let Some(hash) = self.digests.get(generation) {
+            return hash;
+        }
+
+        let Ok(mut entries) = self.entries().await else {
+            return hash(&Vec::<()>::new());
+        };
+        let mut children = Vec::with_capacity(entries.len());
+
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        for (name, node) in entries {
+            children.push((name, node.kind(), node.tree_hash().await));
+        }
+
+        let hash = hash(&children);
+
+        self.digests.set(generation, hash);
+
+        hash
     }
 }
 
-// Directory - Methods
-
-impl<D, F> Directory<D, F>
+#[async_trait]
+impl<D, F> Identified for Directory<D, F>
 where
     D: ValueType,
     F: ValueType,
 {
-    #[must_use]
-    pub(crate) fn create(value: Option<D>, parent: Option<(String, Reference<D, F>)>) -> Self {
-        Self(Arc::new_cyclic(|weak| {
-            RwLock::new(Internal {
-                children: Children::default(),
-                parent,
-                value: Value::from_option(value),
-                weak: Reference(weak.clone()),
-            })
-        }))
-    }
-
-    #[must_use]
-    pub(crate) fn create_root() -> Self {
-        Self::create(None, None)
+    async fn id(&self) -> NodeId {
+        NodeId(self.0.id.load(Ordering::SeqCst))
     }
 }
 
-#[cfg(test)]
-mod create_tests {
-    // use super::Directory;
-
-    // #[tokio::test]
-    // async fn create_root() {
-    //     let dir: Directory<(), ()> = Directory::create_root();
-    //     // let children = dir.read(|dir| dir.children.clone()).await;
-    //     // let len = children.r
-
-    //     // assert_eq!(
-    //     //     dir.read(|dir| dir.children.clone())
-    //     //         .then(|children| children.read(|children| children.len()))
-    //     //         .await,
-    //     //     0
-    //     // );
-    //     //assert_eq!(dir.read(|dir| dir.parent.is_none()).await, true);
-    // }
-}
-
-impl<D, F> Directory<D, F>
+#[async_trait]
+impl<D, F> Meta for Directory<D, F>
 where
     D: ValueType,
     F: ValueType,
 {
-    async fn count_predicate<P>(&self, predicate: P) -> usize
+    async fn get_meta<M>(&self) -> Option<M>
     where
-        P: FnMut(&&Node<D, F>) -> bool + Send + Sync,
+        M: Clone + Send + Sync + 'static,
     {
-        self.read()
-            .then(|this| async move {
-                this.children
-                    .read()
-                    .map(|children| children.values().filter(predicate).count())
-                    .await
-            })
-            .await
+        self.read().map(|this| this.meta.get()).await
     }
-}
-
-#[cfg(test)]
-mod count_tests {
-    use super::{
-        Count,
-        Directory,
-    };
-
-    #[tokio::test]
-    async fn count_empty() {
-        let dir: Directory<(), ()> = Directory::create_root();
 
-        assert_eq!(dir.count().await, 0);
-        assert_eq!(dir.count_dir().await, 0);
-        assert_eq!(dir.count_file().await, 0);
+    async fn remove_meta<M>(&self) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        self.remove_meta_as(None).await
     }
-}
 
-#[derive(Clone, Copy, Debug, Default)]
-enum GetAction {
-    CreateDefault,
-    #[default]
-    ReturnNone,
+    async fn set_meta<M>(&self, value: M) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        self.set_meta_as(value, None).await
+    }
 }
 
-#[derive(Clone, Copy, Debug)]
-enum GetPosition {
-    Child,
-    Parent,
+#[async_trait]
+impl<D, F> Named for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn name(&self) -> Option<OsString> {
+        self.read()
+            .map(|this| this.parent.as_ref().map(|parent| parent.0.to_os_string()))
+            .await
+    }
 }
 
-impl<D, F> Directory<D, F>
+#[async_trait]
+impl<D, F> Observe<D> for Directory<D, F>
 where
     D: ValueType,
     F: ValueType,
 {
-    async fn get<P>(
-        &self,
-        path: P,
-        get_action: GetAction,
-        get_type: GetType,
-    ) -> Result<Option<Node<D, F>>, GetError>
-    where
-        P: AsRef<Path> + Send,
-    {
-        let mut current = Some(Node::Directory(self.clone()));
-        let mut components = path.as_ref().components().peekable();
-
-        while let Some(component) = components.next() {
-            match current.as_ref() {
-                Some(Node::Directory(dir)) => match component {
-                    Component::CurDir => {}
-                    Component::Prefix(_) => return Err(GetError::UnexpectedPrefix),
-                    Component::RootDir => current = dir.get_root().await?,
-                    Component::ParentDir => current = dir.get_parent().await?,
-                    Component::Normal(name) => {
-                        let name = String::from(name.to_string_lossy());
-                        let get_position = components
-                            .peek()
-                            .map_or(GetPosition::Child, |_| GetPosition::Parent);
-
-                        current = dir
-                            .get_named(name, get_position, get_action, get_type)
-                            .await?;
-                    }
-                },
-                Some(Node::File(_)) => return Err(GetError::UnexpectedFile),
-                _ => return Ok(None),
-            }
+    async fn journal(&self) -> Option<Append<D>> {
+        match &self.hooks().journal {
+            Some(journal) => Some(journal.append_dir(self.path().await)),
+            _ => None,
         }
-
-        Ok(current)
     }
 
-    #[allow(clippy::match_bool)]
-    async fn get_root(&self) -> Result<Option<Node<D, F>>, GetError> {
-        match self.is_root().await {
-            true => Ok(Some(Node::Directory(self.clone()))),
-            _ => Err(GetError::UnexpectedRoot),
-        }
-    }
+    async fn observe(&self, access: Access) {
+        let now = self.context().clock.now();
 
-    async fn get_parent(&self) -> Result<Option<Node<D, F>>, GetError> {
-        match self.parent().await {
-            Some(parent) => Ok(Some(Node::Directory(parent))),
-            _ => Err(GetError::UnexpectedOrphan),
-        }
+        self.read()
+            .then(|this| async move {
+                this.times
+                    .lock()
+                    .map(|mut times| times.observe(access, now))
+                    .await;
+            })
+            .await;
     }
 
-    async fn get_named(
-        &self,
-        name: String,
-        get_position: GetPosition,
-        get_action: GetAction,
-        get_type: GetType,
-    ) -> Result<Option<Node<D, F>>, GetError> {
-        match self.get_child(&name).await {
-            Some(node) => Ok(Some(node)),
-            _ => match get_position {
-                GetPosition::Child => self.get_action(name, get_action, get_type).await,
-                GetPosition::Parent => self.get_action(name, get_action, GetType::Directory).await,
-            },
-        }
+    async fn permit(&self, action: Action) -> bool {
+        self.hooks().permit(action, self.path()).await
     }
 
-    async fn get_child(&self, name: &str) -> Option<Node<D, F>> {
-        self.read()
-            .then(|this| async move {
-                this.children
-                    .read()
-                    .map(|children| children.get(name).map(Clone::clone))
-                    .await
-            })
-            .await
+    async fn quota(&self) -> Option<Arc<Context<D>>> {
+        None
     }
 
-    async fn get_action(
-        &self,
-        name: String,
-        get_action: GetAction,
-        get_type: GetType,
-    ) -> Result<Option<Node<D, F>>, GetError> {
-        match get_action {
-            GetAction::CreateDefault => {
-                self.read()
-                    .then(|this| async move {
-                        let parent = (name.clone(), this.weak.clone());
-                        let new_node = match get_type {
-                            GetType::Directory => Node::Directory(Self::create(None, Some(parent))),
-                            GetType::File => Node::File(File::create(None, parent)),
-                        };
-
-                        let node = this
-                            .children
-                            .write()
-                            .map(|mut children| match children.try_insert(name, new_node) {
-                                Ok(node) => node.clone(),
-                                Err(err) => err.entry.get().clone(),
-                            })
-                            .await;
+    async fn versions(&self) -> Option<Versions<D>> {
+        None
+    }
 
-                        Ok(Some(node))
-                    })
-                    .await
-            }
-            GetAction::ReturnNone => Ok(None),
-        }
+    async fn written(&self, context: Option<&str>) {
+        self.bump().await;
+        self.hooks()
+            .notify_as(
+                Event::Write,
+                self.path(),
+                &Node::Directory(self.clone()),
+                None,
+                context,
+            )
+            .await;
     }
 }
 
-// Children
-
-#[derive(Debug, Default)]
-pub struct Children<D, F>(pub(crate) Arc<RwLock<HashMap<String, Node<D, F>>>>)
+#[async_trait]
+impl<D, F> Parent<D, F> for Directory<D, F>
 where
     D: ValueType,
-    F: ValueType;
-
-// Children - Standard Traits
+    F: ValueType,
+{
+    async fn children(&self) -> Vec<Listed<D, F>> {
+        self.list().await
+    }
+}
 
-impl<D, F> Clone for Children<D, F>
+#[async_trait]
+impl<D, F> Permissions for Directory<D, F>
 where
     D: ValueType,
     F: ValueType,
 {
-    fn clone(&self) -> Self {
-        Self(self.0.clone())
+    async fn readonly(&self) -> bool {
+        self.read().map(|this| this.readonly).await
+    }
+
+    async fn set_readonly(&self, readonly: bool) {
+        self.set_readonly_as(readonly, None).await;
     }
 }
 
-impl<D, F> Deref for Children<D, F>
+#[async_trait]
+impl<D, F> Timestamps for Directory<D, F>
 where
     D: ValueType,
     F: ValueType,
 {
-    type Target = Arc<RwLock<HashMap<String, Node<D, F>>>>;
+    async fn accessed(&self) -> SystemTime {
+        self.times().map(|times| times.accessed).await
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    async fn created(&self) -> SystemTime {
+        self.times().map(|times| times.created).await
     }
-}
 
-// Reference
+    async fn modified(&self) -> SystemTime {
+        self.times().map(|times| times.modified).await
+    }
+}
 
-#[derive(Debug)]
-pub struct Reference<D, F>(pub(crate) Weak<RwLock<Internal<D, F>>>)
+#[async_trait]
+impl<D, F> Versioned for Directory<D, F>
 where
     D: ValueType,
-    F: ValueType;
-
-// Reference - Standard Traits
+    F: ValueType,
+{
+    async fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+}
 
-impl<D, F> Clone for Reference<D, F>
+#[async_trait]
+impl<D, F> Xattrs for Directory<D, F>
 where
     D: ValueType,
     F: ValueType,
 {
-    fn clone(&self) -> Self {
-        Self(self.0.clone())
+    async fn get_xattr(&self, name: &str) -> Option<Vec<u8>> {
+        self.read().map(|this| this.xattrs.get(name).cloned()).await
+    }
+
+    async fn list_xattrs(&self) -> Vec<String> {
+        self.read()
+            .map(|this| {
+                let mut names = this.xattrs.keys().cloned().collect::<Vec<_>>();
+
+                names.sort();
+                names
+            })
+            .await
+    }
+
+    async fn remove_xattr(&self, name: &str) -> Result<Option<Vec<u8>>, WriteError> {
+        Node::Directory(self.clone())
+            .replace_xattr(name, None, None)
+            .await
+    }
+
+    async fn set_xattr(&self, name: &str, value: Vec<u8>) -> Result<Option<Vec<u8>>, WriteError> {
+        Node::Directory(self.clone())
+            .replace_xattr(name, Some(value), None)
+            .await
     }
 }
 
-// Reference - Methods
+// Directory - Methods
 
-impl<D, F> Reference<D, F>
+impl<D, F> Directory<D, F>
 where
     D: ValueType,
     F: ValueType,
 {
-    fn upgrade(&self) -> Option<Directory<D, F>> {
-        self.0.upgrade().map(Directory)
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    #[must_use]
+    pub(crate) fn create(
+        value: D,
+        parent: Option<(Arc<OsStr>, Reference<D, F>)>,
+        context: Arc<Context<F>>,
+        depth: usize,
+        hooks: Arc<Hooks<D, F>>,
+    ) -> Self {
+        let id = context.next_id();
+        let times = Times::new(context.clock.now());
+
+        Self(Arc::new(Shared {
+            children: Children::new(context.case_insensitive, context.order, context.shards),
+            context: StdRwLock::new(context),
+            depth: AtomicUsize::new(depth),
+            digests: Digests::default(),
+            generation: Arc::new(AtomicU64::new(next_generation())),
+            id: AtomicU64::new(id.0),
+            pending: Mutex::new(hooks.loader.is_some()),
+            internal: RwLock::new(Internal {
+                meta: Metas::default(),
+                parent,
+                readonly: false,
+                times: Mutex::new(times),
+                value: Value::new(value),
+                xattrs: HashMap::new(),
+            }),
+            hooks: StdRwLock::new(hooks),
+        }))
+    }
+
+    #[must_use]
+    pub(crate) fn create_root(value: D, context: Arc<Context<F>>, hooks: Arc<Hooks<D, F>>) -> Self {
+        Self::create(value, None, context, 0, hooks)
+    }
+
+    #[must_use]
+    pub fn new(value: D) -> Self {
+        Self::create_root(value, Arc::default(), Arc::default())
+    }
+
+    #[allow(clippy::match_bool)]
+    pub async fn entry<N>(&self, name: N) -> Entry<'_, D, F>
+    where
+        N: AsRef<OsStr> + Send,
+    {
+        let loaded = self.populate().await.map_err(|_| WriteError::LoadFailed);
+        let name = Arc::<OsStr>::from(name.as_ref());
+
+        loop {
+            let occupied = self.children.shard(&name).read().await.contains_key(&name);
+            let permit = match (occupied, loaded) {
+                (true, _) => None,
+                (_, Err(err)) => Some(Err(err)),
+                _ => Some(self.permit_create(&name).await),
+            };
+            let guard = self.children.shard(&name).write().await;
+
+            match (guard.get(&name).cloned(), permit) {
+                (Some(node), _) => {
+                    return Entry::Occupied(OccupiedEntry {
+                        dir: self,
+                        guard,
+                        name,
+                        node,
+                    })
+                }
+                (None, Some(permit)) => {
+                    return Entry::Vacant(VacantEntry {
+                        dir: self,
+                        guard,
+                        name,
+                        permit,
+                    })
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) async fn entries(&self) -> io::Result<Vec<(Arc<OsStr>, Node<D, F>)>> {
+        self.populate().await?;
+
+        Ok(self.children.entries().await)
+    }
+
+    pub(crate) async fn ordered_entries(&self) -> io::Result<Vec<(Arc<OsStr>, Node<D, F>)>> {
+        let mut entries = self.entries().await?;
+
+        if !matches!(self.children.order, Order::Insertion | Order::Sorted) {
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        }
+
+        Ok(entries)
+    }
+
+    pub(crate) async fn insert_dir(
+        &self,
+        name: Arc<OsStr>,
+        value: D,
+        context: Option<&str>,
+    ) -> Result<Self, WriteError> {
+        let (reference, shared) = self.reference().await?;
+        let dir = Self::create(
+            value,
+            Some((name.clone(), reference)),
+            shared,
+            self.depth() + 1,
+            self.hooks(),
+        );
+
+        self.insert(name, Node::Directory(dir.clone()), 0, context)
+            .await?;
+        Ok(dir)
+    }
+
+    pub(crate) async fn insert_file(
+        &self,
+        name: Arc<OsStr>,
+        value: F,
+        context: Option<&str>,
+    ) -> Result<File<D, F>, WriteError> {
+        let (reference, shared) = self.reference().await?;
+        let size = shared.len(&value);
+        let file = File::create(
+            value,
+            (name.clone(), reference),
+            shared,
+            self.hooks(),
+        );
+
+        self.insert(name, Node::File(file.clone()), size, context)
+            .await?;
+        Ok(file)
+    }
+
+    #[allow(clippy::match_bool)]
+    async fn insert(
+        &self,
+        name: Arc<OsStr>,
+        node: Node<D, F>,
+        size: usize,
+        context: Option<&str>,
+    ) -> Result<(), WriteError> {
+        self.authorize(Action::Create, &name).await?;
+        self.populate()
+            .await
+            .map_err(|_| WriteError::LoadFailed)?;
+
+        let indexed = self.indexed(&name, Some(&node)).await;
+        let replaced = self
+            .children
+            .insert(name.clone(), node.clone(), |siblings| {
+                match self.context().quota.admit(siblings, size) {
+                    true => {
+                        self.reindex(indexed);
+                        Ok(())
+                    }
+                    _ => Err(WriteError::QuotaExceeded),
+                }
+            })
+            .await?;
+
+        if let Some(replaced) = &replaced {
+            self.release(replaced.clone()).await;
+            self.hooks()
+                .journal(self.path().map(|path| path.join(&*name)), |_, path| {
+                    Record::Remove { path }
+                })
+                .await;
+            self.hooks()
+                .notify_as(
+                    Event::Remove,
+                    self.path().map(|path| path.join(&*name)),
+                    replaced,
+                    None,
+                    context,
+                )
+                .await;
+        }
+
+        self.journal_tree(&name, &node).await;
+        self.track(&node).await;
+        self.bump().await;
+        self.hooks()
+            .notify_as(
+                Event::Create,
+                self.path().map(|path| path.join(&*name)),
+                &node,
+                None,
+                context,
+            )
+            .await;
+        self.hooks().record(|| Operation::Insert {
+            dir: self.reference_weak(),
+            name,
+            node,
+            replaced,
+        });
+
+        Ok(())
+    }
+
+    #[allow(clippy::match_bool)]
+    pub(crate) async fn link(
+        &self,
+        name: Arc<OsStr>,
+        node: Node<D, F>,
+    ) -> Result<Option<Node<D, F>>, WriteError> {
+        let (reference, context) = self.reference().await?;
+        let (nodes, size) = Self::usage(&context, node.clone()).await;
+
+        self.populate()
+            .await
+            .map_err(|_| WriteError::LoadFailed)?;
+
+        let indexed = self.indexed(&name, Some(&node)).await;
+        let replaced = self
+            .children
+            .insert(name.clone(), node.clone(), |siblings| {
+                match context.quota.admit_tree(siblings, nodes, size) {
+                    true => {
+                        self.reindex(indexed);
+                        Ok(())
+                    }
+                    _ => Err(WriteError::QuotaExceeded),
+                }
+            })
+            .await?;
+
+        if let Some(replaced) = &replaced {
+            self.release(replaced.clone()).await;
+        }
+
+        self.relocate(node.clone(), (name.clone(), reference)).await;
+        self.track(&node).await;
+        self.bump().await;
+
+        Ok(replaced)
+    }
+
+    #[allow(clippy::match_bool)]
+    pub(crate) async fn link_vacant(
+        &self,
+        name: Arc<OsStr>,
+        node: Node<D, F>,
+    ) -> Result<bool, WriteError> {
+        let (reference, context) = self.reference().await?;
+        let (nodes, size) = Self::usage(&context, node.clone()).await;
+
+        self.populate()
+            .await
+            .map_err(|_| WriteError::LoadFailed)?;
+
+        let indexed = self.indexed(&name, Some(&node)).await;
+        let linked = self
+            .children
+            .insert_vacant(name.clone(), node.clone(), |siblings| {
+                match context.quota.admit_tree(siblings, nodes, size) {
+                    true => {
+                        self.reindex(indexed);
+                        Ok(())
+                    }
+                    _ => Err(WriteError::QuotaExceeded),
+                }
+            })
+            .await?;
+
+        if linked {
+            self.relocate(node.clone(), (name, reference)).await;
+            self.track(&node).await;
+            self.bump().await;
+        }
+
+        Ok(linked)
+    }
+
+    pub(crate) async fn unlink(&self, name: &OsStr) -> Result<Option<Node<D, F>>, WriteError> {
+        self.unlink_if(name, |_| true).await
+    }
+
+    pub(crate) async fn unlink_if<P>(
+        &self,
+        name: &OsStr,
+        predicate: P,
+    ) -> Result<Option<Node<D, F>>, WriteError>
+    where
+        P: FnOnce(&Node<D, F>) -> bool + Send,
+    {
+        self.populate()
+            .await
+            .map_err(|_| WriteError::LoadFailed)?;
+
+        let indexed = self.indexed(name, None).await;
+        let mut guard = self.children.shard(name).write().await;
+
+        if !guard.get(name).is_some_and(predicate) {
+            return Ok(None);
+        }
+
+        let Some(node) = guard.remove(name) else {
+            return Ok(None);
+        };
+
+        self.children.release();
+        self.reindex(indexed);
+        drop(guard);
+
+        self.release(node.clone()).await;
+        self.bump().await;
+
+        Ok(Some(node))
+    }
+
+    pub(crate) fn context(&self) -> Arc<Context<F>> {
+        self.0
+            .context
+            .read()
+            .map_or_else(|err| err.into_inner().clone(), |context| context.clone())
+    }
+
+    pub(crate) fn hooks(&self) -> Arc<Hooks<D, F>> {
+        self.0
+            .hooks
+            .read()
+            .map_or_else(|err| err.into_inner().clone(), |hooks| hooks.clone())
+    }
+
+    pub(crate) fn depth(&self) -> usize {
+        self.0.depth.load(Ordering::SeqCst)
+    }
+
+    pub(crate) async fn bump(&self) {
+        bump(&self.generations().await);
+    }
+
+    pub(crate) async fn remove_meta_as<M>(&self, context: Option<&str>) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        let removed = self.write().map(|mut this| this.meta.remove()).await;
+
+        self.changed(context).await;
+        removed
+    }
+
+    pub(crate) async fn set_meta_as<M>(&self, value: M, context: Option<&str>) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        let replaced = self.write().map(|mut this| this.meta.insert(value)).await;
+
+        self.changed(context).await;
+        replaced
+    }
+
+    pub(crate) async fn set_readonly_as(&self, readonly: bool, context: Option<&str>) {
+        self.write().map(|mut this| this.readonly = readonly).await;
+        self.bump().await;
+        self.hooks()
+            .journal(self.path(), |_, path| Record::SetReadonly {
+                path,
+                readonly,
+            })
+            .await;
+        self.changed(context).await;
+    }
+
+    async fn changed(&self, context: Option<&str>) {
+        self.hooks()
+            .notify_as(
+                Event::Metadata,
+                self.path(),
+                &Node::Directory(self.clone()),
+                None,
+                context,
+            )
+            .await;
+    }
+
+    pub(crate) async fn swap_xattr(
+        &self,
+        name: &str,
+        value: Option<Vec<u8>>,
+    ) -> (Option<Vec<u8>>, Arc<Hooks<D, F>>) {
+        let previous = self
+            .write()
+            .map(|mut this| match value {
+                Some(value) => this.xattrs.insert(String::from(name), value),
+                _ => this.xattrs.remove(name),
+            })
+            .await;
+
+        self.bump().await;
+
+        (previous, self.hooks())
+    }
+
+    pub(crate) async fn generations(&self) -> Vec<Arc<AtomicU64>> {
+        let mut generations = Vec::new();
+        let mut current = Some(self.clone());
+
+        while let Some(dir) = current {
+            generations.push(dir.generation.clone());
+            current = dir.parent().await;
+        }
+
+        generations
+    }
+
+    pub(crate) async fn journal_tree(&self, name: &OsStr, node: &Node<D, F>) {
+        let Some(journal) = &self.hooks().journal else {
+            return;
+        };
+
+        let mut pending = vec![(self.path().await.join(name), node.clone())];
+        let mut readonly = Vec::new();
+
+        while let Some((path, node)) = pending.pop() {
+            if node.readonly().await {
+                readonly.push(path.clone());
+            }
+
+            match node {
+                Node::Directory(dir) => {
+                    let value = dir
+                        .data()
+                        .await
+                        .read()
+                        .map(|value| journal.snapshot_dir(&value))
+                        .await;
+
+                    journal.append(Record::CreateDir {
+                        path: path.clone(),
+                        value,
+                    });
+                    pending.extend(
+                        dir.entries()
+                            .await
+                            .unwrap_or_default()
+                            .into_iter()
+                            .rev()
+                            .map(|(name, node)| (path.join(&*name), node)),
+                    );
+                }
+                Node::File(file) => {
+                    let value = file
+                        .data()
+                        .await
+                        .read()
+                        .map(|value| journal.snapshot_file(&value))
+                        .await;
+
+                    journal.append(Record::CreateFile { path, value });
+                }
+            }
+        }
+
+        for path in readonly.into_iter().rev() {
+            journal.append(Record::SetReadonly {
+                path,
+                readonly: true,
+            });
+        }
+    }
+
+    pub(crate) async fn populate(&self) -> io::Result<()> {
+        let Some(loader) = &self.hooks().loader else {
+            return Ok(());
+        };
+
+        if !*self.0.pending.lock().await {
+            return Ok(());
+        }
+
+        let children = loader(self.path().await).await?;
+        let mut pending = self.0.pending.lock().await;
+
+        if *pending {
+            self.load(children).await?;
+            *pending = false;
+        }
+
+        drop(pending);
+
+        if let Some(lru) = &self.hooks().lru {
+            lru.evict().await;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::match_bool)]
+    async fn load(&self, children: Vec<(OsString, Stored<D, F>)>) -> io::Result<()> {
+        let mut loaded = Vec::with_capacity(children.len());
+
+        for (name, stored) in children {
+            let name = Arc::<OsStr>::from(name);
+            let parent = (name.clone(), self.reference_weak());
+            let context = self.context();
+            let mut created = false;
+            let admitted = self
+                .children
+                .get_or_try_insert_with(name.clone(), |siblings| {
+                    let size = match &stored {
+                        Stored::File(value) => context.len(value),
+                        Stored::Directory(_) => 0,
+                    };
+
+                    match context.quota.admit(siblings, size) {
+                        true => {
+                            created = true;
+
+                            Ok(match stored {
+                                Stored::Directory(value) => Node::Directory(Self::create(
+                                    value,
+                                    Some(parent),
+                                    context,
+                                    self.depth() + 1,
+                                    self.hooks(),
+                                )),
+                                Stored::File(value) => {
+                                    let file = File::create(value, parent, context, self.hooks());
+
+                                    if let Some(lru) = &self.hooks().lru {
+                                        lru.track(&file);
+                                    }
+
+                                    Node::File(file)
+                                }
+                            })
+                        }
+                        _ => Err(WriteError::QuotaExceeded),
+                    }
+                })
+                .await;
+
+            match admitted {
+                Ok(node) if created => loaded.push((name, node)),
+                Ok(_) => {}
+                Err(err) => {
+                    for (name, node) in loaded {
+                        self.children.remove(&name).await;
+                        self.release(node).await;
+                    }
+
+                    return Err(io::Error::new(io::ErrorKind::StorageFull, err));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn relocate(&self, node: Node<D, F>, parent: (Arc<OsStr>, Reference<D, F>)) {
+        let (context, hooks) = (self.context(), self.hooks());
+
+        match node {
+            Node::Directory(dir) => {
+                dir.write().map(|mut this| this.parent = Some(parent)).await;
+
+                let mut pending = vec![(dir, self.depth() + 1)];
+
+                while let Some((dir, depth)) = pending.pop() {
+                    dir.0.depth.store(depth, Ordering::SeqCst);
+                    dir.rebind(&context, &hooks);
+
+                    for (_, node) in dir.children.entries().await {
+                        match node {
+                            Node::Directory(dir) => pending.push((dir, depth + 1)),
+                            Node::File(file) => file.rebind(&context, &hooks).await,
+                        }
+                    }
+                }
+            }
+            Node::File(file) => {
+                file.set_parent(parent).await;
+                file.rebind(&context, &hooks).await;
+            }
+        }
+    }
+
+    fn rebind(&self, context: &Arc<Context<F>>, hooks: &Arc<Hooks<D, F>>) {
+        if let Ok(mut current) = self.0.context.write() {
+            if !Arc::ptr_eq(&current, context) {
+                *current = context.clone();
+                self.0.id.store(context.next_id().0, Ordering::SeqCst);
+            }
+        }
+
+        if let Ok(mut current) = self.0.hooks.write() {
+            *current = hooks.clone();
+        }
+    }
+
+    pub(crate) async fn indexed(&self, name: &OsStr, node: Option<&Node<D, F>>) -> Indexed<D, F> {
+        self.hooks().index.as_ref()?;
+
+        let path = self.path().await.join(name);
+        let nodes = match node {
+            Some(node) => Index::flatten(path.clone(), node).await,
+            _ => Vec::new(),
+        };
+
+        Some((path, nodes))
+    }
+
+    pub(crate) fn reindex(&self, indexed: Indexed<D, F>) {
+        if let (Some(index), Some((path, nodes))) = (&self.hooks().index, indexed) {
+            index.replace_tree(&path, nodes);
+        }
+    }
+
+    pub(crate) async fn release(&self, node: Node<D, F>) {
+        if let Some(lru) = &self.hooks().lru {
+            lru.forget(&node).await;
+        }
+
+        let (nodes, size) = Self::usage(&self.context(), node).await;
+
+        self.context().quota.release(nodes, size);
+    }
+
+    async fn usage(context: &Context<F>, node: Node<D, F>) -> (usize, usize) {
+        let (mut nodes, mut size) = (0, 0);
+        let mut pending = vec![node];
+
+        while let Some(node) = pending.pop() {
+            match node {
+                Node::Directory(dir) => {
+                    pending.extend(
+                        dir.children
+                            .entries()
+                            .await
+                            .into_iter()
+                            .map(|(_, node)| node),
+                    );
+                }
+                Node::File(file) => {
+                    size += file
+                        .data()
+                        .await
+                        .read()
+                        .map(|value| context.len(&value))
+                        .await;
+                }
+            }
+
+            nodes += 1;
+        }
+
+        (nodes, size)
+    }
+
+    #[allow(clippy::match_bool)]
+    pub(crate) async fn authorize(&self, action: Action, name: &OsStr) -> Result<(), WriteError> {
+        match self
+            .hooks()
+            .permit(action, self.path().map(|path| path.join(name)))
+            .await
+        {
+            true => Ok(()),
+            _ => Err(WriteError::AccessDenied),
+        }
+    }
+
+    #[allow(clippy::match_bool)]
+    async fn reference(&self) -> Result<(Reference<D, F>, Arc<Context<F>>), WriteError> {
+        self.read()
+            .map(|this| match this.readonly {
+                true => Err(WriteError::PermissionDenied),
+                _ => Ok((self.reference_weak(), self.context())),
+            })
+            .await
+    }
+
+    pub(crate) async fn track(&self, node: &Node<D, F>) {
+        if let Some(lru) = &self.hooks().lru {
+            lru.track_tree(node).await;
+            lru.evict().await;
+        }
+    }
+
+    fn reference_weak(&self) -> Reference<D, F> {
+        Reference(Arc::downgrade(&self.0))
+    }
+
+    async fn times(&self) -> Times {
+        self.read()
+            .then(|this| async move { *this.times.lock().await })
+            .await
+    }
+}
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn count_predicate<P>(&self, predicate: P) -> io::Result<usize>
+    where
+        P: FnMut(&&Node<D, F>) -> bool + Send + Sync,
+    {
+        self.populate().await?;
+
+        Ok(self
+            .children
+            .entries()
+            .map(|entries| {
+                entries
+                    .iter()
+                    .map(|(_, node)| node)
+                    .filter(predicate)
+                    .count()
+            })
+            .await)
+    }
+}
+
+#[cfg(test)]
+mod count_tests {
+    use std::sync::Arc;
+
+    use super::{
+        Count,
+        Directory,
+    };
+
+    #[tokio::test]
+    async fn count_empty() {
+        let dir: Directory<(), ()> = Directory::create_root((), Arc::default(), Arc::default());
+
+        assert_eq!(dir.count().await.unwrap(), 0);
+        assert_eq!(dir.count_dir().await.unwrap(), 0);
+        assert_eq!(dir.count_file().await.unwrap(), 0);
+    }
+}
+
+type Create<B, I> = (B, I);
+
+type Indexed<D, F> = Option<(PathBuf, Vec<(PathBuf, Node<D, F>)>)>;
+
+type Init<D, F> = fn() -> Stored<D, F>;
+
+type Parents<D> = fn() -> D;
+
+#[derive(Clone, Copy, Debug)]
+enum Step<'a> {
+    Current,
+    Name(&'a OsStr),
+    Parent,
+    Prefix,
+    Root,
+}
+
+impl<'a> From<Component<'a>> for Step<'a> {
+    fn from(component: Component<'a>) -> Self {
+        match component {
+            Component::CurDir => Self::Current,
+            Component::Normal(name) => Self::Name(name),
+            Component::ParentDir => Self::Parent,
+            Component::Prefix(_) => Self::Prefix,
+            Component::RootDir => Self::Root,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Step<'a> {
+    fn from(segment: &'a str) -> Self {
+        match segment {
+            "" | "." => Self::Current,
+            ".." => Self::Parent,
+            _ => Self::Name(OsStr::new(segment)),
+        }
+    }
+}
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get<P, B, I>(
+        &self,
+        path: P,
+        create: Option<Create<B, I>>,
+    ) -> Result<Option<Node<D, F>>, GetError>
+    where
+        P: AsRef<Path> + Send,
+        B: Fn() -> D + Send + Sync,
+        I: FnOnce() -> Stored<D, F> + Send,
+    {
+        let path = path.as_ref();
+
+        let steps = path.components().map(Step::from).collect();
+
+        self.resolve(steps, || path.to_path_buf(), create, self.context().clamp_parent)
+            .await
+    }
+
+    async fn resolve<E, B, I>(
+        &self,
+        steps: Vec<Step<'_>>,
+        path: E,
+        create: Option<Create<B, I>>,
+        clamp: bool,
+    ) -> Result<Option<Node<D, F>>, GetError>
+    where
+        E: Fn() -> PathBuf + Send + Sync,
+        B: Fn() -> D + Send + Sync,
+        I: FnOnce() -> Stored<D, F> + Send,
+    {
+        self.limit_components(steps.len(), &path)?;
+
+        let (parent, mut init) = create.unzip();
+        let mut current = Some(Node::Directory(self.clone()));
+        let mut last = 0;
+        let start = self.depth();
+        let mut steps = steps.into_iter().enumerate().peekable();
+
+        while let Some((index, step)) = steps.next() {
+            let next = match current.as_ref() {
+                Some(Node::Directory(dir)) => match step {
+                    Step::Current => Ok(current.clone()),
+                    Step::Prefix => Err(GetErrorKind::UnexpectedPrefix),
+                    Step::Root => dir.get_root().await,
+                    Step::Parent => dir.get_parent(clamp).await,
+                    Step::Name(_) if self.exceeds_traversal(dir, start) => {
+                        Err(GetErrorKind::TraversalLimitExceeded)
+                    }
+                    Step::Name(name) => match steps.peek() {
+                        Some(_) => {
+                            dir.get_named(
+                                name,
+                                parent.as_ref().map(|parent| move || Stored::Directory(parent())),
+                                None,
+                            )
+                            .await
+                        }
+                        _ => dir.get_named(name, init.take(), None).await,
+                    },
+                },
+                Some(Node::File(_)) => Err(GetErrorKind::UnexpectedFile),
+                _ => return Ok(None),
+            };
+
+            last = index;
+            current = next.map_err(|kind| GetError::new(kind, &path(), index))?;
+        }
+
+        self.accept(current, path, last).await
+    }
+
+    pub(crate) async fn accept<E>(
+        &self,
+        node: Option<Node<D, F>>,
+        path: E,
+        index: usize,
+    ) -> Result<Option<Node<D, F>>, GetError>
+    where
+        E: Fn() -> PathBuf + Send + Sync,
+    {
+        if let Some(node) = &node {
+            if !self.hooks().permit(Action::Get, node.path()).await {
+                return Err(GetError::new(GetErrorKind::AccessDenied, &path(), index));
+            }
+        }
+
+        if let Some(lru) = &self.hooks().lru {
+            if let Some(Node::File(file)) = &node {
+                lru.touch(file);
+            }
+
+            lru.evict().await;
+        }
+
+        Ok(node)
+    }
+
+    fn limit_components<E>(&self, len: usize, path: E) -> Result<(), GetError>
+    where
+        E: Fn() -> PathBuf,
+    {
+        match self.context().max_components {
+            Some(max) if len > max => {
+                Err(GetError::new(GetErrorKind::ComponentLimitExceeded, &path(), max))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn exceeds_traversal(&self, dir: &Self, start: usize) -> bool {
+        self.context()
+            .max_traversal
+            .is_some_and(|max| dir.depth().saturating_sub(start) >= max)
+    }
+
+    #[allow(clippy::match_bool)]
+    async fn get_root(&self) -> Result<Option<Node<D, F>>, GetErrorKind> {
+        match self.is_root().await {
+            true => Ok(Some(Node::Directory(self.clone()))),
+            _ => Err(GetErrorKind::UnexpectedRoot),
+        }
+    }
+
+    async fn get_parent(&self, clamp: bool) -> Result<Option<Node<D, F>>, GetErrorKind> {
+        match self.parent().await {
+            Some(parent) => Ok(Some(Node::Directory(parent))),
+            _ if clamp => Ok(Some(Node::Directory(self.clone()))),
+            _ => Err(GetErrorKind::UnexpectedOrphan),
+        }
+    }
+
+    async fn get_named<I>(
+        &self,
+        name: &OsStr,
+        init: Option<I>,
+        context: Option<&str>,
+    ) -> Result<Option<Node<D, F>>, GetErrorKind>
+    where
+        I: FnOnce() -> Stored<D, F> + Send,
+    {
+        self.populate()
+            .await
+            .map_err(|_| GetErrorKind::LoadFailed)?;
+
+        match (self.children.get(name).await, init) {
+            (Some(node), _) => Ok(Some(node)),
+            (_, Some(init)) => self.get_create(name, init, context).await.map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    pub(crate) async fn get_child(&self, name: &OsStr) -> Result<Option<Node<D, F>>, WriteError> {
+        self.populate()
+            .await
+            .map_err(|_| WriteError::LoadFailed)?;
+
+        Ok(self.children.get(name).await)
+    }
+
+    async fn get_create<I>(
+        &self,
+        name: &OsStr,
+        init: I,
+        context: Option<&str>,
+    ) -> Result<Node<D, F>, GetErrorKind>
+    where
+        I: FnOnce() -> Stored<D, F> + Send,
+    {
+        if self.readonly().await {
+            return Err(GetErrorKind::PermissionDenied);
+        }
+
+        if !self
+            .hooks()
+            .permit(Action::Create, self.path().map(|path| path.join(name)))
+            .await
+        {
+            return Err(GetErrorKind::AccessDenied);
+        }
+
+        if self
+            .context()
+            .max_depth
+            .is_some_and(|max| self.depth() >= max)
+        {
+            return Err(GetErrorKind::DepthLimitExceeded);
+        }
+
+        let path = match self.hooks().journal {
+            Some(_) => Some(self.path().await.join(name)),
+            _ => None,
+        };
+        let indexed = self.indexed(name, None).await;
+        let name = Arc::<OsStr>::from(name);
+        let parent = (name.clone(), self.reference_weak());
+        let shared = self.context();
+        let mut created = false;
+
+        let node = self
+            .children
+            .get_or_try_insert_with(name.clone(), |siblings| {
+                let value = init();
+                let size = match &value {
+                    Stored::Directory(_) => 0,
+                    Stored::File(value) => shared.len(value),
+                };
+
+                if !shared.quota.admit(siblings, size) {
+                    return Err(GetErrorKind::QuotaExceeded);
+                }
+
+                if let (Some(journal), Some(path)) = (&self.hooks().journal, path) {
+                    journal.append(match &value {
+                        Stored::Directory(value) => Record::CreateDir {
+                            path,
+                            value: journal.snapshot_dir(value),
+                        },
+                        Stored::File(value) => Record::CreateFile {
+                            path,
+                            value: journal.snapshot_file(value),
+                        },
+                    });
+                }
+
+                let node = match value {
+                    Stored::Directory(value) => Node::Directory(Self::create(
+                        value,
+                        Some(parent),
+                        shared,
+                        self.depth() + 1,
+                        self.hooks(),
+                    )),
+                    Stored::File(value) => {
+                        Node::File(File::create(value, parent, shared, self.hooks()))
+                    }
+                };
+
+                if let (Some(lru), Node::File(file)) = (&self.hooks().lru, &node) {
+                    lru.track(file);
+                }
+
+                self.reindex(indexed.map(|(path, _)| (path.clone(), vec![(path, node.clone())])));
+                self.hooks().record(|| Operation::Insert {
+                    dir: self.reference_weak(),
+                    name: name.clone(),
+                    node: node.clone(),
+                    replaced: None,
+                });
+
+                created = true;
+
+                Ok(node)
+            })
+            .await?;
+
+        if created {
+            self.bump().await;
+            self.hooks()
+                .notify_as(
+                    Event::Create,
+                    self.path().map(|path| path.join(&*name)),
+                    &node,
+                    None,
+                    context,
+                )
+                .await;
+        }
+
+        Ok(node)
+    }
+}
+
+// Children
+
+#[derive(Debug)]
+pub struct Children<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    case_insensitive: bool,
+    hasher: RandomState,
+    len: AtomicUsize,
+    order: Order,
+    shards: Box<[RwLock<Entries<D, F>>]>,
+}
+
+// Children - Methods
+
+impl<D, F> Children<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn new(case_insensitive: bool, order: Order, shards: usize) -> Self {
+        let shards = match order {
+            Order::Hashed => shards.max(1),
+            #[cfg(feature = "im")]
+            Order::Persistent => shards.max(1),
+            Order::Insertion | Order::Sorted => 1,
+        };
+
+        Self {
+            case_insensitive,
+            hasher: RandomState::new(),
+            len: AtomicUsize::new(0),
+            order,
+            shards: (0..shards)
+                .map(|_| {
+                    RwLock::new(Entries {
+                        index: case_insensitive.then(HashMap::new),
+                        nodes: Nodes::new(order),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    #[cfg(feature = "im")]
+    pub(crate) async fn frozen(&self) -> Vec<im::HashMap<Arc<OsStr>, Node<D, F>>> {
+        let mut frozen = Vec::with_capacity(self.shards.len());
+
+        for shard in &*self.shards {
+            frozen.push(shard.read().map(|shard| shard.nodes.frozen()).await);
+        }
+
+        frozen
+    }
+
+    pub(crate) async fn entries(&self) -> Vec<(Arc<OsStr>, Node<D, F>)> {
+        let mut entries = Vec::with_capacity(self.len.load(Ordering::SeqCst));
+
+        for shard in &*self.shards {
+            shard
+                .read()
+                .map(|shard| {
+                    entries.extend(
+                        shard
+                            .iter()
+                            .map(|(name, node)| (name.clone(), node.clone())),
+                    );
+                })
+                .await;
+        }
+
+        entries
+    }
+
+    pub(crate) async fn get(&self, name: &OsStr) -> Option<Node<D, F>> {
+        self.shard(name)
+            .read()
+            .map(|shard| shard.get(name).cloned())
+            .await
+    }
+
+    pub(crate) async fn get_many(&self, names: &[&OsStr]) -> Vec<Option<Node<D, F>>> {
+        let mut shards = BTreeMap::<usize, Vec<usize>>::new();
+        let mut nodes = vec![None; names.len()];
+
+        for (index, name) in names.iter().enumerate() {
+            shards.entry(self.shard_of(name)).or_default().push(index);
+        }
+
+        for (shard, indices) in shards {
+            self.shards[shard]
+                .read()
+                .map(|shard| {
+                    for index in indices {
+                        nodes[index] = shard.get(names[index]).cloned();
+                    }
+                })
+                .await;
+        }
+
+        nodes
+    }
+
+    pub(crate) async fn get_or_try_insert_with<C, E>(
+        &self,
+        name: Arc<OsStr>,
+        create: C,
+    ) -> Result<Node<D, F>, E>
+    where
+        C: FnOnce(usize) -> Result<Node<D, F>, E> + Send,
+    {
+        self.shard(&name)
+            .write()
+            .map(|mut shard| {
+                if let Some(node) = shard.get(&name) {
+                    return Ok(node.clone());
+                }
+
+                let node = create(self.reserve()).inspect_err(|_| self.release())?;
+
+                shard.insert(name, node.clone());
+
+                Ok(node)
+            })
+            .await
+    }
+
+    #[allow(clippy::match_bool)]
+    pub(crate) async fn insert<A, E>(
+        &self,
+        name: Arc<OsStr>,
+        node: Node<D, F>,
+        admit: A,
+    ) -> Result<Option<Node<D, F>>, E>
+    where
+        A: FnOnce(usize) -> Result<(), E> + Send,
+    {
+        self.shard(&name)
+            .write()
+            .map(|mut shard| {
+                match shard.contains_key(&name) {
+                    true => admit(self.len.load(Ordering::SeqCst).saturating_sub(1))?,
+                    _ => admit(self.reserve()).inspect_err(|_| self.release())?,
+                }
+
+                Ok(shard.insert(name, node))
+            })
+            .await
+    }
+
+    pub(crate) async fn insert_vacant<A, E>(
+        &self,
+        name: Arc<OsStr>,
+        node: Node<D, F>,
+        admit: A,
+    ) -> Result<bool, E>
+    where
+        A: FnOnce(usize) -> Result<(), E> + Send,
+    {
+        self.shard(&name)
+            .write()
+            .map(|mut shard| {
+                if shard.contains_key(&name) {
+                    return Ok(false);
+                }
+
+                admit(self.reserve()).inspect_err(|_| self.release())?;
+                shard.insert(name, node);
+
+                Ok(true)
+            })
+            .await
+    }
+
+    pub(crate) fn release(&self) {
+        self.len.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn reserve(&self) -> usize {
+        self.len.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub(crate) async fn remove(&self, name: &OsStr) -> Option<Node<D, F>> {
+        self.shard(name)
+            .write()
+            .map(|mut shard| {
+                let node = shard.remove(name)?;
+
+                self.release();
+
+                Some(node)
+            })
+            .await
+    }
+
+    #[allow(clippy::match_bool)]
+    pub(crate) fn normalized(&self, name: &OsStr) -> OsString {
+        match self.case_insensitive {
+            true => normalize(name),
+            _ => name.to_os_string(),
+        }
+    }
+
+    fn shard(&self, name: &OsStr) -> &RwLock<Entries<D, F>> {
+        &self.shards[self.shard_of(name)]
+    }
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::match_bool
+    )]
+    fn shard_of(&self, name: &OsStr) -> usize {
+        let hash = match self.case_insensitive {
+            true => self.hasher.hash_one(normalize(name)),
+            _ => self.hasher.hash_one(name),
+        };
+
+        hash as usize % self.shards.len()
+    }
+}
+
+// Entries
+
+#[derive(Debug)]
+pub struct Entries<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    index: Option<HashMap<OsString, Arc<OsStr>>>,
+    nodes: Nodes<D, F>,
+}
+
+// Entries - Methods
+
+impl<D, F> Entries<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) fn contains_key(&self, name: &OsStr) -> bool {
+        self.nodes.contains_key(self.key(name))
+    }
+
+    pub(crate) fn get(&self, name: &OsStr) -> Option<&Node<D, F>> {
+        self.nodes.get(self.key(name))
+    }
+
+    pub(crate) fn insert(&mut self, name: Arc<OsStr>, node: Node<D, F>) -> Option<Node<D, F>> {
+        let replaced = self.remove(&name);
+
+        if let Some(index) = &mut self.index {
+            index.insert(normalize(&name), name.clone());
+        }
+
+        self.nodes.insert(name, node);
+
+        replaced
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&Arc<OsStr>, &Node<D, F>)> {
+        self.nodes.iter()
+    }
+
+    pub(crate) fn remove(&mut self, name: &OsStr) -> Option<Node<D, F>> {
+        let key = self
+            .index
+            .as_mut()
+            .and_then(|index| index.remove(&normalize(name)));
+
+        self.nodes.remove(key.as_deref().unwrap_or(name))
+    }
+
+    fn key<'a>(&'a self, name: &'a OsStr) -> &'a OsStr {
+        self.index
+            .as_ref()
+            .and_then(|index| index.get(&normalize(name)))
+            .map_or(name, AsRef::as_ref)
+    }
+}
+
+// Nodes
+
+type Iter<'a, D, F> = Box<dyn Iterator<Item = (&'a Arc<OsStr>, &'a Node<D, F>)> + 'a>;
+
+#[derive(Debug)]
+pub enum Nodes<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    Hashed(HashMap<Arc<OsStr>, Node<D, F>>),
+    Insertion(IndexMap<Arc<OsStr>, Node<D, F>>),
+    #[cfg(feature = "im")]
+    Persistent(im::HashMap<Arc<OsStr>, Node<D, F>>),
+    Sorted(BTreeMap<Arc<OsStr>, Node<D, F>>),
+}
+
+// Nodes - Methods
+
+impl<D, F> Nodes<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn new(order: Order) -> Self {
+        match order {
+            Order::Hashed => Self::Hashed(HashMap::new()),
+            Order::Insertion => Self::Insertion(IndexMap::new()),
+            #[cfg(feature = "im")]
+            Order::Persistent => Self::Persistent(im::HashMap::new()),
+            Order::Sorted => Self::Sorted(BTreeMap::new()),
+        }
+    }
+
+    fn contains_key(&self, name: &OsStr) -> bool {
+        match self {
+            Self::Hashed(nodes) => nodes.contains_key(name),
+            Self::Insertion(nodes) => nodes.contains_key(name),
+            #[cfg(feature = "im")]
+            Self::Persistent(nodes) => nodes.contains_key(name),
+            Self::Sorted(nodes) => nodes.contains_key(name),
+        }
+    }
+
+    #[cfg(feature = "im")]
+    fn frozen(&self) -> im::HashMap<Arc<OsStr>, Node<D, F>> {
+        match self {
+            Self::Persistent(nodes) => nodes.clone(),
+            nodes => nodes
+                .iter()
+                .map(|(name, node)| (name.clone(), node.clone()))
+                .collect(),
+        }
+    }
+
+    fn get(&self, name: &OsStr) -> Option<&Node<D, F>> {
+        match self {
+            Self::Hashed(nodes) => nodes.get(name),
+            Self::Insertion(nodes) => nodes.get(name),
+            #[cfg(feature = "im")]
+            Self::Persistent(nodes) => nodes.get(name),
+            Self::Sorted(nodes) => nodes.get(name),
+        }
+    }
+
+    fn insert(&mut self, name: Arc<OsStr>, node: Node<D, F>) -> Option<Node<D, F>> {
+        match self {
+            Self::Hashed(nodes) => nodes.insert(name, node),
+            Self::Insertion(nodes) => nodes.insert(name, node),
+            #[cfg(feature = "im")]
+            Self::Persistent(nodes) => nodes.insert(name, node),
+            Self::Sorted(nodes) => nodes.insert(name, node),
+        }
+    }
+
+    fn iter(&self) -> Iter<'_, D, F> {
+        match self {
+            Self::Hashed(nodes) => Box::new(nodes.iter()),
+            Self::Insertion(nodes) => Box::new(nodes.iter()),
+            #[cfg(feature = "im")]
+            Self::Persistent(nodes) => Box::new(nodes.iter()),
+            Self::Sorted(nodes) => Box::new(nodes.iter()),
+        }
+    }
+
+    fn remove(&mut self, name: &OsStr) -> Option<Node<D, F>> {
+        match self {
+            Self::Hashed(nodes) => nodes.remove(name),
+            Self::Insertion(nodes) => nodes.shift_remove(name),
+            #[cfg(feature = "im")]
+            Self::Persistent(nodes) => nodes.remove(name),
+            Self::Sorted(nodes) => nodes.remove(name),
+        }
+    }
+}
+
+// Functions
+
+fn normalize(name: &OsStr) -> OsString {
+    name.to_str().map_or_else(
+        || name.to_os_string(),
+        |name| OsString::from(name.to_lowercase()),
+    )
+}
+
+// Reference
+
+#[derive(Debug)]
+pub struct Reference<D, F>(pub(crate) Weak<Shared<D, F>>)
+where
+    D: ValueType,
+    F: ValueType;
+
+// Reference - Standard Traits
+
+impl<D, F> Clone for Reference<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+// Reference - Methods
+
+impl<D, F> Reference<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) fn upgrade(&self) -> Option<Directory<D, F>> {
+        self.0.upgrade().map(Directory)
+    }
+}
+
+// Shared
+
+#[derive(Debug)]
+pub struct Shared<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) children: Children<D, F>,
+    context: StdRwLock<Arc<Context<F>>>,
+    depth: AtomicUsize,
+    digests: Digests,
+    generation: Arc<AtomicU64>,
+    id: AtomicU64,
+    internal: RwLock<Internal<D, F>>,
+    pending: Mutex<bool>,
+    hooks: StdRwLock<Arc<Hooks<D, F>>>,
+}
+
+// Shared - Standard Traits
+
+impl<D, F> Deref for Shared<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    type Target = RwLock<Internal<D, F>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.internal
     }
 }
 
@@ -446,8 +2058,10 @@ where
     D: ValueType,
     F: ValueType,
 {
-    children: Children<D, F>,
-    parent: Option<(String, Reference<D, F>)>,
+    meta: Metas,
+    parent: Option<(Arc<OsStr>, Reference<D, F>)>,
+    readonly: bool,
+    times: Mutex<Times>,
     value: Value<D>,
-    weak: Reference<D, F>,
+    xattrs: HashMap<String, Vec<u8>>,
 }
@@ -0,0 +1,291 @@
+use std::{
+    ffi::OsStr,
+    future,
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use futures::FutureExt;
+use thiserror::Error;
+
+use super::{
+    super::{
+        file_system::{
+            access::Action,
+            hooks::Event,
+            journal::Record,
+            undo::Operation,
+        },
+        node::{
+            child::Child,
+            data::ValueType,
+            data_ext::WriteError,
+            located::Located,
+            named::Named,
+            permissions::Permissions,
+            Node,
+        },
+    },
+    entry::Entry,
+    Directory,
+};
+
+// Attach
+
+#[async_trait]
+pub trait Attach<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn attach<N>(&self, name: N, node: Node<D, F>) -> Result<Option<Node<D, F>>, AttachError>
+    where
+        N: AsRef<OsStr> + Send;
+
+    async fn detach<N>(&self, name: N) -> Result<Option<Node<D, F>>, WriteError>
+    where
+        N: AsRef<OsStr> + Send;
+
+    async fn insert<N>(&self, name: N, node: Node<D, F>) -> Result<Option<Node<D, F>>, AttachError>
+    where
+        N: AsRef<OsStr> + Send;
+}
+
+#[derive(Clone, Copy, Debug, Error)]
+pub enum AttachError {
+    #[error("insert requires a detached node, but the node has a parent")]
+    Attached,
+    #[error("attach would move a directory inside itself")]
+    Cycle,
+    #[error("name must be a single normal path component")]
+    InvalidName,
+    #[error("internal error writing directory")]
+    Write(#[from] WriteError),
+}
+
+// Attach - Implementation
+
+#[async_trait]
+impl<D, F> Attach<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn attach<N>(&self, name: N, node: Node<D, F>) -> Result<Option<Node<D, F>>, AttachError>
+    where
+        N: AsRef<OsStr> + Send,
+    {
+        self.attach_as(name.as_ref(), node, None).await
+    }
+
+    async fn detach<N>(&self, name: N) -> Result<Option<Node<D, F>>, WriteError>
+    where
+        N: AsRef<OsStr> + Send,
+    {
+        self.detach_as(name.as_ref(), None).await
+    }
+
+    async fn insert<N>(&self, name: N, node: Node<D, F>) -> Result<Option<Node<D, F>>, AttachError>
+    where
+        N: AsRef<OsStr> + Send,
+    {
+        self.insert_as(name.as_ref(), node, None).await
+    }
+}
+
+// Directory - Attach
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[allow(clippy::single_match_else)]
+    pub(crate) async fn attach_as(
+        &self,
+        name: &OsStr,
+        node: Node<D, F>,
+        context: Option<&str>,
+    ) -> Result<Option<Node<D, F>>, AttachError> {
+        let name = Arc::<OsStr>::from(name);
+
+        if !is_normal(&name) {
+            return Err(AttachError::InvalidName);
+        }
+
+        if let Node::Directory(dir) = &node {
+            if dir.is_ancestor_of(self).await {
+                return Err(AttachError::Cycle);
+            }
+        }
+
+        let origin = Self::origin(&node).await;
+
+        if let Some((parent, from)) = &origin {
+            if parent.ptr_eq(self) && *from == name {
+                return Ok(None);
+            }
+
+            if parent.readonly().await {
+                return Err(WriteError::PermissionDenied.into());
+            }
+
+            parent.authorize(Action::Remove, from).await?;
+        }
+
+        self.authorize(Action::Create, &name).await?;
+
+        if let Some((parent, from)) = &origin {
+            parent.unlink(from).await?;
+        }
+
+        let replaced = match self.link(name.clone(), node.clone()).await {
+            Ok(replaced) => replaced,
+            Err(err) => {
+                if let Some((parent, from)) = origin {
+                    let _ = parent.link(from, node).await;
+                }
+
+                return Err(err.into());
+            }
+        };
+
+        match origin {
+            Some((parent, from)) => {
+                let (from_path, to_path) = (
+                    parent.path().await.join(&*from),
+                    self.path().await.join(&*name),
+                );
+
+                if let Some(journal) = &self.hooks().journal {
+                    journal.append(Record::Rename {
+                        from: from_path.clone(),
+                        to: to_path.clone(),
+                    });
+                }
+
+                self.renamed(from_path, to_path, &node, replaced.as_ref(), context)
+                    .await;
+                self.hooks().record(|| Operation::Rename {
+                    from: (parent.reference_weak(), from),
+                    to: (self.reference_weak(), name),
+                    node,
+                    replaced: replaced.clone(),
+                });
+            }
+            _ => {
+                if let Some(replaced) = &replaced {
+                    self.hooks()
+                        .journal(self.path().map(|path| path.join(&*name)), |_, path| {
+                            Record::Remove { path }
+                        })
+                        .await;
+                    self.hooks()
+                        .notify_as(
+                            Event::Remove,
+                            self.path().map(|path| path.join(&*name)),
+                            replaced,
+                            None,
+                            context,
+                        )
+                        .await;
+                }
+
+                self.journal_tree(&name, &node).await;
+                self.hooks()
+                    .notify_as(
+                        Event::Create,
+                        self.path().map(|path| path.join(&*name)),
+                        &node,
+                        None,
+                        context,
+                    )
+                    .await;
+                self.hooks().record(|| Operation::Insert {
+                    dir: self.reference_weak(),
+                    name,
+                    node,
+                    replaced: replaced.clone(),
+                });
+            }
+        }
+
+        Ok(replaced)
+    }
+
+    pub(crate) async fn detach_as(
+        &self,
+        name: &OsStr,
+        context: Option<&str>,
+    ) -> Result<Option<Node<D, F>>, WriteError> {
+        match self.entry(name).await {
+            Entry::Occupied(entry) => entry.remove_as(context).await.map(Some),
+            Entry::Vacant(_) => Ok(None),
+        }
+    }
+
+    pub(crate) async fn insert_as(
+        &self,
+        name: &OsStr,
+        node: Node<D, F>,
+        context: Option<&str>,
+    ) -> Result<Option<Node<D, F>>, AttachError> {
+        if node.parent().await.is_some() {
+            return Err(AttachError::Attached);
+        }
+
+        self.attach_as(name, node, context).await
+    }
+
+    pub(crate) async fn renamed(
+        &self,
+        from: PathBuf,
+        to: PathBuf,
+        node: &Node<D, F>,
+        replaced: Option<&Node<D, F>>,
+        context: Option<&str>,
+    ) {
+        if let Some(replaced) = replaced {
+            self.hooks()
+                .notify_as(
+                    Event::Remove,
+                    future::ready(to.clone()),
+                    replaced,
+                    None,
+                    context,
+                )
+                .await;
+        }
+
+        self.hooks()
+            .notify_as(Event::Rename, future::ready(to), node, Some(from), context)
+            .await;
+    }
+
+    async fn origin(node: &Node<D, F>) -> Option<(Self, Arc<OsStr>)> {
+        let parent = node.parent().await?;
+        let name = Arc::<OsStr>::from(node.name().await?);
+
+        parent
+            .get_child(&name)
+            .await
+            .is_ok_and(|current| current.is_some_and(|current| current.same_node(node)))
+            .then_some((parent, name))
+    }
+}
+
+// Functions
+
+pub fn is_normal(name: &OsStr) -> bool {
+    let mut components = Path::new(name).components();
+
+    matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(normal)), None) if normal == name
+    )
+}
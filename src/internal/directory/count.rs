@@ -1,12 +1,14 @@
+use std::io;
+
 use async_trait::async_trait;
 
 // Count
 
 #[async_trait]
 pub trait Count {
-    async fn count(&self) -> usize;
+    async fn count(&self) -> io::Result<usize>;
 
-    async fn count_dir(&self) -> usize;
+    async fn count_dir(&self) -> io::Result<usize>;
 
-    async fn count_file(&self) -> usize;
+    async fn count_file(&self) -> io::Result<usize>;
 }
@@ -0,0 +1,230 @@
+use std::path::{
+    Component,
+    Path,
+    PathBuf,
+};
+
+use async_trait::async_trait;
+
+use super::{
+    super::{
+        file::File,
+        file_system::backend::Stored,
+        node::{
+            data::ValueType,
+            Node,
+        },
+    },
+    create_all::CreateError,
+    get::{
+        GetError,
+        GetErrorKind,
+    },
+    get_ext::GetExt,
+    Directory,
+};
+
+// Create
+
+#[async_trait]
+pub trait Create<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn create_dir<P>(&self, path: P, value: D) -> Result<Directory<D, F>, CreateError>
+    where
+        P: AsRef<Path> + Send;
+
+    async fn create_dir_all<P>(&self, path: P) -> Result<CreatedDirs<D, F>, CreateError>
+    where
+        P: AsRef<Path> + Send,
+        D: Default;
+
+    async fn create_file<P>(&self, path: P, value: F) -> Result<File<D, F>, CreateError>
+    where
+        P: AsRef<Path> + Send;
+}
+
+#[derive(Debug)]
+pub struct CreatedDirs<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub created: Vec<PathBuf>,
+    pub dir: Directory<D, F>,
+    pub existing: Vec<PathBuf>,
+}
+
+// Create - Implementation
+
+#[async_trait]
+impl<D, F> Create<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn create_dir<P>(&self, path: P, value: D) -> Result<Self, CreateError>
+    where
+        P: AsRef<Path> + Send,
+    {
+        self.create_dir_as(path.as_ref(), value, None).await
+    }
+
+    async fn create_dir_all<P>(&self, path: P) -> Result<CreatedDirs<D, F>, CreateError>
+    where
+        P: AsRef<Path> + Send,
+        D: Default,
+    {
+        self.create_dir_all_as(path.as_ref(), None).await
+    }
+
+    async fn create_file<P>(&self, path: P, value: F) -> Result<File<D, F>, CreateError>
+    where
+        P: AsRef<Path> + Send,
+    {
+        self.create_file_as(path.as_ref(), value, None).await
+    }
+}
+
+// Directory - Create
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) async fn create_dir_as(
+        &self,
+        path: &Path,
+        value: D,
+        context: Option<&str>,
+    ) -> Result<Self, CreateError> {
+        match self
+            .create_new(path, Stored::Directory(value), context)
+            .await?
+        {
+            Node::Directory(dir) => Ok(dir),
+            Node::File(_) => Err(CreateError::AlreadyExists(path.to_path_buf())),
+        }
+    }
+
+    #[allow(clippy::match_bool)]
+    pub(crate) async fn create_dir_all_as(
+        &self,
+        path: &Path,
+        context: Option<&str>,
+    ) -> Result<CreatedDirs<D, F>, CreateError>
+    where
+        D: Default,
+    {
+        let components = path.components().count();
+
+        self.limit_components(components, || path.to_path_buf())?;
+
+        let start = self.depth();
+        let mut current = PathBuf::new();
+        let mut dirs = CreatedDirs {
+            created: Vec::new(),
+            dir: self.clone(),
+            existing: Vec::new(),
+        };
+
+        for (index, component) in path.components().enumerate() {
+            let mut created = false;
+
+            current.push(component);
+
+            let node = match component {
+                Component::CurDir => Ok(Some(Node::Directory(dirs.dir.clone()))),
+                Component::Prefix(_) => Err(GetErrorKind::UnexpectedPrefix),
+                Component::RootDir => dirs.dir.get_root().await,
+                Component::ParentDir => dirs.dir.get_parent(self.context().clamp_parent).await,
+                Component::Normal(_) if self.exceeds_traversal(&dirs.dir, start) => {
+                    Err(GetErrorKind::TraversalLimitExceeded)
+                }
+                Component::Normal(name) => {
+                    let node = dirs
+                        .dir
+                        .get_named(
+                            name,
+                            Some(|| {
+                                created = true;
+                                Stored::Directory(D::default())
+                            }),
+                            context,
+                        )
+                        .await;
+
+                    match created {
+                        true => dirs.created.push(current.clone()),
+                        _ => dirs.existing.push(current.clone()),
+                    }
+
+                    node
+                }
+            };
+
+            dirs.dir = match node.map_err(|kind| GetError::new(kind, path, index))? {
+                Some(Node::Directory(dir)) => dir,
+                Some(Node::File(_)) => return Err(CreateError::UnexpectedFile(current)),
+                _ => return Err(GetError::new(GetErrorKind::Other, path, index).into()),
+            };
+        }
+
+        self.accept(
+            Some(Node::Directory(dirs.dir.clone())),
+            || path.to_path_buf(),
+            components.saturating_sub(1),
+        )
+        .await?;
+
+        Ok(dirs)
+    }
+
+    pub(crate) async fn create_file_as(
+        &self,
+        path: &Path,
+        value: F,
+        context: Option<&str>,
+    ) -> Result<File<D, F>, CreateError> {
+        match self.create_new(path, Stored::File(value), context).await? {
+            Node::File(file) => Ok(file),
+            Node::Directory(_) => Err(CreateError::AlreadyExists(path.to_path_buf())),
+        }
+    }
+
+    async fn create_new(
+        &self,
+        path: &Path,
+        stored: Stored<D, F>,
+        context: Option<&str>,
+    ) -> Result<Node<D, F>, CreateError> {
+        let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+            return Err(CreateError::InvalidPath(path.to_path_buf()));
+        };
+
+        let Some(dir) = self.get_dir(parent).await? else {
+            return Err(CreateError::IntermediateNotFound(path.to_path_buf()));
+        };
+
+        let mut created = false;
+        let node = dir
+            .get_named(
+                name,
+                Some(|| {
+                    created = true;
+                    stored
+                }),
+                context,
+            )
+            .await
+            .map_err(|kind| GetError::new(kind, path, path.components().count() - 1))?;
+
+        match (node, created) {
+            (Some(node), true) => Ok(node),
+            _ => Err(CreateError::AlreadyExists(path.to_path_buf())),
+        }
+    }
+}
@@ -0,0 +1,481 @@
+use std::{
+    collections::{
+        btree_map,
+        BTreeMap,
+        HashMap,
+        HashSet,
+    },
+    ffi::{
+        OsStr,
+        OsString,
+    },
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+    sync::{
+        atomic::Ordering,
+        Arc,
+    },
+};
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use super::{
+    super::{
+        file_system::{
+            access::Action,
+            backend::Stored,
+        },
+        node::{
+            data::ValueType,
+            data_ext::WriteError,
+            permissions::Permissions,
+            root::Root,
+            Node,
+        },
+    },
+    get::{
+        GetError,
+        GetErrorKind,
+    },
+    get_ext::GetDirectoryError,
+    Directory,
+};
+
+// CreateAll
+
+#[async_trait]
+pub trait CreateAll<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn create_all<I, C>(&self, entries: I, create: C) -> Result<(), CreateError>
+    where
+        I: IntoIterator<Item = (PathBuf, Stored<D, F>)> + Send,
+        I::IntoIter: Send,
+        C: Fn() -> D + Send + Sync;
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum CreateError {
+    #[error("path already exists: {0}")]
+    AlreadyExists(PathBuf),
+    #[error("internal error getting node")]
+    Get(#[from] GetError),
+    #[error("internal error getting directory")]
+    GetDirectory(#[from] GetDirectoryError),
+    #[error("path has a missing intermediate directory: {0}")]
+    IntermediateNotFound(PathBuf),
+    #[error("path has no final name component: {0}")]
+    InvalidPath(PathBuf),
+    #[error("expected directory, but file found: {0}")]
+    UnexpectedFile(PathBuf),
+    #[error("expected file, but directory found: {0}")]
+    UnexpectedDirectory(PathBuf),
+    #[error("internal error writing node")]
+    Write(#[from] WriteError),
+}
+
+// CreateAll - Implementation
+
+#[async_trait]
+impl<D, F> CreateAll<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn create_all<I, C>(&self, entries: I, create: C) -> Result<(), CreateError>
+    where
+        I: IntoIterator<Item = (PathBuf, Stored<D, F>)> + Send,
+        I::IntoIter: Send,
+        C: Fn() -> D + Send + Sync,
+    {
+        self.create_all_as(entries, create, None).await
+    }
+}
+
+// Directory - CreateAll
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) async fn create_all_as<I, C>(
+        &self,
+        entries: I,
+        create: C,
+        context: Option<&str>,
+    ) -> Result<(), CreateError>
+    where
+        I: IntoIterator<Item = (PathBuf, Stored<D, F>)> + Send,
+        I::IntoIter: Send,
+        C: Fn() -> D + Send + Sync,
+    {
+        let groups = self.plan(entries).await?;
+
+        self.check_all(&groups).await?;
+
+        for group in groups.into_values() {
+            let parent = group.path(group.names.len());
+            let dir = self.parent_or_create(&group, &create, context).await?;
+
+            dir.create_group(&parent, group.children, context).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn plan<I>(&self, entries: I) -> Result<BTreeMap<Key, Group<D, F>>, CreateError>
+    where
+        I: IntoIterator<Item = (PathBuf, Stored<D, F>)> + Send,
+        I::IntoIter: Send,
+    {
+        let root = self.is_root().await;
+        let mut groups = BTreeMap::<Key, Group<D, F>>::new();
+        let mut files = HashMap::<Key, PathBuf>::new();
+        let mut seen = HashSet::<Key>::new();
+
+        for (path, stored) in entries {
+            let (absolute, mut names) = names(&path, root)?;
+            let Some(name) = names.pop() else {
+                return Err(CreateError::InvalidPath(path));
+            };
+
+            let parent = self.key(&names);
+            let mut key = parent.clone();
+
+            key.push(self.children.normalized(&name));
+
+            if !seen.insert(key.clone()) {
+                return Err(CreateError::AlreadyExists(path));
+            }
+
+            if let Stored::File(_) = &stored {
+                files.insert(key, path);
+            }
+
+            groups
+                .entry(parent)
+                .or_insert_with(|| Group {
+                    root: absolute,
+                    names,
+                    children: Vec::new(),
+                })
+                .children
+                .push((name, stored));
+        }
+
+        for key in groups.keys() {
+            for len in 1..=key.len() {
+                if let Some(path) = files.get(&key[..len]) {
+                    return Err(CreateError::UnexpectedFile(path.clone()));
+                }
+            }
+        }
+
+        Ok(groups)
+    }
+
+    async fn check_all(&self, groups: &BTreeMap<Key, Group<D, F>>) -> Result<(), CreateError> {
+        let context = self.context();
+        let mut created = HashSet::<Key>::new();
+        let mut added = HashMap::<Key, (usize, usize)>::new();
+        let mut nodes = 0;
+        let mut size = 0;
+
+        for (key, group) in groups {
+            let (dir, depth) = self.deepest(group).await?;
+
+            if dir.readonly().await {
+                return Err(WriteError::PermissionDenied.into());
+            }
+
+            for index in depth..group.names.len() {
+                if !created.insert(key[..=index].to_vec()) {
+                    continue;
+                }
+
+                if context
+                    .max_depth
+                    .is_some_and(|max| dir.depth() + index - depth >= max)
+                {
+                    let kind = GetErrorKind::DepthLimitExceeded;
+                    let path = group.path(group.names.len());
+
+                    return Err(GetError::new(kind, &path, index + usize::from(group.root)).into());
+                }
+
+                let path = relative(&group.names[depth..=index]);
+
+                dir.authorize(Action::Create, path.as_os_str()).await?;
+
+                nodes += 1;
+                added
+                    .entry(key[..index].to_vec())
+                    .or_insert_with(|| (existing_len(&dir, index == depth), 0))
+                    .1 += 1;
+            }
+
+            let parent = relative(&group.names[depth..]);
+            let exists = depth == group.names.len();
+
+            for (name, stored) in &group.children {
+                let mut child = key.clone();
+
+                child.push(self.children.normalized(name));
+                created.insert(child);
+
+                if exists {
+                    if let Some(node) = dir.get_child(name).await? {
+                        let parent = group.path(group.names.len());
+
+                        return Err(existing(&parent, name, &node, stored));
+                    }
+                }
+
+                dir.authorize(Action::Create, parent.join(&**name).as_os_str())
+                    .await?;
+
+                nodes += 1;
+
+                if let Stored::File(value) = stored {
+                    size += context.len(value);
+                }
+            }
+
+            added
+                .entry(key.clone())
+                .or_insert_with(|| (existing_len(&dir, exists), 0))
+                .1 += group.children.len();
+        }
+
+        if context
+            .quota
+            .max_children
+            .is_some_and(|max| added.values().any(|(len, new)| len + new > max))
+            || !context.quota.fits(nodes, size)
+        {
+            return Err(WriteError::QuotaExceeded.into());
+        }
+
+        Ok(())
+    }
+
+    async fn deepest(&self, group: &Group<D, F>) -> Result<(Self, usize), CreateError> {
+        let mut dir = self.clone();
+
+        for (index, name) in group.names.iter().enumerate() {
+            match dir.get_child(name).await? {
+                Some(Node::Directory(child)) => dir = child,
+                Some(Node::File(_)) => {
+                    return Err(CreateError::UnexpectedFile(group.path(index + 1)));
+                }
+                None => return Ok((dir, index)),
+            }
+        }
+
+        Ok((dir, group.names.len()))
+    }
+
+    async fn parent_or_create<C>(
+        &self,
+        group: &Group<D, F>,
+        create: &C,
+        context: Option<&str>,
+    ) -> Result<Self, CreateError>
+    where
+        C: Fn() -> D + Send + Sync,
+    {
+        let path = group.path(group.names.len());
+        let offset = usize::from(group.root);
+        let mut dir = self.clone();
+
+        for (index, name) in group.names.iter().enumerate() {
+            dir = match dir
+                .get_named(name, Some(|| Stored::Directory(create())), context)
+                .await
+                .map_err(|kind| GetError::new(kind, &path, index + offset))?
+            {
+                Some(Node::Directory(dir)) => dir,
+                Some(Node::File(_)) => {
+                    return Err(CreateError::UnexpectedFile(group.path(index + 1)));
+                }
+                _ => return Err(GetError::new(GetErrorKind::Other, &path, index + offset).into()),
+            };
+        }
+
+        self.accept(
+            Some(Node::Directory(dir.clone())),
+            || path.clone(),
+            (group.names.len() + offset).saturating_sub(1),
+        )
+        .await?;
+
+        Ok(dir)
+    }
+
+    fn key(&self, names: &[Arc<OsStr>]) -> Key {
+        names
+            .iter()
+            .map(|name| self.children.normalized(name))
+            .collect()
+    }
+
+    async fn create_group(
+        &self,
+        parent: &Path,
+        children: Vec<(Arc<OsStr>, Stored<D, F>)>,
+        context: Option<&str>,
+    ) -> Result<(), CreateError> {
+        let mut shards = BTreeMap::new();
+
+        for (name, _) in &children {
+            let shard = self.children.shard_of(name);
+
+            if let btree_map::Entry::Vacant(entry) = shards.entry(shard) {
+                entry.insert(self.children.shards[shard].write().await);
+            }
+        }
+
+        for (name, stored) in &children {
+            if let Some(node) = shards[&self.children.shard_of(name)].get(name) {
+                return Err(existing(parent, name, node, stored));
+            }
+        }
+
+        let mut created = Vec::new();
+
+        for (name, stored) in children {
+            let node = match stored {
+                Stored::Directory(value) => self
+                    .create_dir_node(&name, value)
+                    .await
+                    .map(|(dir, record)| (Node::Directory(dir), record)),
+                Stored::File(value) => self
+                    .create_file_node(&name, value)
+                    .await
+                    .map(|(file, record)| (Node::File(file), record)),
+            };
+
+            match node {
+                Ok((node, record)) => created.push((name, node, record)),
+                Err(err) => {
+                    for (_, node, _) in created {
+                        self.children.release();
+                        self.release(node).await;
+                    }
+
+                    return Err(err.into());
+                }
+            }
+        }
+
+        let mut inserted = Vec::new();
+
+        for (name, node, record) in created {
+            if let Some(guard) = shards.get_mut(&self.children.shard_of(&name)) {
+                self.insert_node(guard, name.clone(), &node, record).await;
+                inserted.push((name, node));
+            }
+        }
+
+        drop(shards);
+
+        for (name, node) in inserted {
+            self.inserted(&name, &node, context).await;
+        }
+
+        Ok(())
+    }
+}
+
+// Functions
+
+fn existing<D, F>(
+    parent: &Path,
+    name: &OsStr,
+    node: &Node<D, F>,
+    stored: &Stored<D, F>,
+) -> CreateError
+where
+    D: ValueType,
+    F: ValueType,
+{
+    let path = parent.join(name);
+
+    match (node, stored) {
+        (Node::File(_), Stored::Directory(_)) => CreateError::UnexpectedFile(path),
+        (Node::Directory(_), Stored::File(_)) => CreateError::UnexpectedDirectory(path),
+        _ => CreateError::AlreadyExists(path),
+    }
+}
+
+#[allow(clippy::match_bool)]
+fn existing_len<D, F>(dir: &Directory<D, F>, exists: bool) -> usize
+where
+    D: ValueType,
+    F: ValueType,
+{
+    match exists {
+        true => dir.children.len.load(Ordering::SeqCst),
+        _ => 0,
+    }
+}
+
+fn names(path: &Path, root: bool) -> Result<(bool, Vec<Arc<OsStr>>), CreateError> {
+    let mut absolute = false;
+    let mut names = Vec::new();
+
+    for component in path.components() {
+        match component {
+            Component::RootDir if root => absolute = true,
+            Component::RootDir => {
+                return Err(GetError::new(GetErrorKind::UnexpectedRoot, path, 0).into());
+            }
+            Component::CurDir => {}
+            Component::Normal(name) => names.push(Arc::from(name)),
+            Component::ParentDir if !names.is_empty() => {
+                names.pop();
+            }
+            Component::ParentDir | Component::Prefix(_) => {
+                return Err(CreateError::InvalidPath(path.to_path_buf()));
+            }
+        }
+    }
+
+    Ok((absolute, names))
+}
+
+fn relative(names: &[Arc<OsStr>]) -> PathBuf {
+    names.iter().map(|name| &**name).collect()
+}
+
+// Group
+
+type Key = Vec<OsString>;
+
+struct Group<D, F> {
+    root: bool,
+    names: Vec<Arc<OsStr>>,
+    children: Vec<(Arc<OsStr>, Stored<D, F>)>,
+}
+
+// Group - Methods
+
+impl<D, F> Group<D, F> {
+    #[allow(clippy::match_bool)]
+    fn path(&self, len: usize) -> PathBuf {
+        let mut path = match self.root {
+            true => PathBuf::from("/"),
+            _ => PathBuf::new(),
+        };
+
+        path.extend(self.names[..len].iter().map(|name| &**name));
+        path
+    }
+}
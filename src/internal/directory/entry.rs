@@ -0,0 +1,420 @@
+use std::{
+    ffi::OsStr,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use async_lock::RwLockWriteGuard;
+use futures::FutureExt;
+
+use super::{
+    super::{
+        file::File,
+        file_system::{
+            access::Action,
+            hooks::Event,
+            journal::{
+                Journal,
+                Record,
+            },
+            undo::Operation,
+        },
+        node::{
+            data::ValueType,
+            data_ext::WriteError,
+            located::Located,
+            permissions::Permissions,
+            Node,
+        },
+    },
+    attach::is_normal,
+    Directory,
+    Entries,
+};
+
+// Entry
+
+#[derive(Debug)]
+pub enum Entry<'a, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    Occupied(OccupiedEntry<'a, D, F>),
+    Vacant(VacantEntry<'a, D, F>),
+}
+
+// Entry - Methods
+
+impl<D, F> Entry<'_, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub fn and_modify<M>(self, f: M) -> Self
+    where
+        M: FnOnce(&Node<D, F>),
+    {
+        match self {
+            Self::Occupied(entry) => {
+                f(entry.get());
+                Self::Occupied(entry)
+            }
+            Self::Vacant(entry) => Self::Vacant(entry),
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &OsStr {
+        match self {
+            Self::Occupied(entry) => entry.name(),
+            Self::Vacant(entry) => entry.name(),
+        }
+    }
+
+    pub async fn or_insert_dir(self, value: D) -> Result<Node<D, F>, WriteError> {
+        self.or_insert_dir_as(value, None).await
+    }
+
+    pub async fn or_insert_file(self, value: F) -> Result<Node<D, F>, WriteError> {
+        self.or_insert_file_as(value, None).await
+    }
+
+    pub(crate) async fn or_insert_dir_as(
+        self,
+        value: D,
+        context: Option<&str>,
+    ) -> Result<Node<D, F>, WriteError> {
+        match self {
+            Self::Occupied(entry) => Ok(entry.into_node()),
+            Self::Vacant(entry) => {
+                entry
+                    .insert_dir_as(value, context)
+                    .map(|dir| dir.map(Node::Directory))
+                    .await
+            }
+        }
+    }
+
+    pub(crate) async fn or_insert_file_as(
+        self,
+        value: F,
+        context: Option<&str>,
+    ) -> Result<Node<D, F>, WriteError> {
+        match self {
+            Self::Occupied(entry) => Ok(entry.into_node()),
+            Self::Vacant(entry) => {
+                entry
+                    .insert_file_as(value, context)
+                    .map(|file| file.map(Node::File))
+                    .await
+            }
+        }
+    }
+}
+
+// OccupiedEntry
+
+#[derive(Debug)]
+pub struct OccupiedEntry<'a, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) dir: &'a Directory<D, F>,
+    pub(crate) guard: RwLockWriteGuard<'a, Entries<D, F>>,
+    pub(crate) name: Arc<OsStr>,
+    pub(crate) node: Node<D, F>,
+}
+
+// OccupiedEntry - Methods
+
+impl<D, F> OccupiedEntry<'_, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub const fn get(&self) -> &Node<D, F> {
+        &self.node
+    }
+
+    #[must_use]
+    pub fn into_node(self) -> Node<D, F> {
+        self.node
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &OsStr {
+        &self.name
+    }
+
+    pub async fn remove(self) -> Result<Node<D, F>, WriteError> {
+        self.remove_as(None).await
+    }
+
+    pub(crate) async fn remove_as(self, context: Option<&str>) -> Result<Node<D, F>, WriteError> {
+        let Self { dir, guard, name, node } = self;
+
+        drop(guard);
+
+        if dir.readonly().await {
+            return Err(WriteError::PermissionDenied);
+        }
+
+        dir.authorize(Action::Remove, &name).await?;
+
+        let indexed = dir.indexed(&name, None).await;
+        let mut guard = dir.children.shard(&name).write().await;
+
+        if !guard
+            .get(&name)
+            .is_some_and(|current| current.same_node(&node))
+        {
+            return Err(WriteError::Conflict);
+        }
+
+        guard.remove(&name);
+        dir.children.release();
+        dir.reindex(indexed);
+
+        drop(guard);
+        dir.release(node.clone()).await;
+        dir.bump().await;
+
+        dir.hooks()
+            .journal(dir.path().map(|path| path.join(&*name)), |_, path| {
+                Record::Remove { path }
+            })
+            .await;
+        dir.hooks()
+            .notify_as(
+                Event::Remove,
+                dir.path().map(|path| path.join(&*name)),
+                &node,
+                None,
+                context,
+            )
+            .await;
+
+        dir.hooks().record(|| Operation::Remove {
+            dir: dir.reference_weak(),
+            name,
+            node: node.clone(),
+        });
+
+        Ok(node)
+    }
+}
+
+// VacantEntry
+
+#[derive(Debug)]
+pub struct VacantEntry<'a, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) dir: &'a Directory<D, F>,
+    pub(crate) guard: RwLockWriteGuard<'a, Entries<D, F>>,
+    pub(crate) name: Arc<OsStr>,
+    pub(crate) permit: Result<(), WriteError>,
+}
+
+// VacantEntry - Methods
+
+impl<D, F> VacantEntry<'_, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub async fn insert_dir(self, value: D) -> Result<Directory<D, F>, WriteError> {
+        self.insert_dir_as(value, None).await
+    }
+
+    pub async fn insert_file(self, value: F) -> Result<File<D, F>, WriteError> {
+        self.insert_file_as(value, None).await
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &OsStr {
+        &self.name
+    }
+
+    async fn insert_dir_as(
+        self,
+        value: D,
+        context: Option<&str>,
+    ) -> Result<Directory<D, F>, WriteError> {
+        let Self {
+            dir: parent,
+            mut guard,
+            name,
+            permit,
+        } = self;
+
+        permit?;
+
+        let (dir, record) = parent.create_dir_node(&name, value).await?;
+        let node = Node::Directory(dir.clone());
+
+        parent.insert_node(&mut guard, name.clone(), &node, record).await;
+
+        drop(guard);
+        parent.inserted(&name, &node, context).await;
+
+        Ok(dir)
+    }
+
+    async fn insert_file_as(
+        self,
+        value: F,
+        context: Option<&str>,
+    ) -> Result<File<D, F>, WriteError> {
+        let Self {
+            dir: parent,
+            mut guard,
+            name,
+            permit,
+        } = self;
+
+        permit?;
+
+        let (file, record) = parent.create_file_node(&name, value).await?;
+        let node = Node::File(file.clone());
+
+        parent.insert_node(&mut guard, name.clone(), &node, record).await;
+
+        drop(guard);
+        parent.inserted(&name, &node, context).await;
+
+        Ok(file)
+    }
+}
+
+// Directory - Entry
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) async fn permit_create(&self, name: &OsStr) -> Result<(), WriteError> {
+        if !is_normal(name) {
+            return Err(WriteError::InvalidName);
+        }
+
+        self.authorize(Action::Create, name).await
+    }
+
+    pub(crate) async fn create_dir_node(
+        &self,
+        name: &Arc<OsStr>,
+        value: D,
+    ) -> Result<(Self, Option<Record<D, F>>), WriteError> {
+        let (reference, context) = self.reference().await?;
+
+        self.admit(0)?;
+
+        let record = self
+            .create_record(name, |journal, path| Record::CreateDir {
+                path,
+                value: journal.snapshot_dir(&value),
+            })
+            .await;
+        let dir = Self::create(
+            value,
+            Some((name.clone(), reference)),
+            context,
+            self.depth() + 1,
+            self.hooks(),
+        );
+
+        Ok((dir, record))
+    }
+
+    pub(crate) async fn create_file_node(
+        &self,
+        name: &Arc<OsStr>,
+        value: F,
+    ) -> Result<(File<D, F>, Option<Record<D, F>>), WriteError> {
+        let (reference, context) = self.reference().await?;
+
+        self.admit(context.len(&value))?;
+
+        let record = self
+            .create_record(name, |journal, path| Record::CreateFile {
+                path,
+                value: journal.snapshot_file(&value),
+            })
+            .await;
+        let file = File::create(value, (name.clone(), reference), context, self.hooks());
+
+        Ok((file, record))
+    }
+
+    pub(crate) async fn insert_node(
+        &self,
+        guard: &mut Entries<D, F>,
+        name: Arc<OsStr>,
+        node: &Node<D, F>,
+        record: Option<Record<D, F>>,
+    ) {
+        let indexed = self.indexed(&name, Some(node)).await;
+
+        guard.insert(name.clone(), node.clone());
+        self.reindex(indexed);
+
+        if let (Some(journal), Some(record)) = (&self.hooks().journal, record) {
+            journal.append(record);
+        }
+
+        self.hooks().record(|| Operation::Insert {
+            dir: self.reference_weak(),
+            name,
+            node: node.clone(),
+            replaced: None,
+        });
+    }
+
+    pub(crate) async fn inserted(&self, name: &OsStr, node: &Node<D, F>, context: Option<&str>) {
+        self.bump().await;
+        self.hooks()
+            .notify_as(
+                Event::Create,
+                self.path().map(|path| path.join(name)),
+                node,
+                None,
+                context,
+            )
+            .await;
+
+        if let (Some(lru), Node::File(file)) = (&self.hooks().lru, node) {
+            lru.track(file);
+            lru.evict().await;
+        }
+    }
+
+    async fn create_record<R>(&self, name: &OsStr, record: R) -> Option<Record<D, F>>
+    where
+        R: FnOnce(&Journal<D, F>, PathBuf) -> Record<D, F> + Send,
+    {
+        match &self.hooks().journal {
+            Some(journal) => Some(record(journal, self.path().await.join(name))),
+            _ => None,
+        }
+    }
+
+    fn admit(&self, size: usize) -> Result<(), WriteError> {
+        let siblings = self.children.reserve();
+
+        if self.context().quota.admit(siblings, size) {
+            return Ok(());
+        }
+
+        self.children.release();
+
+        Err(WriteError::QuotaExceeded)
+    }
+}
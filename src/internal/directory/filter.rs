@@ -0,0 +1,109 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use async_trait::async_trait;
+use futures::{
+    future::BoxFuture,
+    FutureExt,
+};
+
+use super::{
+    super::{
+        file_system::FileSystem,
+        node::{
+            data::ValueType,
+            data_ext::{
+                DataExt,
+                WriteError,
+            },
+            Node,
+        },
+    },
+    count::Count,
+    Directory,
+};
+
+// Filter
+
+#[async_trait]
+pub trait Filter<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn filter<P>(&self, predicate: P) -> Result<FileSystem<D, F>, WriteError>
+    where
+        D: Clone,
+        F: Clone,
+        P: Fn(&Path, &Node<D, F>) -> bool + Send + Sync;
+}
+
+// Filter - Implementation
+
+#[async_trait]
+impl<D, F> Filter<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn filter<P>(&self, predicate: P) -> Result<FileSystem<D, F>, WriteError>
+    where
+        D: Clone,
+        F: Clone,
+        P: Fn(&Path, &Node<D, F>) -> bool + Send + Sync,
+    {
+        let root = self.read(|value| value.clone()).await;
+        let fs = FileSystem::with_root(self.context().options.clone(), root);
+
+        filter(self, &fs, PathBuf::from("/"), &predicate).await?;
+
+        Ok(fs)
+    }
+}
+
+// Functions
+
+fn filter<'a, D, F, P>(
+    source: &'a Directory<D, F>,
+    target: &'a Directory<D, F>,
+    path: PathBuf,
+    predicate: &'a P,
+) -> BoxFuture<'a, Result<(), WriteError>>
+where
+    D: ValueType + Clone,
+    F: ValueType + Clone,
+    P: Fn(&Path, &Node<D, F>) -> bool + Send + Sync,
+{
+    async move {
+        let entries = source.entries().await.map_err(|_| WriteError::LoadFailed)?;
+
+        for (name, node) in entries {
+            let path = path.join(&*name);
+            let matched = predicate(&path, &node);
+
+            match node {
+                Node::Directory(source) => {
+                    let value = source.read(|value| value.clone()).await;
+                    let dir = target.insert_dir(name.clone(), value, None).await?;
+
+                    filter(&source, &dir, path, predicate).await?;
+
+                    if !matched && dir.count().await.is_ok_and(|count| count == 0) {
+                        target.unlink(&name).await?;
+                    }
+                }
+                Node::File(source) if matched => {
+                    let value = source.read(|value| value.clone()).await;
+
+                    target.insert_file(name, value, None).await?;
+                }
+                Node::File(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+    .boxed()
+}
@@ -0,0 +1,94 @@
+use std::ffi::OsStr;
+
+use async_trait::async_trait;
+
+use super::{
+    super::node::data::ValueType,
+    visit::{
+        Flow,
+        Visit,
+    },
+    walk::Walked,
+    Directory,
+};
+
+// Find
+
+#[async_trait]
+pub trait Find<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn find_by_name<N>(&self, name: N) -> Option<Walked<D, F>>
+    where
+        N: AsRef<OsStr> + Send;
+
+    async fn find_by_name_all<N>(&self, name: N) -> Vec<Walked<D, F>>
+    where
+        N: AsRef<OsStr> + Send;
+
+    async fn find_by_name_matching<M>(&self, matcher: M) -> Vec<Walked<D, F>>
+    where
+        M: Fn(&OsStr) -> bool + Send + Sync;
+}
+
+// Find - Implementation
+
+#[async_trait]
+impl<D, F> Find<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn find_by_name<N>(&self, name: N) -> Option<Walked<D, F>>
+    where
+        N: AsRef<OsStr> + Send,
+    {
+        let name = name.as_ref().to_os_string();
+
+        find(self, |found| found == name, true).await.pop()
+    }
+
+    async fn find_by_name_all<N>(&self, name: N) -> Vec<Walked<D, F>>
+    where
+        N: AsRef<OsStr> + Send,
+    {
+        let name = name.as_ref().to_os_string();
+
+        find(self, |found| found == name, false).await
+    }
+
+    async fn find_by_name_matching<M>(&self, matcher: M) -> Vec<Walked<D, F>>
+    where
+        M: Fn(&OsStr) -> bool + Send + Sync,
+    {
+        find(self, matcher, false).await
+    }
+}
+
+// Functions
+
+async fn find<D, F, M>(dir: &Directory<D, F>, matcher: M, first: bool) -> Vec<Walked<D, F>>
+where
+    D: ValueType,
+    F: ValueType,
+    M: Fn(&OsStr) -> bool + Send + Sync,
+{
+    let mut found = Vec::new();
+
+    dir.visit(|path, node| {
+        if path.file_name().is_some_and(&matcher) {
+            found.push((path.to_path_buf(), node.clone()));
+
+            if first {
+                return Flow::Stop;
+            }
+        }
+
+        Flow::Continue
+    })
+    .await;
+
+    found
+}
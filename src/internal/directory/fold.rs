@@ -0,0 +1,72 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use async_trait::async_trait;
+
+use super::{
+    super::node::{
+        data::ValueType,
+        Node,
+    },
+    Directory,
+};
+
+// Fold
+
+#[async_trait]
+pub trait Fold<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn fold<T, G>(&self, init: T, f: G) -> T
+    where
+        T: Send,
+        G: FnMut(T, &Path, &Node<D, F>) -> T + Send;
+}
+
+// Fold - Implementation
+
+#[async_trait]
+impl<D, F> Fold<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn fold<T, G>(&self, init: T, mut f: G) -> T
+    where
+        T: Send,
+        G: FnMut(T, &Path, &Node<D, F>) -> T + Send,
+    {
+        let mut acc = init;
+        let mut pending = children(PathBuf::from("/"), self).await;
+
+        while let Some((path, node)) = pending.pop() {
+            acc = f(acc, &path, &node);
+
+            if let Node::Directory(dir) = node {
+                pending.extend(children(path, &dir).await);
+            }
+        }
+
+        acc
+    }
+}
+
+// Functions
+
+async fn children<D, F>(path: PathBuf, dir: &Directory<D, F>) -> Vec<(PathBuf, Node<D, F>)>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    dir.entries()
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .rev()
+        .map(|(name, node)| (path.join(&*name), node))
+        .collect()
+}
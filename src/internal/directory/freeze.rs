@@ -0,0 +1,95 @@
+use std::{
+    ffi::OsStr,
+    io,
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+
+use super::{
+    super::node::{
+        data::ValueType,
+        Node,
+    },
+    Directory,
+};
+
+// Freeze
+
+#[async_trait]
+pub trait Freeze<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn freeze(&self) -> io::Result<Frozen<D, F>>;
+}
+
+// Freeze - Implementation
+
+#[async_trait]
+impl<D, F> Freeze<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn freeze(&self) -> io::Result<Frozen<D, F>> {
+        self.populate().await?;
+
+        Ok(Frozen {
+            shards: self.children.frozen().await,
+        })
+    }
+}
+
+// Frozen
+
+#[derive(Clone, Debug)]
+pub struct Frozen<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    shards: Vec<im::HashMap<Arc<OsStr>, Node<D, F>>>,
+}
+
+// Frozen - Methods
+
+impl<D, F> Frozen<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub fn contains<N>(&self, name: N) -> bool
+    where
+        N: AsRef<OsStr>,
+    {
+        self.get(name).is_some()
+    }
+
+    pub fn get<N>(&self, name: N) -> Option<&Node<D, F>>
+    where
+        N: AsRef<OsStr>,
+    {
+        self.shards
+            .iter()
+            .find_map(|shard| shard.get(name.as_ref()))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.shards.iter().all(im::HashMap::is_empty)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&OsStr, &Node<D, F>)> {
+        self.shards
+            .iter()
+            .flat_map(im::HashMap::iter)
+            .map(|(name, node)| (name.as_ref(), node))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(im::HashMap::len).sum()
+    }
+}
@@ -1,11 +1,21 @@
-use std::path::Path;
+use std::path::{
+    Path,
+    PathBuf,
+};
 
 use async_trait::async_trait;
+use miette::{
+    Diagnostic,
+    SourceSpan,
+};
 use thiserror::Error;
 
-use super::super::node::{
-    data::ValueType,
-    Node,
+use super::super::{
+    file_system::backend::Stored,
+    node::{
+        data::ValueType,
+        Node,
+    },
 };
 
 // Get
@@ -22,7 +32,20 @@ where
 
     async fn get_default<P>(&self, path: P, get_type: GetType) -> Result<Node<D, F>, GetError>
     where
-        P: AsRef<Path> + Send;
+        P: AsRef<Path> + Send,
+        D: Default,
+        F: Default;
+
+    async fn get_or_create_with<P, B, C>(
+        &self,
+        path: P,
+        parents: B,
+        create: C,
+    ) -> Result<Node<D, F>, GetError>
+    where
+        P: AsRef<Path> + Send,
+        B: Fn() -> D + Send + Sync,
+        C: FnOnce() -> Stored<D, F> + Send;
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -34,8 +57,21 @@ pub enum GetType {
 }
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Clone, Copy, Debug, Error)]
-pub enum GetError {
+#[derive(Clone, Debug, Diagnostic, Error)]
+#[error("{kind} (component {index} of {})", .path.display())]
+pub struct GetError {
+    kind: GetErrorKind,
+    path: PathBuf,
+    index: usize,
+    #[source_code]
+    input: String,
+    #[label("{kind}")]
+    span: SourceSpan,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum GetErrorKind {
     #[error("path indicated a directory, but a file was found")]
     UnexpectedFile,
     #[error("path indicated parent directory, but current directory has no parent")]
@@ -44,6 +80,73 @@ pub enum GetError {
     UnexpectedPrefix,
     #[error("path was an absolute (root) path, but the directory is not a root directory")]
     UnexpectedRoot,
+    #[error("path required creating a node, but the parent directory is read-only")]
+    PermissionDenied,
+    #[error("access to the path was denied by the file system interceptor")]
+    AccessDenied,
+    #[error("path contained more components than the file system maximum")]
+    ComponentLimitExceeded,
+    #[error("path required creating a node deeper than the file system maximum depth")]
+    DepthLimitExceeded,
+    #[error("path required creating a node, but doing so would exceed the file system quota")]
+    QuotaExceeded,
+    #[error("path traversed deeper than the file system maximum traversal depth")]
+    TraversalLimitExceeded,
+    #[error("path traversed a directory whose children could not be loaded")]
+    LoadFailed,
     #[error("an internal error occurred")]
     Other,
 }
+
+// GetError - Methods
+
+impl GetError {
+    pub(crate) fn new(kind: GetErrorKind, path: &Path, index: usize) -> Self {
+        let input = path.to_string_lossy().into_owned();
+        let span = span(&input, index);
+
+        Self {
+            kind,
+            path: path.to_path_buf(),
+            index,
+            input,
+            span,
+        }
+    }
+
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> GetErrorKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+// Functions
+
+fn span(input: &str, index: usize) -> SourceSpan {
+    let mut offset = 0;
+
+    for (current, component) in Path::new(input).components().enumerate() {
+        let text = component.as_os_str().to_string_lossy();
+        let start = input[offset..]
+            .find(&*text)
+            .map_or(offset, |found| offset + found);
+
+        if current == index {
+            return (start, text.len()).into();
+        }
+
+        offset = start + text.len();
+    }
+
+    (offset, 0).into()
+}
@@ -0,0 +1,55 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use super::{
+    super::node::{
+        data::ValueType,
+        Node,
+    },
+    get::GetError,
+    Create,
+    Directory,
+    Init,
+    Parents,
+    Step,
+};
+
+// GetClamped
+
+#[async_trait]
+pub trait GetClamped<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_clamped<P>(&self, path: P, clamp: bool) -> Result<Option<Node<D, F>>, GetError>
+    where
+        P: AsRef<Path> + Send;
+}
+
+// GetClamped - Implementation
+
+#[async_trait]
+impl<D, F> GetClamped<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_clamped<P>(&self, path: P, clamp: bool) -> Result<Option<Node<D, F>>, GetError>
+    where
+        P: AsRef<Path> + Send,
+    {
+        let path = path.as_ref();
+
+        let steps = path.components().map(Step::from).collect();
+
+        self.resolve(
+            steps,
+            || path.to_path_buf(),
+            None::<Create<Parents<D>, Init<D, F>>>,
+            clamp,
+        )
+        .await
+    }
+}
@@ -6,6 +6,7 @@ use thiserror::Error;
 use super::{
     super::{
         file::File,
+        file_system::backend::Stored,
         node::{
             data::ValueType,
             Node,
@@ -33,7 +34,18 @@ where
 
     async fn get_dir_default<P>(&self, path: P) -> Result<Directory<D, F>, GetDirectoryError>
     where
-        P: AsRef<Path> + Send;
+        P: AsRef<Path> + Send,
+        D: Default,
+        F: Default;
+
+    async fn get_dir_or_create_with<P, C>(
+        &self,
+        path: P,
+        create: C,
+    ) -> Result<Directory<D, F>, GetDirectoryError>
+    where
+        P: AsRef<Path> + Send,
+        C: Fn() -> D + Send + Sync;
 
     async fn get_file<P>(&self, path: P) -> Result<Option<File<D, F>>, GetFileError>
     where
@@ -41,10 +53,22 @@ where
 
     async fn get_file_default<P>(&self, path: P) -> Result<File<D, F>, GetFileError>
     where
-        P: AsRef<Path> + Send;
+        P: AsRef<Path> + Send,
+        D: Default,
+        F: Default;
+
+    async fn get_file_or_create_with<P, C>(
+        &self,
+        path: P,
+        create: C,
+    ) -> Result<File<D, F>, GetFileError>
+    where
+        P: AsRef<Path> + Send,
+        C: FnOnce() -> F + Send,
+        D: Default;
 }
 
-#[derive(Clone, Copy, Debug, Error)]
+#[derive(Clone, Debug, Error)]
 pub enum GetDirectoryError {
     #[error("expected directory, but file found")]
     UnexpectedFile,
@@ -52,7 +76,7 @@ pub enum GetDirectoryError {
     Get(#[from] GetError),
 }
 
-#[derive(Clone, Copy, Debug, Error)]
+#[derive(Clone, Debug, Error)]
 pub enum GetFileError {
     #[error("expected file, but directory found")]
     UnexpectedDirectory,
@@ -84,6 +108,8 @@ where
     async fn get_dir_default<P>(&self, path: P) -> Result<Directory<D, F>, GetDirectoryError>
     where
         P: AsRef<Path> + Send,
+        D: Default,
+        F: Default,
     {
         match self.get_default(path, GetType::Directory).await {
             Ok(Node::Directory(dir)) => Ok(dir),
@@ -92,6 +118,25 @@ where
         }
     }
 
+    async fn get_dir_or_create_with<P, C>(
+        &self,
+        path: P,
+        create: C,
+    ) -> Result<Directory<D, F>, GetDirectoryError>
+    where
+        P: AsRef<Path> + Send,
+        C: Fn() -> D + Send + Sync,
+    {
+        match self
+            .get_or_create_with(path, &create, || Stored::Directory(create()))
+            .await
+        {
+            Ok(Node::Directory(dir)) => Ok(dir),
+            Ok(Node::File(_)) => Err(GetDirectoryError::UnexpectedFile),
+            Err(err) => Err(GetDirectoryError::Get(err)),
+        }
+    }
+
     async fn get_file<P>(&self, path: P) -> Result<Option<File<D, F>>, GetFileError>
     where
         P: AsRef<Path> + Send,
@@ -107,6 +152,8 @@ where
     async fn get_file_default<P>(&self, path: P) -> Result<File<D, F>, GetFileError>
     where
         P: AsRef<Path> + Send,
+        D: Default,
+        F: Default,
     {
         match self.get_default(path, GetType::File).await {
             Ok(Node::Directory(_)) => Err(GetFileError::UnexpectedDirectory),
@@ -114,4 +161,43 @@ where
             Err(err) => Err(GetFileError::Get(err)),
         }
     }
+
+    async fn get_file_or_create_with<P, C>(
+        &self,
+        path: P,
+        create: C,
+    ) -> Result<File<D, F>, GetFileError>
+    where
+        P: AsRef<Path> + Send,
+        C: FnOnce() -> F + Send,
+        D: Default,
+    {
+        file_or_create_with(self, path, D::default, create).await
+    }
+}
+
+// Functions
+
+pub async fn file_or_create_with<G, D, F, P, B, C>(
+    get: &G,
+    path: P,
+    parents: B,
+    create: C,
+) -> Result<File<D, F>, GetFileError>
+where
+    G: Get<D, F> + Sync,
+    D: ValueType,
+    F: ValueType,
+    P: AsRef<Path> + Send,
+    B: Fn() -> D + Send + Sync,
+    C: FnOnce() -> F + Send,
+{
+    match get
+        .get_or_create_with(path, parents, || Stored::File(create()))
+        .await
+    {
+        Ok(Node::Directory(_)) => Err(GetFileError::UnexpectedDirectory),
+        Ok(Node::File(file)) => Ok(file),
+        Err(err) => Err(GetFileError::Get(err)),
+    }
 }
@@ -0,0 +1,256 @@
+use std::{
+    collections::BTreeMap,
+    ffi::OsStr,
+    path::Path,
+};
+
+use async_trait::async_trait;
+use futures::future;
+
+use super::{
+    super::node::{
+        data::ValueType,
+        root::Root,
+        Node,
+    },
+    get::{
+        GetError,
+        GetErrorKind,
+    },
+    scope::{
+        confine,
+        Scope,
+    },
+    Create,
+    Directory,
+    Init,
+    Parents,
+    Step,
+};
+
+// GetMany
+
+#[async_trait]
+pub trait GetMany<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_many<I, P>(&self, paths: I) -> Vec<Result<Option<Node<D, F>>, GetError>>
+    where
+        I: IntoIterator<Item = P> + Send,
+        I::IntoIter: Send,
+        P: AsRef<Path> + Send;
+}
+
+// GetMany - Implementations
+
+#[async_trait]
+impl<D, F> GetMany<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_many<I, P>(&self, paths: I) -> Vec<Result<Option<Node<D, F>>, GetError>>
+    where
+        I: IntoIterator<Item = P> + Send,
+        I::IntoIter: Send,
+        P: AsRef<Path> + Send,
+    {
+        let paths = paths.into_iter().collect::<Vec<_>>();
+        let paths = paths.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+
+        self.get_all(&paths).await
+    }
+}
+
+#[async_trait]
+impl<D, F> GetMany<D, F> for Scope<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_many<I, P>(&self, paths: I) -> Vec<Result<Option<Node<D, F>>, GetError>>
+    where
+        I: IntoIterator<Item = P> + Send,
+        I::IntoIter: Send,
+        P: AsRef<Path> + Send,
+    {
+        let confined = paths
+            .into_iter()
+            .map(|path| confine(path.as_ref()))
+            .collect::<Vec<_>>();
+        let paths = confined
+            .iter()
+            .filter_map(|path| path.as_deref().ok())
+            .collect::<Vec<_>>();
+        let mut resolved = self.root().get_all(&paths).await.into_iter();
+
+        confined
+            .into_iter()
+            .filter_map(|path| match path {
+                Ok(_) => resolved.next(),
+                Err(err) => Some(Err(err)),
+            })
+            .collect()
+    }
+}
+
+// Directory - GetMany
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[allow(clippy::match_bool)]
+    async fn get_all(&self, paths: &[&Path]) -> Vec<Result<Option<Node<D, F>>, GetError>> {
+        let mut results = paths.iter().map(|_| None).collect::<Vec<_>>();
+        let mut walks = Vec::new();
+        let mut others = Vec::new();
+
+        for (index, path) in paths.iter().enumerate() {
+            let steps = path.components().map(Step::from).collect::<Vec<_>>();
+
+            if let Err(err) = self.limit_components(steps.len(), || path.to_path_buf()) {
+                results[index] = Some(Err(err));
+                continue;
+            }
+
+            match Walk::new(index, &steps) {
+                Some(walk) if walk.root && !self.is_root().await => {
+                    let kind = GetErrorKind::UnexpectedRoot;
+
+                    results[index] = Some(Err(GetError::new(kind, path, 0)));
+                }
+                Some(walk) => walks.push(walk),
+                _ => others.push(index),
+            }
+        }
+
+        let resolved = future::join_all(
+            others
+                .iter()
+                .map(|index| self.get(paths[*index], None::<Create<Parents<D>, Init<D, F>>>)),
+        )
+        .await;
+
+        for (index, result) in others.into_iter().zip(resolved) {
+            results[index] = Some(result);
+        }
+
+        let start = self.depth();
+        let mut found = Vec::new();
+        let mut pending = vec![(self.clone(), 0, (0..walks.len()).collect::<Vec<_>>())];
+
+        while let Some((dir, depth, ids)) = pending.pop() {
+            let (done, ids) = ids
+                .into_iter()
+                .partition::<Vec<_>, _>(|id| walks[*id].names.len() == depth);
+
+            found.extend(
+                done.into_iter()
+                    .map(|id| (id, Some(Node::Directory(dir.clone())))),
+            );
+
+            if ids.is_empty() {
+                continue;
+            }
+
+            let kind = match self.exceeds_traversal(&dir, start) {
+                true => Some(GetErrorKind::TraversalLimitExceeded),
+                _ => dir.populate().await.err().map(|_| GetErrorKind::LoadFailed),
+            };
+
+            if let Some(kind) = kind {
+                for id in ids {
+                    let walk = &walks[id];
+
+                    results[walk.index] = Some(Err(walk.error(kind, paths, depth)));
+                }
+
+                continue;
+            }
+
+            let mut children = BTreeMap::<&OsStr, Vec<usize>>::new();
+
+            for id in ids {
+                children.entry(walks[id].names[depth].1).or_default().push(id);
+            }
+
+            let names = children.keys().copied().collect::<Vec<_>>();
+            let nodes = dir.children.get_many(&names).await;
+
+            for (ids, node) in children.into_values().zip(nodes) {
+                match node {
+                    Some(Node::Directory(child)) => pending.push((child, depth + 1, ids)),
+                    Some(Node::File(file)) => {
+                        for id in ids {
+                            let walk = &walks[id];
+
+                            match walk.names.len() == depth + 1 {
+                                true => found.push((id, Some(Node::File(file.clone())))),
+                                _ => {
+                                    results[walk.index] = Some(Err(walk.error(
+                                        GetErrorKind::UnexpectedFile,
+                                        paths,
+                                        depth + 1,
+                                    )));
+                                }
+                            }
+                        }
+                    }
+                    None => found.extend(ids.into_iter().map(|id| (id, None))),
+                }
+            }
+        }
+
+        for (id, node) in found {
+            let walk = &walks[id];
+            let path = paths[walk.index];
+            let result = self.accept(node, || path.to_path_buf(), walk.last).await;
+
+            results[walk.index] = Some(result);
+        }
+
+        results.into_iter().flatten().collect()
+    }
+}
+
+// Walk
+
+struct Walk<'a> {
+    index: usize,
+    last: usize,
+    names: Vec<(usize, &'a OsStr)>,
+    root: bool,
+}
+
+// Walk - Methods
+
+impl<'a> Walk<'a> {
+    fn new(index: usize, steps: &[Step<'a>]) -> Option<Self> {
+        let mut names = Vec::new();
+        let mut root = false;
+
+        for (position, step) in steps.iter().enumerate() {
+            match step {
+                Step::Root if position == 0 => root = true,
+                Step::Current => {}
+                Step::Name(name) => names.push((position, *name)),
+                _ => return None,
+            }
+        }
+
+        Some(Self {
+            index,
+            last: steps.len().saturating_sub(1),
+            names,
+            root,
+        })
+    }
+
+    fn error(&self, kind: GetErrorKind, paths: &[&Path], depth: usize) -> GetError {
+        GetError::new(kind, paths[self.index], self.names[depth].0)
+    }
+}
@@ -0,0 +1,116 @@
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+};
+
+use async_trait::async_trait;
+use futures::{
+    future::BoxFuture,
+    FutureExt,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use thiserror::Error;
+
+use super::{
+    super::node::{
+        data::{
+            Data,
+            ValueType,
+        },
+        data_ext::WriteError,
+        Node,
+    },
+    Directory,
+};
+
+// ToJson
+
+#[async_trait]
+pub trait ToJson {
+    async fn to_json(&self) -> Result<String, JsonError>;
+}
+
+#[derive(Debug, Error)]
+pub enum JsonError {
+    #[error("json could not be encoded or decoded")]
+    Json(#[from] serde_json::Error),
+    #[error("node name is not valid UTF-8 or not a single path component: {0:?}")]
+    InvalidName(OsString),
+    #[error("json root must be a directory")]
+    InvalidRoot,
+    #[error("internal error writing node")]
+    Write(#[from] WriteError),
+}
+
+// ToJson - Implementation
+
+#[async_trait]
+impl<D, F> ToJson for Directory<D, F>
+where
+    D: ValueType + Clone + Serialize + 'static,
+    F: ValueType + Clone + Serialize + 'static,
+{
+    async fn to_json(&self) -> Result<String, JsonError> {
+        let json = json(Node::Directory(self.clone())).await?;
+
+        Ok(serde_json::to_string_pretty(&json)?)
+    }
+}
+
+// Json
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "lowercase"
+)]
+pub enum Json<D, F> {
+    Dir {
+        #[serde(default)]
+        value: D,
+        #[serde(default)]
+        children: BTreeMap<String, Self>,
+    },
+    File {
+        #[serde(default)]
+        value: F,
+    },
+}
+
+// Functions
+
+fn json<D, F>(node: Node<D, F>) -> BoxFuture<'static, Result<Json<D, F>, JsonError>>
+where
+    D: ValueType + Clone + 'static,
+    F: ValueType + Clone + 'static,
+{
+    async move {
+        match node {
+            Node::Directory(dir) => {
+                let mut children = BTreeMap::new();
+
+                let entries = dir.entries().await.map_err(|_| WriteError::LoadFailed)?;
+
+                for (name, node) in entries {
+                    let name = name
+                        .to_str()
+                        .ok_or_else(|| JsonError::InvalidName(name.to_os_string()))?;
+
+                    children.insert(String::from(name), json(node).await?);
+                }
+
+                Ok(Json::Dir {
+                    value: dir.data().await.read().await.clone(),
+                    children,
+                })
+            }
+            Node::File(file) => Ok(Json::File {
+                value: file.data().await.read().await.clone(),
+            }),
+        }
+    }
+    .boxed()
+}
@@ -0,0 +1,64 @@
+use std::{
+    ffi::OsString,
+    io,
+};
+
+use async_trait::async_trait;
+
+use super::{
+    super::node::{
+        data::ValueType,
+        Node,
+    },
+    Directory,
+};
+
+// List
+
+#[async_trait]
+pub trait List<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn list(&self) -> Vec<Listed<D, F>>;
+
+    async fn list_sorted(&self, directories_first: bool) -> Vec<Listed<D, F>>;
+
+    async fn try_list(&self) -> io::Result<Vec<Listed<D, F>>>;
+}
+
+pub type Listed<D, F> = (OsString, Node<D, F>);
+
+// List - Implementation
+
+#[async_trait]
+impl<D, F> List<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn list(&self) -> Vec<Listed<D, F>> {
+        self.try_list().await.unwrap_or_default()
+    }
+
+    async fn list_sorted(&self, directories_first: bool) -> Vec<Listed<D, F>> {
+        let mut listed = self.list().await;
+
+        listed.sort_by(|(a, a_node), (b, b_node)| {
+            let kind = |node: &Node<D, F>| directories_first && matches!(node, Node::File(_));
+
+            kind(a_node).cmp(&kind(b_node)).then_with(|| a.cmp(b))
+        });
+        listed
+    }
+
+    async fn try_list(&self) -> io::Result<Vec<Listed<D, F>>> {
+        self.entries().await.map(|entries| {
+            entries
+                .into_iter()
+                .map(|(name, node)| (name.to_os_string(), node))
+                .collect()
+        })
+    }
+}
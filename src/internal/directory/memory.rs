@@ -0,0 +1,135 @@
+use std::{
+    collections::BTreeMap,
+    ffi::{
+        OsStr,
+        OsString,
+    },
+    mem,
+    sync::{
+        atomic::AtomicUsize,
+        Arc,
+    },
+};
+
+use async_lock::RwLock;
+use async_trait::async_trait;
+use futures::{
+    future::BoxFuture,
+    FutureExt,
+};
+
+use super::{
+    super::{
+        file::Internal,
+        node::{
+            data::{
+                Data,
+                ValueType,
+            },
+            data_len::DataLen,
+            Node,
+        },
+    },
+    Directory,
+    Shared,
+};
+
+// MemoryUsage
+
+#[async_trait]
+pub trait MemoryUsage<F>
+where
+    F: ValueType,
+{
+    async fn memory_usage(&self) -> Usage;
+
+    async fn memory_usage_with_len(&self) -> Usage
+    where
+        F: DataLen;
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Usage {
+    pub children: BTreeMap<OsString, Self>,
+    pub contents: Option<usize>,
+    pub structure: usize,
+}
+
+// MemoryUsage - Implementation
+
+#[async_trait]
+impl<D, F> MemoryUsage<F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn memory_usage(&self) -> Usage {
+        usage(self, None).await
+    }
+
+    async fn memory_usage_with_len(&self) -> Usage
+    where
+        F: DataLen,
+    {
+        usage(self, Some(F::data_len)).await
+    }
+}
+
+// Usage - Methods
+
+impl Usage {
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.structure + self.contents.unwrap_or_default()
+    }
+}
+
+// Functions
+
+const ARC: usize = 2 * mem::size_of::<AtomicUsize>();
+
+fn usage<D, F>(dir: &Directory<D, F>, len: Option<fn(&F) -> usize>) -> BoxFuture<'_, Usage>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async move {
+        let mut total = Usage {
+            contents: len.map(|_| 0),
+            structure: ARC + mem::size_of::<Shared<D, F>>() + ARC + mem::size_of::<RwLock<D>>(),
+            ..Usage::default()
+        };
+
+        for (name, node) in dir.entries().await.unwrap_or_default() {
+            total.structure += ARC + name.len() + mem::size_of::<(Arc<OsStr>, Node<D, F>)>();
+
+            match node {
+                Node::Directory(dir) => {
+                    let child = usage(&dir, len).await;
+
+                    total.structure += child.structure;
+                    total.contents = total.contents.zip(child.contents).map(|(a, b)| a + b);
+                    total.children.insert(name.to_os_string(), child);
+                }
+                Node::File(file) => {
+                    total.structure += ARC
+                        + mem::size_of::<RwLock<Internal<D, F>>>()
+                        + ARC
+                        + mem::size_of::<RwLock<F>>();
+
+                    if let (Some(len), Some(contents)) = (len, total.contents.as_mut()) {
+                        *contents += file
+                            .data()
+                            .then(
+                                |value| async move { value.read().map(|value| len(&value)).await },
+                            )
+                            .await;
+                    }
+                }
+            }
+        }
+
+        total
+    }
+    .boxed()
+}
@@ -0,0 +1,273 @@
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use super::{
+    super::{
+        file::File,
+        node::{
+            data::{
+                Data,
+                ValueType,
+            },
+            data_ext::{
+                write,
+                DataExt,
+                WriteError,
+            },
+            Node,
+        },
+    },
+    get_ext::{
+        GetDirectoryError,
+        GetFileError,
+    },
+    Directory,
+};
+
+// Merge
+
+#[async_trait]
+pub trait Merge<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn merge_from<P>(&self, other: &Directory<D, F>, policy: P) -> Result<(), MergeError>
+    where
+        P: Fn(&Path, Conflict<'_, D, F>) -> Resolution<F> + Send + Sync;
+}
+
+#[derive(Debug)]
+pub enum Conflict<'a, D, F> {
+    Data(&'a F, &'a F),
+    Directory(&'a D, &'a D),
+    Type,
+}
+
+#[derive(Debug)]
+pub enum Resolution<F> {
+    Ours,
+    Theirs,
+    Combined(F),
+}
+
+// MergeError
+
+#[derive(Clone, Debug, Error)]
+pub enum MergeError {
+    #[error("merge source is an ancestor of the merge target")]
+    Ancestor,
+    #[error("combined resolution at {0} replaces a directory, but can only produce a file")]
+    Combined(PathBuf),
+    #[error("internal error getting directory")]
+    GetDirectory(#[from] GetDirectoryError),
+    #[error("internal error getting file")]
+    GetFile(#[from] GetFileError),
+    #[error("merge target {0} no longer exists")]
+    Missing(PathBuf),
+    #[error("internal error writing node")]
+    Write(#[from] WriteError),
+}
+
+// Merge - Implementation
+
+#[async_trait]
+impl<D, F> Merge<D, F> for Directory<D, F>
+where
+    D: ValueType + Clone + PartialEq,
+    F: ValueType + Clone + PartialEq,
+{
+    #[allow(clippy::use_self)]
+    async fn merge_from<P>(&self, other: &Directory<D, F>, policy: P) -> Result<(), MergeError>
+    where
+        P: Fn(&Path, Conflict<'_, D, F>) -> Resolution<F> + Send + Sync,
+    {
+        self.merge_from_as(other, policy, None).await
+    }
+}
+
+// Directory - Merge
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType + Clone + PartialEq,
+    F: ValueType + Clone + PartialEq,
+{
+    pub(crate) async fn merge_from_as<P>(
+        &self,
+        other: &Self,
+        policy: P,
+        context: Option<&str>,
+    ) -> Result<(), MergeError>
+    where
+        P: Fn(&Path, Conflict<'_, D, F>) -> Resolution<F> + Send + Sync,
+    {
+        if !other.ptr_eq(self) && other.is_ancestor_of(self).await {
+            return Err(MergeError::Ancestor);
+        }
+
+        let steps = self.merge_steps(other, policy).await?;
+
+        for step in steps {
+            match step {
+                Step::Copy(parent, name, node) => copy(&parent, name, &node, context).await?,
+                Step::Directory(dir, value) => {
+                    write(&dir, |mut current| *current = value, context).await?;
+                }
+                Step::File(file, value) => {
+                    write(&file, |mut current| *current = value, context).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn merge_steps<P>(&self, other: &Self, policy: P) -> Result<Vec<Step<D, F>>, MergeError>
+    where
+        P: Fn(&Path, Conflict<'_, D, F>) -> Resolution<F> + Send + Sync,
+    {
+        let mut pending = vec![(PathBuf::from("/"), self.clone(), other.clone())];
+        let mut steps = Vec::new();
+
+        while let Some((path, ours, theirs)) = pending.pop() {
+            let resolution = {
+                let (ours, theirs) = (ours.data().await, theirs.data().await);
+                let (ours, theirs) = (ours.read().await, theirs.read().await);
+
+                (*ours != *theirs).then(|| policy(&path, Conflict::Directory(&ours, &theirs)))
+            };
+
+            match resolution {
+                None | Some(Resolution::Ours) => {}
+                Some(Resolution::Theirs) => {
+                    let value = theirs.read(|value| value.clone()).await;
+
+                    steps.push(Step::Directory(ours.clone(), value));
+                }
+                Some(Resolution::Combined(_)) => return Err(MergeError::Combined(path)),
+            }
+
+            let entries = ours
+                .entries()
+                .await
+                .map_err(|_| WriteError::LoadFailed)?
+                .into_iter()
+                .map(|(name, node)| (ours.children.normalized(&name), node))
+                .collect::<HashMap<_, _>>();
+
+            let children = theirs.entries().await.map_err(|_| WriteError::LoadFailed)?;
+
+            for (name, node) in children {
+                let path = path.join(&*name);
+                let existing = entries.get(&ours.children.normalized(&name)).cloned();
+
+                match (existing, node) {
+                    (Some(Node::Directory(ours)), Node::Directory(theirs)) => {
+                        pending.push((path, ours, theirs));
+                    }
+                    (Some(Node::File(ours)), Node::File(theirs)) => {
+                        let resolution = {
+                            let (ours, theirs) = (ours.data().await, theirs.data().await);
+                            let (ours, theirs) = (ours.read().await, theirs.read().await);
+
+                            (*ours != *theirs)
+                                .then(|| policy(&path, Conflict::Data(&ours, &theirs)))
+                        };
+
+                        match resolution {
+                            None | Some(Resolution::Ours) => {}
+                            Some(Resolution::Theirs) => {
+                                let value = theirs.read(|value| value.clone()).await;
+
+                                steps.push(Step::File(ours, value));
+                            }
+                            Some(Resolution::Combined(value)) => {
+                                steps.push(Step::File(ours, value));
+                            }
+                        }
+                    }
+                    (Some(_), theirs) => match policy(&path, Conflict::Type) {
+                        Resolution::Ours => {}
+                        Resolution::Theirs => steps.push(Step::Copy(ours.clone(), name, theirs)),
+                        Resolution::Combined(_) => return Err(MergeError::Combined(path)),
+                    },
+                    (None, theirs) => steps.push(Step::Copy(ours.clone(), name, theirs)),
+                }
+            }
+        }
+
+        Ok(steps)
+    }
+}
+
+// Step
+
+enum Step<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    Copy(Directory<D, F>, Arc<OsStr>, Node<D, F>),
+    Directory(Directory<D, F>, D),
+    File(File<D, F>, F),
+}
+
+// Functions
+
+async fn copy<D, F>(
+    parent: &Directory<D, F>,
+    name: Arc<OsStr>,
+    node: &Node<D, F>,
+    context: Option<&str>,
+) -> Result<(), WriteError>
+where
+    D: ValueType + Clone,
+    F: ValueType + Clone,
+{
+    match node {
+        Node::Directory(source) => {
+            let value = source.read(|value| value.clone()).await;
+            let mut pending = vec![(
+                parent.insert_dir(name, value, context).await?,
+                source.clone(),
+            )];
+
+            while let Some((target, source)) = pending.pop() {
+                let entries = source.entries().await.map_err(|_| WriteError::LoadFailed)?;
+
+                for (name, node) in entries {
+                    match node {
+                        Node::Directory(source) => {
+                            let value = source.read(|value| value.clone()).await;
+
+                            pending.push((target.insert_dir(name, value, context).await?, source));
+                        }
+                        Node::File(source) => {
+                            let value = source.read(|value| value.clone()).await;
+
+                            target.insert_file(name, value, context).await?;
+                        }
+                    }
+                }
+            }
+        }
+        Node::File(source) => {
+            let value = source.read(|value| value.clone()).await;
+
+            parent.insert_file(name, value, context).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use futures::FutureExt;
+
+use super::{
+    super::node::{
+        data::ValueType,
+        NodeKind,
+    },
+    get::{
+        Get,
+        GetType,
+    },
+};
+
+// Probe
+
+#[async_trait]
+pub trait Probe<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn exists<P>(&self, path: P) -> bool
+    where
+        P: AsRef<Path> + Send;
+
+    async fn node_type<P>(&self, path: P) -> Option<NodeKind>
+    where
+        P: AsRef<Path> + Send;
+}
+
+// Probe - Blanket Implementation
+
+#[async_trait]
+impl<D, F, G> Probe<D, F> for G
+where
+    G: Get<D, F> + Sync,
+    D: ValueType,
+    F: ValueType,
+{
+    async fn exists<P>(&self, path: P) -> bool
+    where
+        P: AsRef<Path> + Send,
+    {
+        self.node_type(path).map(|kind| kind.is_some()).await
+    }
+
+    async fn node_type<P>(&self, path: P) -> Option<NodeKind>
+    where
+        P: AsRef<Path> + Send,
+    {
+        match self.get(path, GetType::Directory).await {
+            Ok(Some(node)) => Some(node.kind()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    cmp,
+    ffi::OsStr,
+    path::Path,
+    ptr,
+    sync::{
+        atomic::Ordering,
+        Arc,
+    },
+};
+
+use async_lock::{
+    RwLock,
+    RwLockWriteGuard,
+};
+use async_trait::async_trait;
+use thiserror::Error;
+
+use super::{
+    super::{
+        file_system::{
+            access::Action,
+            journal::Record,
+            undo::Operation,
+        },
+        node::{
+            child::Child,
+            data::ValueType,
+            data_ext::WriteError,
+            located::Located,
+            permissions::Permissions,
+            Node,
+        },
+    },
+    get_ext::{
+        GetDirectoryError,
+        GetExt,
+    },
+    Directory,
+    Entries,
+};
+
+// Rename
+
+#[async_trait]
+pub trait Rename {
+    async fn rename<P, Q>(&self, from: P, to: Q) -> Result<(), RenameError>
+    where
+        P: AsRef<Path> + Send,
+        Q: AsRef<Path> + Send;
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum RenameError {
+    #[error("rename would move a directory inside itself")]
+    Cycle,
+    #[error("internal error getting directory")]
+    GetDirectory(#[from] GetDirectoryError),
+    #[error("path has no final name component")]
+    InvalidPath,
+    #[error("target directory is not empty")]
+    NotEmpty,
+    #[error("source path does not exist")]
+    NotFound,
+    #[error("source is a directory, but target is a file")]
+    UnexpectedFile,
+    #[error("source is a file, but target is a directory")]
+    UnexpectedDirectory,
+    #[error("internal error writing directory")]
+    Write(#[from] WriteError),
+}
+
+// Rename - Implementation
+
+#[async_trait]
+impl<D, F> Rename for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn rename<P, Q>(&self, from: P, to: Q) -> Result<(), RenameError>
+    where
+        P: AsRef<Path> + Send,
+        Q: AsRef<Path> + Send,
+    {
+        self.rename_as(from.as_ref(), to.as_ref(), None).await
+    }
+}
+
+// Directory - Rename
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) async fn rename_as(
+        &self,
+        from: &Path,
+        to: &Path,
+        context: Option<&str>,
+    ) -> Result<(), RenameError> {
+        let (from_dir, from_name) = self.split(from).await?;
+        let (to_dir, to_name) = self.split(to).await?;
+        let node = from_dir
+            .get_child(&from_name)
+            .await?
+            .ok_or(RenameError::NotFound)?;
+
+        if from_dir.ptr_eq(&to_dir) && from_name == to_name {
+            return Ok(());
+        }
+
+        if let Node::Directory(dir) = &node {
+            if dir.is_ancestor_of(&to_dir).await {
+                return Err(RenameError::Cycle);
+            }
+        }
+
+        if from_dir.readonly().await {
+            return Err(WriteError::PermissionDenied.into());
+        }
+
+        from_dir.authorize(Action::Remove, &from_name).await?;
+        to_dir.authorize(Action::Create, &to_name).await?;
+
+        let replaced = to_dir
+            .relink(&from_dir, &from_name, to_name.clone(), &node)
+            .await?;
+        let (from_path, to_path) = (
+            from_dir.path().await.join(&*from_name),
+            to_dir.path().await.join(&*to_name),
+        );
+
+        if let Some(journal) = &self.hooks().journal {
+            journal.append(Record::Rename {
+                from: from_path.clone(),
+                to: to_path.clone(),
+            });
+        }
+
+        to_dir
+            .renamed(from_path, to_path, &node, replaced.as_ref(), context)
+            .await;
+
+        self.hooks().record(|| Operation::Rename {
+            from: (from_dir.reference_weak(), from_name),
+            to: (to_dir.reference_weak(), to_name),
+            node,
+            replaced,
+        });
+
+        Ok(())
+    }
+
+    pub(crate) async fn is_ancestor_of(&self, dir: &Self) -> bool {
+        let mut current = Some(dir.clone());
+
+        while let Some(dir) = current {
+            if dir.ptr_eq(self) {
+                return true;
+            }
+
+            current = dir.parent().await;
+        }
+
+        false
+    }
+
+    async fn relink(
+        &self,
+        from: &Self,
+        from_name: &Arc<OsStr>,
+        name: Arc<OsStr>,
+        node: &Node<D, F>,
+    ) -> Result<Option<Node<D, F>>, RenameError> {
+        let (reference, context) = self.reference().await?;
+        let same = from.ptr_eq(self);
+        let (nodes, size) = Self::usage(&context, node.clone()).await;
+
+        from.populate().await.map_err(|_| WriteError::LoadFailed)?;
+
+        if let Some(Node::Directory(dir)) = self.get_child(&name).await? {
+            dir.populate().await.map_err(|_| WriteError::LoadFailed)?;
+        }
+
+        let unindexed = from.indexed(from_name, None).await;
+        let indexed = self.indexed(&name, Some(node)).await;
+        let mut guards = Guards::lock(
+            from.children.shard(from_name),
+            self.children.shard(&name),
+        )
+        .await;
+
+        if !guards
+            .source()
+            .get(from_name)
+            .is_some_and(|current| current.same_node(node))
+        {
+            return Err(WriteError::Conflict.into());
+        }
+
+        if let Some(existing) = guards.target().get(&name) {
+            replaceable(node, existing)?;
+        }
+
+        if !same && !guards.target().contains_key(&name) {
+            let source = from.context();
+
+            source.quota.release(nodes, size);
+
+            if !context
+                .quota
+                .admit_tree(self.children.len.load(Ordering::SeqCst), nodes, size)
+            {
+                source.quota.restore(nodes, size);
+
+                return Err(WriteError::QuotaExceeded.into());
+            }
+        }
+
+        guards.source().remove(from_name);
+
+        let replaced = guards.target().insert(name.clone(), node.clone());
+
+        match (same, &replaced) {
+            (true, Some(_)) => self.children.release(),
+            (false, Some(_)) => from.children.release(),
+            (false, None) => {
+                from.children.release();
+                self.children.reserve();
+            }
+            (true, None) => {}
+        }
+
+        from.reindex(unindexed);
+        self.reindex(indexed);
+
+        drop(guards);
+
+        if let Some(replaced) = &replaced {
+            self.release(replaced.clone()).await;
+        }
+
+        self.relocate(node.clone(), (name, reference)).await;
+        self.track(node).await;
+        from.bump().await;
+        self.bump().await;
+
+        Ok(replaced)
+    }
+
+    async fn split(&self, path: &Path) -> Result<(Self, Arc<OsStr>), RenameError> {
+        let name = path.file_name().ok_or(RenameError::InvalidPath)?;
+        let parent = path.parent().ok_or(RenameError::InvalidPath)?;
+
+        self.get_dir(parent)
+            .await?
+            .map(|dir| (dir, Arc::from(name)))
+            .ok_or(RenameError::NotFound)
+    }
+}
+
+// Functions
+
+fn replaceable<D, F>(node: &Node<D, F>, existing: &Node<D, F>) -> Result<(), RenameError>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    match (node, existing) {
+        _ if node.same_node(existing) => Ok(()),
+        (Node::Directory(_), Node::File(_)) => Err(RenameError::UnexpectedFile),
+        (Node::File(_), Node::Directory(_)) => Err(RenameError::UnexpectedDirectory),
+        (Node::Directory(_), Node::Directory(dir))
+            if dir.children.len.load(Ordering::SeqCst) > 0 =>
+        {
+            Err(RenameError::NotEmpty)
+        }
+        _ => Ok(()),
+    }
+}
+
+// Guards
+
+enum Guards<'a, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    One(RwLockWriteGuard<'a, Entries<D, F>>),
+    Two(RwLockWriteGuard<'a, Entries<D, F>>, RwLockWriteGuard<'a, Entries<D, F>>),
+}
+
+// Guards - Methods
+
+impl<'a, D, F> Guards<'a, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn lock(source: &'a RwLock<Entries<D, F>>, target: &'a RwLock<Entries<D, F>>) -> Self {
+        let order = ptr::from_ref(source).cmp(&ptr::from_ref(target));
+
+        match order {
+            cmp::Ordering::Equal => Self::One(source.write().await),
+            cmp::Ordering::Less => {
+                let source = source.write().await;
+
+                Self::Two(source, target.write().await)
+            }
+            cmp::Ordering::Greater => {
+                let target = target.write().await;
+
+                Self::Two(source.write().await, target)
+            }
+        }
+    }
+
+    fn source(&mut self) -> &mut Entries<D, F> {
+        match self {
+            Self::One(guard) | Self::Two(guard, _) => guard,
+        }
+    }
+
+    fn target(&mut self) -> &mut Entries<D, F> {
+        match self {
+            Self::One(guard) | Self::Two(_, guard) => guard,
+        }
+    }
+}
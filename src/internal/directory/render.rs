@@ -0,0 +1,120 @@
+use std::fmt::Write;
+
+use async_trait::async_trait;
+use futures::FutureExt;
+
+use super::{
+    super::node::{
+        data::{
+            Data,
+            ValueType,
+        },
+        named::Named,
+        Node,
+    },
+    Directory,
+};
+
+// Render
+
+#[async_trait]
+pub trait Render<F>
+where
+    F: ValueType,
+{
+    async fn render_tree(&self) -> String;
+
+    async fn render_tree_with<S>(&self, summary: S) -> String
+    where
+        S: Fn(&F) -> String + Send + Sync;
+}
+
+// Render - Implementation
+
+#[async_trait]
+impl<D, F> Render<F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn render_tree(&self) -> String {
+        render(self, None::<fn(&F) -> String>).await
+    }
+
+    async fn render_tree_with<S>(&self, summary: S) -> String
+    where
+        S: Fn(&F) -> String + Send + Sync,
+    {
+        render(self, Some(summary)).await
+    }
+}
+
+#[allow(clippy::match_bool)]
+async fn render<D, F, S>(dir: &Directory<D, F>, summary: Option<S>) -> String
+where
+    D: ValueType,
+    F: ValueType,
+    S: Fn(&F) -> String + Send + Sync,
+{
+    let mut output = dir
+        .name()
+        .map(|name| {
+            name.map_or_else(
+                || String::from("/"),
+                |name| name.to_string_lossy().into_owned(),
+            )
+        })
+        .await;
+    let mut pending = vec![(
+        dir.ordered_entries().await.unwrap_or_default().into_iter(),
+        String::new(),
+    )];
+
+    output.push('\n');
+
+    while let Some((entries, prefix)) = pending.last_mut() {
+        let Some((name, node)) = entries.next() else {
+            pending.pop();
+            continue;
+        };
+
+        let last = entries.len() == 0;
+        let prefix = prefix.clone();
+        let branch = match last {
+            true => "└── ",
+            _ => "├── ",
+        };
+
+        let _ = write!(output, "{prefix}{branch}{}", name.to_string_lossy());
+
+        match node {
+            Node::Directory(dir) => {
+                let indent = match last {
+                    true => "    ",
+                    _ => "│   ",
+                };
+
+                pending.push((
+                    dir.ordered_entries().await.unwrap_or_default().into_iter(),
+                    format!("{prefix}{indent}"),
+                ));
+            }
+            Node::File(file) => {
+                if let Some(summary) = &summary {
+                    let summary = file
+                        .data()
+                        .then(
+                            |value| async move { value.read().map(|value| summary(&value)).await },
+                        )
+                        .await;
+
+                    let _ = write!(output, " ({summary})");
+                }
+            }
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
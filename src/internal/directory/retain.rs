@@ -0,0 +1,104 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use async_trait::async_trait;
+use futures::{
+    future::BoxFuture,
+    FutureExt,
+};
+
+use super::{
+    super::node::{
+        data::ValueType,
+        Node,
+    },
+    count::Count,
+    Directory,
+};
+
+// Retain
+
+#[async_trait]
+pub trait Retain<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn retain<P>(&self, predicate: P) -> usize
+    where
+        P: FnMut(&Path, &Node<D, F>) -> bool + Send;
+
+    async fn retain_and_prune<P>(&self, predicate: P) -> usize
+    where
+        P: FnMut(&Path, &Node<D, F>) -> bool + Send;
+}
+
+// Retain - Implementation
+
+#[async_trait]
+impl<D, F> Retain<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn retain<P>(&self, mut predicate: P) -> usize
+    where
+        P: FnMut(&Path, &Node<D, F>) -> bool + Send,
+    {
+        retain(self, PathBuf::from("/"), &mut predicate, false, None).await
+    }
+
+    async fn retain_and_prune<P>(&self, mut predicate: P) -> usize
+    where
+        P: FnMut(&Path, &Node<D, F>) -> bool + Send,
+    {
+        retain(self, PathBuf::from("/"), &mut predicate, true, None).await
+    }
+}
+
+// Functions
+
+pub fn retain<'a, D, F, P>(
+    dir: &'a Directory<D, F>,
+    path: PathBuf,
+    predicate: &'a mut P,
+    prune: bool,
+    context: Option<&'a str>,
+) -> BoxFuture<'a, usize>
+where
+    D: ValueType,
+    F: ValueType,
+    P: FnMut(&Path, &Node<D, F>) -> bool + Send,
+{
+    async move {
+        let mut removed = 0;
+
+        let Ok(entries) = dir.entries().await else {
+            return 0;
+        };
+
+        for (name, node) in entries {
+            let path = path.join(&*name);
+            let emptied = match &node {
+                Node::Directory(child) => {
+                    let descendants = retain(child, path.clone(), predicate, prune, context).await;
+
+                    removed += descendants;
+                    prune && descendants > 0 && child.count().await.is_ok_and(|count| count == 0)
+                }
+                Node::File(_) => false,
+            };
+
+            if (emptied || !predicate(&path, &node))
+                && matches!(dir.detach_as(&name, context).await, Ok(Some(_)))
+            {
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+    .boxed()
+}
@@ -0,0 +1,163 @@
+use std::path::{
+    Component,
+    Path,
+    PathBuf,
+};
+
+use async_trait::async_trait;
+
+use super::{
+    super::{
+        file_system::backend::Stored,
+        node::{
+            data::ValueType,
+            Node,
+        },
+    },
+    get::{
+        Get,
+        GetError,
+        GetErrorKind,
+        GetType,
+    },
+    get_ext::{
+        GetDirectoryError,
+        GetExt,
+    },
+    Directory,
+};
+
+// Scoped
+
+#[async_trait]
+pub trait Scoped<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn scope<P>(&self, path: P) -> Result<Option<Scope<D, F>>, GetDirectoryError>
+    where
+        P: AsRef<Path> + Send;
+}
+
+// Scoped - Blanket Implementation
+
+#[async_trait]
+impl<D, F, G> Scoped<D, F> for G
+where
+    G: Get<D, F> + Sync,
+    D: ValueType,
+    F: ValueType,
+{
+    async fn scope<P>(&self, path: P) -> Result<Option<Scope<D, F>>, GetDirectoryError>
+    where
+        P: AsRef<Path> + Send,
+    {
+        self.get_dir(path)
+            .await
+            .map(|dir| dir.map(|root| Scope { root }))
+    }
+}
+
+// Scope
+
+#[derive(Debug)]
+pub struct Scope<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    root: Directory<D, F>,
+}
+
+// Scope - Standard Traits
+
+impl<D, F> Clone for Scope<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+        }
+    }
+}
+
+// Scope - Library Traits
+
+#[async_trait]
+impl<D, F> Get<D, F> for Scope<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get<P>(&self, path: P, get_type: GetType) -> Result<Option<Node<D, F>>, GetError>
+    where
+        P: AsRef<Path> + Send,
+    {
+        Get::get(&self.root, confine(path.as_ref())?, get_type).await
+    }
+
+    async fn get_default<P>(&self, path: P, get_type: GetType) -> Result<Node<D, F>, GetError>
+    where
+        P: AsRef<Path> + Send,
+        D: Default,
+        F: Default,
+    {
+        self.root
+            .get_default(confine(path.as_ref())?, get_type)
+            .await
+    }
+
+    async fn get_or_create_with<P, B, C>(
+        &self,
+        path: P,
+        parents: B,
+        create: C,
+    ) -> Result<Node<D, F>, GetError>
+    where
+        P: AsRef<Path> + Send,
+        B: Fn() -> D + Send + Sync,
+        C: FnOnce() -> Stored<D, F> + Send,
+    {
+        self.root
+            .get_or_create_with(confine(path.as_ref())?, parents, create)
+            .await
+    }
+}
+
+// Scope - Methods
+
+impl<D, F> Scope<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub const fn root(&self) -> &Directory<D, F> {
+        &self.root
+    }
+}
+
+// Functions
+
+pub fn confine(path: &Path) -> Result<PathBuf, GetError> {
+    let mut confined = PathBuf::new();
+
+    for (index, component) in path.components().enumerate() {
+        match component {
+            Component::Prefix(_) => {
+                return Err(GetError::new(GetErrorKind::UnexpectedPrefix, path, index))
+            }
+            Component::RootDir => confined.clear(),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                confined.pop();
+            }
+            Component::Normal(name) => confined.push(name),
+        }
+    }
+
+    Ok(confined)
+}
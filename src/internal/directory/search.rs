@@ -0,0 +1,83 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use futures::{
+    future::BoxFuture,
+    stream::{
+        self,
+        BoxStream,
+    },
+    StreamExt,
+};
+
+use super::{
+    super::node::{
+        data::{
+            Data,
+            ValueType,
+        },
+        Node,
+    },
+    Directory,
+};
+
+// Search
+
+pub trait Search<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn search<'a, M, T>(&'a self, matcher: M) -> BoxStream<'a, (PathBuf, T)>
+    where
+        M: for<'m> Fn(&'m Path, &'m F) -> BoxFuture<'m, Option<T>> + Send + Sync + 'a,
+        T: Send + 'a;
+}
+
+// Search - Implementation
+
+impl<D, F> Search<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn search<'a, M, T>(&'a self, matcher: M) -> BoxStream<'a, (PathBuf, T)>
+    where
+        M: for<'m> Fn(&'m Path, &'m F) -> BoxFuture<'m, Option<T>> + Send + Sync + 'a,
+        T: Send + 'a,
+    {
+        let pending = vec![(PathBuf::from("/"), Node::Directory(self.clone()))];
+
+        stream::unfold((pending, matcher), |(mut pending, matcher)| async move {
+            while let Some((path, node)) = pending.pop() {
+                match node {
+                    Node::Directory(dir) => pending.extend(
+                        dir.entries()
+                            .await
+                            .unwrap_or_default()
+                            .into_iter()
+                            .rev()
+                            .map(|(name, node)| (path.join(&*name), node)),
+                    ),
+                    Node::File(file) => {
+                        let value = file.data().await;
+                        let found = {
+                            let value = value.read().await;
+
+                            matcher(&path, &value).await
+                        };
+
+                        if let Some(found) = found {
+                            return Some(((path, found), (pending, matcher)));
+                        }
+                    }
+                }
+            }
+
+            None
+        })
+        .boxed()
+    }
+}
@@ -0,0 +1,163 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use super::{
+    super::{
+        file_system::backend::Stored,
+        node::{
+            data::ValueType,
+            Node,
+        },
+    },
+    get::{
+        GetError,
+        GetErrorKind,
+        GetType,
+    },
+    Create,
+    Directory,
+    Init,
+    Parents,
+    Step,
+};
+
+// GetSegments
+
+#[async_trait]
+pub trait GetSegments<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_segments<S>(&self, segments: S) -> Result<Option<Node<D, F>>, GetError>
+    where
+        S: IntoIterator + Send,
+        S::Item: AsRef<str> + Send + Sync;
+
+    async fn get_segments_default<S>(
+        &self,
+        segments: S,
+        get_type: GetType,
+    ) -> Result<Node<D, F>, GetError>
+    where
+        S: IntoIterator + Send,
+        S::Item: AsRef<str> + Send + Sync,
+        D: Default,
+        F: Default;
+
+    async fn get_segments_or_create_with<S, B, C>(
+        &self,
+        segments: S,
+        parents: B,
+        create: C,
+    ) -> Result<Node<D, F>, GetError>
+    where
+        S: IntoIterator + Send,
+        S::Item: AsRef<str> + Send + Sync,
+        B: Fn() -> D + Send + Sync,
+        C: FnOnce() -> Stored<D, F> + Send;
+}
+
+// GetSegments - Implementation
+
+#[async_trait]
+impl<D, F> GetSegments<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_segments<S>(&self, segments: S) -> Result<Option<Node<D, F>>, GetError>
+    where
+        S: IntoIterator + Send,
+        S::Item: AsRef<str> + Send + Sync,
+    {
+        self.get_segments_with(segments, None::<Create<Parents<D>, Init<D, F>>>)
+            .await
+    }
+
+    async fn get_segments_default<S>(
+        &self,
+        segments: S,
+        get_type: GetType,
+    ) -> Result<Node<D, F>, GetError>
+    where
+        S: IntoIterator + Send,
+        S::Item: AsRef<str> + Send + Sync,
+        D: Default,
+        F: Default,
+    {
+        self.get_segments_or_create_with(segments, D::default, move || match get_type {
+            GetType::Directory => Stored::Directory(D::default()),
+            GetType::File => Stored::File(F::default()),
+        })
+        .await
+    }
+
+    async fn get_segments_or_create_with<S, B, C>(
+        &self,
+        segments: S,
+        parents: B,
+        create: C,
+    ) -> Result<Node<D, F>, GetError>
+    where
+        S: IntoIterator + Send,
+        S::Item: AsRef<str> + Send + Sync,
+        B: Fn() -> D + Send + Sync,
+        C: FnOnce() -> Stored<D, F> + Send,
+    {
+        let segments = segments.into_iter().collect::<Vec<_>>();
+
+        match self
+            .get_segments_with(&segments, Some((parents, create)))
+            .await
+        {
+            Ok(Some(node)) => Ok(node),
+            Ok(None) => Err(GetError::new(
+                GetErrorKind::Other,
+                &path(&segments),
+                segments.len().saturating_sub(1),
+            )),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+// Directory - Segments
+
+impl<D, F> Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_segments_with<S, B, I>(
+        &self,
+        segments: S,
+        create: Option<Create<B, I>>,
+    ) -> Result<Option<Node<D, F>>, GetError>
+    where
+        S: IntoIterator + Send,
+        S::Item: AsRef<str> + Send + Sync,
+        B: Fn() -> D + Send + Sync,
+        I: FnOnce() -> Stored<D, F> + Send,
+    {
+        let segments = segments.into_iter().collect::<Vec<_>>();
+
+        let steps = segments
+            .iter()
+            .map(|segment| Step::from(segment.as_ref()))
+            .collect();
+
+        self.resolve(steps, || path(&segments), create, self.context().clamp_parent)
+            .await
+    }
+}
+
+// Functions
+
+fn path<S>(segments: &[S]) -> PathBuf
+where
+    S: AsRef<str>,
+{
+    segments.iter().map(AsRef::as_ref).collect()
+}
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+
+use super::{
+    super::{
+        file::len::Len,
+        node::{
+            data::ValueType,
+            data_len::DataLen,
+            Node,
+        },
+    },
+    Directory,
+};
+
+// Size
+
+#[async_trait]
+pub trait Size {
+    async fn size(&self) -> usize;
+}
+
+// Size - Implementation
+
+#[async_trait]
+impl<D, F> Size for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType + DataLen,
+{
+    async fn size(&self) -> usize {
+        let mut size = 0;
+        let mut pending = vec![self.clone()];
+
+        while let Some(dir) = pending.pop() {
+            for (_, node) in dir.entries().await.unwrap_or_default() {
+                match node {
+                    Node::Directory(dir) => pending.push(dir),
+                    Node::File(file) => size += file.len().await,
+                }
+            }
+        }
+
+        size
+    }
+}
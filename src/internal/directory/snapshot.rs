@@ -0,0 +1,81 @@
+use std::io::{
+    self,
+    Write,
+};
+
+use async_trait::async_trait;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use thiserror::Error;
+
+use super::{
+    super::node::{
+        data::ValueType,
+        data_ext::WriteError,
+        Node,
+    },
+    tree::{
+        tree,
+        Tree,
+    },
+    Directory,
+};
+
+// SaveSnapshot
+
+#[async_trait]
+pub trait SaveSnapshot {
+    async fn save_snapshot<W>(&self, writer: W) -> Result<W, SnapshotError>
+    where
+        W: Write + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    #[error("snapshot could not be encoded or decoded")]
+    Encoding(#[from] postcard::Error),
+    #[error("snapshot is not in the memfs snapshot format")]
+    InvalidFormat,
+    #[error("snapshot could not be read or written")]
+    Io(#[from] io::Error),
+    #[error("snapshot format version {0} is not supported")]
+    UnsupportedVersion(u32),
+    #[error("internal error writing node")]
+    Write(#[from] WriteError),
+}
+
+// SaveSnapshot - Implementation
+
+#[async_trait]
+impl<D, F> SaveSnapshot for Directory<D, F>
+where
+    D: ValueType + Clone + Serialize + 'static,
+    F: ValueType + Clone + Serialize + 'static,
+{
+    async fn save_snapshot<W>(&self, writer: W) -> Result<W, SnapshotError>
+    where
+        W: Write + Send,
+    {
+        let image = Image {
+            magic: MAGIC,
+            version: VERSION,
+            root: tree(Node::Directory(self.clone())).await?,
+        };
+
+        Ok(postcard::to_io(&image, writer)?)
+    }
+}
+
+// Image
+
+pub const MAGIC: [u8; 4] = *b"MEMF";
+pub const VERSION: u32 = 1;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Image<D, F> {
+    pub(crate) magic: [u8; 4],
+    pub(crate) version: u32,
+    pub(crate) root: Tree<D, F>,
+}
@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use futures::FutureExt;
+
+use super::{
+    super::node::{
+        data::{
+            Data,
+            ValueType,
+        },
+        data_len::DataLen,
+        Node,
+    },
+    Directory,
+};
+
+// Statistics
+
+#[async_trait]
+pub trait Statistics<F>
+where
+    F: ValueType,
+{
+    async fn stats(&self) -> Stats;
+
+    async fn stats_with_len(&self) -> Stats
+    where
+        F: DataLen;
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub bytes: Option<usize>,
+    pub directories: usize,
+    pub files: usize,
+    pub max_depth: usize,
+    pub nodes: usize,
+}
+
+// Statistics - Implementation
+
+#[async_trait]
+impl<D, F> Statistics<F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn stats(&self) -> Stats {
+        stats(self, None).await
+    }
+
+    async fn stats_with_len(&self) -> Stats
+    where
+        F: DataLen,
+    {
+        stats(self, Some(F::data_len)).await
+    }
+}
+
+async fn stats<D, F>(dir: &Directory<D, F>, len: Option<fn(&F) -> usize>) -> Stats
+where
+    D: ValueType,
+    F: ValueType,
+{
+    let mut stats = Stats {
+        bytes: len.map(|_| 0),
+        ..Stats::default()
+    };
+    let mut pending = vec![(dir.clone(), 1)];
+
+    while let Some((dir, depth)) = pending.pop() {
+        for (_, node) in dir.entries().await.unwrap_or_default() {
+            stats.max_depth = stats.max_depth.max(depth);
+            stats.nodes += 1;
+
+            match node {
+                Node::Directory(dir) => {
+                    stats.directories += 1;
+                    pending.push((dir, depth + 1));
+                }
+                Node::File(file) => {
+                    stats.files += 1;
+
+                    if let (Some(len), Some(bytes)) = (len, stats.bytes.as_mut()) {
+                        *bytes += file
+                            .data()
+                            .then(
+                                |value| async move { value.read().map(|value| len(&value)).await },
+                            )
+                            .await;
+                    }
+                }
+            }
+        }
+    }
+
+    stats
+}
@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+
+use super::{
+    super::{
+        file_system::{
+            options::Options,
+            FileSystem,
+        },
+        node::{
+            data::ValueType,
+            data_ext::{
+                DataExt,
+                WriteError,
+            },
+            Node,
+        },
+    },
+    Directory,
+};
+
+// Transform
+
+#[async_trait]
+pub trait Transform<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn map_files<F2, MF>(&self, file_fn: MF) -> Result<FileSystem<D, F2>, WriteError>
+    where
+        D: Clone,
+        F2: ValueType,
+        MF: Fn(&F) -> F2 + Send + Sync;
+
+    async fn transform<D2, F2, MD, MF>(
+        &self,
+        dir_fn: MD,
+        file_fn: MF,
+    ) -> Result<FileSystem<D2, F2>, WriteError>
+    where
+        D2: ValueType,
+        F2: ValueType,
+        MD: Fn(&D) -> D2 + Send + Sync,
+        MF: Fn(&F) -> F2 + Send + Sync;
+}
+
+// Transform - Implementation
+
+#[async_trait]
+impl<D, F> Transform<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn map_files<F2, MF>(&self, file_fn: MF) -> Result<FileSystem<D, F2>, WriteError>
+    where
+        D: Clone,
+        F2: ValueType,
+        MF: Fn(&F) -> F2 + Send + Sync,
+    {
+        self.transform(D::clone, file_fn).await
+    }
+
+    async fn transform<D2, F2, MD, MF>(
+        &self,
+        dir_fn: MD,
+        file_fn: MF,
+    ) -> Result<FileSystem<D2, F2>, WriteError>
+    where
+        D2: ValueType,
+        F2: ValueType,
+        MD: Fn(&D) -> D2 + Send + Sync,
+        MF: Fn(&F) -> F2 + Send + Sync,
+    {
+        let root = self.read(|value| dir_fn(&value)).await;
+        let fs = FileSystem::with_root(Options::default(), root);
+        let mut pending = vec![(self.clone(), Directory::clone(&fs))];
+
+        while let Some((source, target)) = pending.pop() {
+            let entries = source.entries().await.map_err(|_| WriteError::LoadFailed)?;
+
+            for (name, node) in entries {
+                match node {
+                    Node::Directory(source) => {
+                        let value = source.read(|value| dir_fn(&value)).await;
+
+                        pending.push((source, target.insert_dir(name, value, None).await?));
+                    }
+                    Node::File(source) => {
+                        let value = source.read(|value| file_fn(&value)).await;
+
+                        target.insert_file(name, value, None).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(fs)
+    }
+}
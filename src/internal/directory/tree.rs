@@ -0,0 +1,125 @@
+use std::{
+    ffi::OsString,
+    io,
+    sync::Arc,
+};
+
+use futures::{
+    future::BoxFuture,
+    FutureExt,
+};
+#[cfg(feature = "snapshot")]
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::{
+    super::node::{
+        data::{
+            Data,
+            ValueType,
+        },
+        data_ext::WriteError,
+        permissions::Permissions,
+        Node,
+    },
+    attach::is_normal,
+    Directory,
+};
+
+// Tree
+
+#[derive(Debug)]
+#[cfg_attr(feature = "snapshot", derive(Deserialize, Serialize))]
+pub enum Tree<D, F> {
+    Directory {
+        entries: Vec<(OsString, Self)>,
+        readonly: bool,
+        value: D,
+    },
+    File {
+        readonly: bool,
+        value: F,
+    },
+}
+
+// Functions
+
+pub async fn entries<D, F>(dir: &Directory<D, F>) -> io::Result<Vec<(OsString, Tree<D, F>)>>
+where
+    D: ValueType + Clone + 'static,
+    F: ValueType + Clone + 'static,
+{
+    let mut entries = Vec::new();
+
+    for (name, node) in dir.ordered_entries().await? {
+        entries.push((name.to_os_string(), tree(node).await?));
+    }
+
+    Ok(entries)
+}
+
+pub fn tree<D, F>(node: Node<D, F>) -> BoxFuture<'static, io::Result<Tree<D, F>>>
+where
+    D: ValueType + Clone + 'static,
+    F: ValueType + Clone + 'static,
+{
+    async move {
+        let readonly = node.readonly().await;
+
+        Ok(match node {
+            Node::Directory(dir) => Tree::Directory {
+                entries: entries(&dir).await?,
+                readonly,
+                value: dir.data().await.read().await.clone(),
+            },
+            Node::File(file) => Tree::File {
+                readonly,
+                value: file.data().await.read().await.clone(),
+            },
+        })
+    }
+    .boxed()
+}
+
+pub fn restore<D, F>(
+    dir: &Directory<D, F>,
+    entries: Vec<(OsString, Tree<D, F>)>,
+) -> BoxFuture<'_, Result<(), WriteError>>
+where
+    D: ValueType + 'static,
+    F: ValueType + 'static,
+{
+    async move {
+        for (name, tree) in entries {
+            if !is_normal(&name) {
+                return Err(WriteError::InvalidName);
+            }
+
+            let name = Arc::from(name.as_os_str());
+
+            match tree {
+                Tree::Directory {
+                    entries,
+                    readonly,
+                    value,
+                } => {
+                    let child = dir.insert_dir(name, value, None).await?;
+
+                    restore(&child, entries).await?;
+                    child.set_readonly(readonly).await;
+                }
+                Tree::File { readonly, value } => {
+                    dir.insert_file(name, value, None)
+                        .await?
+                        .set_readonly(readonly)
+                        .await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+    .boxed()
+}
@@ -0,0 +1,78 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use async_trait::async_trait;
+
+use super::{
+    super::node::{
+        data::ValueType,
+        Node,
+    },
+    Directory,
+};
+
+// Visit
+
+#[async_trait]
+pub trait Visit<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn visit<V>(&self, visitor: V) -> bool
+    where
+        V: FnMut(&Path, &Node<D, F>) -> Flow + Send;
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Flow {
+    Continue,
+    SkipSubtree,
+    Stop,
+}
+
+// Visit - Implementation
+
+#[async_trait]
+impl<D, F> Visit<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn visit<V>(&self, mut visitor: V) -> bool
+    where
+        V: FnMut(&Path, &Node<D, F>) -> Flow + Send,
+    {
+        let mut pending = children(PathBuf::from("/"), self).await;
+
+        while let Some((path, node)) = pending.pop() {
+            match (visitor(&path, &node), node) {
+                (Flow::Stop, _) => return false,
+                (Flow::Continue, Node::Directory(dir)) => {
+                    pending.extend(children(path, &dir).await);
+                }
+                _ => {}
+            }
+        }
+
+        true
+    }
+}
+
+// Functions
+
+async fn children<D, F>(path: PathBuf, dir: &Directory<D, F>) -> Vec<(PathBuf, Node<D, F>)>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    dir.entries()
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .rev()
+        .map(|(name, node)| (path.join(&*name), node))
+        .collect()
+}
@@ -0,0 +1,113 @@
+use std::{
+    collections::VecDeque,
+    io,
+    path::PathBuf,
+};
+
+use futures::{
+    future::BoxFuture,
+    stream::{
+        self,
+        BoxStream,
+        FuturesUnordered,
+    },
+    FutureExt,
+    StreamExt,
+};
+
+use super::{
+    super::node::{
+        data::ValueType,
+        Node,
+    },
+    Directory,
+};
+
+// Walk
+
+pub trait Walk<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn walk_parallel(&self, concurrency: usize) -> BoxStream<'_, io::Result<Walked<D, F>>>;
+}
+
+pub type Walked<D, F> = (PathBuf, Node<D, F>);
+
+// Walk - Implementation
+
+impl<D, F> Walk<D, F> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn walk_parallel(&self, concurrency: usize) -> BoxStream<'_, io::Result<Walked<D, F>>> {
+        let state = State {
+            pending: vec![(PathBuf::from("/"), self.clone())],
+            ready: VecDeque::new(),
+            running: FuturesUnordered::new(),
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(entry) = state.ready.pop_front() {
+                    return Some((entry, state));
+                }
+
+                while state.running.len() < concurrency.max(1) {
+                    match state.pending.pop() {
+                        Some((path, dir)) => state.running.push(children(path, dir)),
+                        _ => break,
+                    }
+                }
+
+                match state.running.next().await? {
+                    Ok(children) => {
+                        for (path, node) in children {
+                            if let Node::Directory(dir) = &node {
+                                state.pending.push((path.clone(), dir.clone()));
+                            }
+
+                            state.ready.push_back(Ok((path, node)));
+                        }
+                    }
+                    Err(err) => state.ready.push_back(Err(err)),
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+// State
+
+struct State<'a, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pending: Vec<(PathBuf, Directory<D, F>)>,
+    ready: VecDeque<io::Result<Walked<D, F>>>,
+    running: FuturesUnordered<Children<'a, D, F>>,
+}
+
+type Children<'a, D, F> = BoxFuture<'a, io::Result<Vec<Walked<D, F>>>>;
+
+// Functions
+
+fn children<'a, D, F>(path: PathBuf, dir: Directory<D, F>) -> Children<'a, D, F>
+where
+    D: ValueType + 'a,
+    F: ValueType + 'a,
+{
+    async move {
+        Ok(dir
+            .entries()
+            .await?
+            .into_iter()
+            .map(|(name, node)| (path.join(&*name), node))
+            .collect())
+    }
+    .boxed()
+}
@@ -0,0 +1,103 @@
+use std::{
+    collections::VecDeque,
+    ffi::OsString,
+    io::{
+        self,
+        Seek,
+        Write,
+    },
+};
+
+use ::zip::{
+    result::ZipError as ArchiveError,
+    write::SimpleFileOptions,
+    ZipWriter,
+};
+use async_trait::async_trait;
+use thiserror::Error;
+
+use super::{
+    super::node::{
+        data::ValueType,
+        data_ext::{
+            DataExt,
+            WriteError,
+        },
+        Node,
+    },
+    get_ext::{
+        GetDirectoryError,
+        GetFileError,
+    },
+    Directory,
+};
+
+// ExportZip
+
+#[async_trait]
+pub trait ExportZip {
+    async fn export_zip<W>(&self, writer: W) -> Result<W, ZipError>
+    where
+        W: Write + Seek + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ZipError {
+    #[error("zip archive could not be read or written")]
+    Archive(#[from] ArchiveError),
+    #[error("zip archive entry could not be read or written")]
+    Io(#[from] io::Error),
+    #[error("zip archive entry has an unsafe path: {0}")]
+    UnsafePath(String),
+    #[error("zip archive has more than one entry for a path: {0}")]
+    Duplicate(String),
+    #[error("node name is not valid UTF-8, so cannot be a zip archive entry: {0:?}")]
+    InvalidName(OsString),
+    #[error("internal error getting directory")]
+    GetDirectory(#[from] GetDirectoryError),
+    #[error("internal error getting file")]
+    GetFile(#[from] GetFileError),
+    #[error("internal error writing file")]
+    Write(#[from] WriteError),
+}
+
+// ExportZip - Implementation
+
+#[async_trait]
+impl<D, F> ExportZip for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType + AsRef<[u8]>,
+{
+    async fn export_zip<W>(&self, writer: W) -> Result<W, ZipError>
+    where
+        W: Write + Seek + Send,
+    {
+        let mut zip = ZipWriter::new(writer);
+        let mut pending = VecDeque::from([(String::new(), self.clone())]);
+
+        while let Some((prefix, dir)) = pending.pop_front() {
+            for (name, node) in dir.ordered_entries().await? {
+                let name = name
+                    .to_str()
+                    .ok_or_else(|| ZipError::InvalidName(name.to_os_string()))?;
+                let path = format!("{prefix}{name}");
+
+                match node {
+                    Node::Directory(dir) => {
+                        zip.add_directory(path.as_str(), SimpleFileOptions::default())?;
+                        pending.push_back((format!("{path}/"), dir));
+                    }
+                    Node::File(file) => {
+                        let bytes = file.read(|value| value.as_ref().to_vec()).await;
+
+                        zip.start_file(path, SimpleFileOptions::default())?;
+                        zip.write_all(&bytes)?;
+                    }
+                }
+            }
+        }
+
+        Ok(zip.finish()?)
+    }
+}
@@ -0,0 +1,243 @@
+use std::io;
+
+use thiserror::Error as ThisError;
+
+#[cfg(feature = "json")]
+use super::directory::json::JsonError;
+#[cfg(feature = "snapshot")]
+use super::directory::snapshot::SnapshotError;
+#[cfg(feature = "zip")]
+use super::directory::zip::ZipError;
+#[cfg(feature = "tokio")]
+use super::file_system::disk::DiskError;
+#[cfg(any(feature = "toml", feature = "yaml"))]
+use super::file_system::fixture::FixtureError;
+use super::{
+    directory::{
+        attach::AttachError,
+        create_all::CreateError,
+        get::GetError,
+        get_ext::{
+            GetDirectoryError,
+            GetFileError,
+        },
+        merge::MergeError,
+        rename::RenameError,
+    },
+    file::history::RevertError,
+    file_system::{
+        backend::BackendError,
+        builder::BuildError,
+        journal::ReplayError,
+        undo::UndoError,
+    },
+    node::{
+        data_ext::WriteError,
+        NodeError,
+    },
+    path::MemPathError,
+};
+
+// Error
+
+#[derive(Debug, ThisError)]
+pub enum Error {
+    #[error("error resolving a path")]
+    Resolve(#[from] ResolveError),
+    #[error("error modifying the file system")]
+    Modify(#[from] ModifyError),
+    #[error("error loading or persisting the file system")]
+    Persist(#[from] PersistError),
+}
+
+#[derive(Clone, Debug, ThisError)]
+pub enum ResolveError {
+    #[error("error getting node")]
+    Get(#[from] GetError),
+    #[error("error getting directory")]
+    GetDirectory(#[from] GetDirectoryError),
+    #[error("error getting file")]
+    GetFile(#[from] GetFileError),
+    #[error("error checking node type")]
+    Node(#[from] NodeError),
+    #[error("error parsing path")]
+    Path(#[from] MemPathError),
+}
+
+#[derive(Clone, Debug, ThisError)]
+pub enum ModifyError {
+    #[error("error attaching node")]
+    Attach(#[from] AttachError),
+    #[error("error creating nodes")]
+    Create(#[from] CreateError),
+    #[error("error merging directories")]
+    Merge(#[from] MergeError),
+    #[error("error renaming node")]
+    Rename(#[from] RenameError),
+    #[error("error reverting file")]
+    Revert(#[from] RevertError),
+    #[error("error undoing operation")]
+    Undo(#[from] UndoError),
+    #[error("error writing node")]
+    Write(#[from] WriteError),
+}
+
+#[derive(Debug, ThisError)]
+pub enum PersistError {
+    #[error("error using backend")]
+    Backend(#[from] BackendError),
+    #[error("error building file system")]
+    Build(#[from] BuildError),
+    #[cfg(feature = "tokio")]
+    #[error("error reading or writing disk")]
+    Disk(#[from] DiskError),
+    #[cfg(any(feature = "toml", feature = "yaml"))]
+    #[error("error loading fixture")]
+    Fixture(#[from] FixtureError),
+    #[cfg(feature = "json")]
+    #[error("error reading or writing json")]
+    Json(#[from] JsonError),
+    #[error("error loading directory children")]
+    Load(#[from] io::Error),
+    #[error("error replaying journal")]
+    Replay(#[from] ReplayError),
+    #[cfg(feature = "snapshot")]
+    #[error("error reading or writing snapshot")]
+    Snapshot(#[from] SnapshotError),
+    #[cfg(feature = "zip")]
+    #[error("error reading or writing zip archive")]
+    Zip(#[from] ZipError),
+}
+
+// Error - Standard Traits
+
+impl From<GetError> for Error {
+    fn from(err: GetError) -> Self {
+        Self::Resolve(err.into())
+    }
+}
+
+impl From<GetDirectoryError> for Error {
+    fn from(err: GetDirectoryError) -> Self {
+        Self::Resolve(err.into())
+    }
+}
+
+impl From<GetFileError> for Error {
+    fn from(err: GetFileError) -> Self {
+        Self::Resolve(err.into())
+    }
+}
+
+impl From<NodeError> for Error {
+    fn from(err: NodeError) -> Self {
+        Self::Resolve(err.into())
+    }
+}
+
+impl From<MemPathError> for Error {
+    fn from(err: MemPathError) -> Self {
+        Self::Resolve(err.into())
+    }
+}
+
+impl From<AttachError> for Error {
+    fn from(err: AttachError) -> Self {
+        Self::Modify(err.into())
+    }
+}
+
+impl From<CreateError> for Error {
+    fn from(err: CreateError) -> Self {
+        Self::Modify(err.into())
+    }
+}
+
+impl From<MergeError> for Error {
+    fn from(err: MergeError) -> Self {
+        Self::Modify(err.into())
+    }
+}
+
+impl From<RenameError> for Error {
+    fn from(err: RenameError) -> Self {
+        Self::Modify(err.into())
+    }
+}
+
+impl From<RevertError> for Error {
+    fn from(err: RevertError) -> Self {
+        Self::Modify(err.into())
+    }
+}
+
+impl From<UndoError> for Error {
+    fn from(err: UndoError) -> Self {
+        Self::Modify(err.into())
+    }
+}
+
+impl From<WriteError> for Error {
+    fn from(err: WriteError) -> Self {
+        Self::Modify(err.into())
+    }
+}
+
+impl From<BackendError> for Error {
+    fn from(err: BackendError) -> Self {
+        Self::Persist(err.into())
+    }
+}
+
+impl From<BuildError> for Error {
+    fn from(err: BuildError) -> Self {
+        Self::Persist(err.into())
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl From<DiskError> for Error {
+    fn from(err: DiskError) -> Self {
+        Self::Persist(err.into())
+    }
+}
+
+#[cfg(any(feature = "toml", feature = "yaml"))]
+impl From<FixtureError> for Error {
+    fn from(err: FixtureError) -> Self {
+        Self::Persist(err.into())
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<JsonError> for Error {
+    fn from(err: JsonError) -> Self {
+        Self::Persist(err.into())
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Persist(err.into())
+    }
+}
+
+impl From<ReplayError> for Error {
+    fn from(err: ReplayError) -> Self {
+        Self::Persist(err.into())
+    }
+}
+
+#[cfg(feature = "snapshot")]
+impl From<SnapshotError> for Error {
+    fn from(err: SnapshotError) -> Self {
+        Self::Persist(err.into())
+    }
+}
+
+#[cfg(feature = "zip")]
+impl From<ZipError> for Error {
+    fn from(err: ZipError) -> Self {
+        Self::Persist(err.into())
+    }
+}
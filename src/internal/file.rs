@@ -1,17 +1,59 @@
+pub mod history;
+pub mod len;
+pub mod lock;
+pub mod open;
+pub mod subscribe;
+
 use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    ffi::{
+        OsStr,
+        OsString,
+    },
     ops::Deref,
-    sync::Arc,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+        Mutex as StdMutex,
+        Weak,
+    },
+    time::SystemTime,
 };
 
-use async_lock::RwLock;
+use async_lock::{
+    Mutex,
+    RwLock,
+};
 use async_trait::async_trait;
 use futures::FutureExt;
 
+use self::{
+    history::Versions,
+    subscribe::Subscribers,
+};
 use super::{
     directory::{
         Directory,
         Reference,
     },
+    file_system::{
+        access::Action,
+        context::Context,
+        hooks::{
+            Event,
+            Hooks,
+        },
+        journal::{
+            Append,
+            Record,
+        },
+    },
     node::{
         child::Child,
         data::{
@@ -19,7 +61,38 @@ use super::{
             Value,
             ValueType,
         },
+        data_ext::WriteError,
+        digest::{
+            hash,
+            Digest,
+            Digests,
+        },
+        identified::{
+            Identified,
+            NodeId,
+        },
+        located::Located,
+        meta::{
+            Meta,
+            Metas,
+        },
         named::Named,
+        observe::{
+            Access,
+            Observe,
+        },
+        permissions::Permissions,
+        timestamps::{
+            Times,
+            Timestamps,
+        },
+        versioned::{
+            bump,
+            next_generation,
+            Versioned,
+        },
+        xattrs::Xattrs,
+        Node,
     },
 };
 
@@ -43,6 +116,23 @@ where
     }
 }
 
+impl<D, F> Eq for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+}
+
+impl<D, F> PartialEq for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+    }
+}
+
 impl<D, F> Deref for File<D, F>
 where
     D: ValueType,
@@ -81,6 +171,78 @@ where
     async fn data(&self) -> Value<F> {
         self.read().map(|this| this.value.clone()).await
     }
+
+    async fn data_mut(&self) -> Value<F> {
+        let mut this = self.write().await;
+
+        this.unshare().await;
+        this.value.clone()
+    }
+}
+
+#[async_trait]
+impl<D, F> Digest for File<D, F>
+where
+    D: ValueType,
+    F: ValueType + AsRef<[u8]>,
+{
+    async fn tree_hash(&self) -> u64 {
+        let generation = self.generation().await;
+
+        if let Some(hash) = self.read().map(|this| this.digests.get(generation)).await {
+            return hash;
+        }
+
+        let hash = self
+            .data()
+            .then(|value| async move { value.read().map(|value| hash((*value).as_ref())).await })
+            .await;
+
+        self.read()
+            .map(|this| this.digests.set(generation, hash))
+            .await;
+
+        hash
+    }
+}
+
+#[async_trait]
+impl<D, F> Identified for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn id(&self) -> NodeId {
+        self.read().map(|this| this.id).await
+    }
+}
+
+#[async_trait]
+impl<D, F> Meta for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_meta<M>(&self) -> Option<M>
+    where
+        M: Clone + Send + Sync + 'static,
+    {
+        self.read().map(|this| this.meta.get()).await
+    }
+
+    async fn remove_meta<M>(&self) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        self.remove_meta_as(None).await
+    }
+
+    async fn set_meta<M>(&self, value: M) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        self.set_meta_as(value, None).await
+    }
 }
 
 #[async_trait]
@@ -89,8 +251,173 @@ where
     D: ValueType,
     F: ValueType,
 {
-    async fn name(&self) -> Option<String> {
-        self.read().map(|this| Some(this.parent.0.clone())).await
+    async fn name(&self) -> Option<OsString> {
+        self.read()
+            .map(|this| Some(this.parent.0.to_os_string()))
+            .await
+    }
+}
+
+#[async_trait]
+impl<D, F> Observe<F> for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn journal(&self) -> Option<Append<F>> {
+        match self.read().map(|this| this.hooks.journal.clone()).await {
+            Some(journal) => Some(journal.append_file(self.path().await)),
+            _ => None,
+        }
+    }
+
+    async fn observe(&self, access: Access) {
+        self.read()
+            .then(|this| async move {
+                let now = this.context.clock.now();
+
+                if let Some(lru) = &this.hooks.lru {
+                    lru.touch(self);
+                }
+
+                this.times
+                    .lock()
+                    .map(|mut times| times.observe(access, now))
+                    .await;
+            })
+            .await;
+    }
+
+    async fn permit(&self, action: Action) -> bool {
+        let hooks = self.read().map(|this| this.hooks.clone()).await;
+
+        hooks.permit(action, self.path()).await
+    }
+
+    async fn quota(&self) -> Option<Arc<Context<F>>> {
+        self.read()
+            .map(|this| this.context.measure.map(|_| this.context.clone()))
+            .await
+    }
+
+    async fn versions(&self) -> Option<Versions<F>> {
+        self.read()
+            .map(|this| {
+                this.context
+                    .history
+                    .map(|(limit, snapshot)| Versions::new(limit, snapshot, this.history.clone()))
+            })
+            .await
+    }
+
+    async fn written(&self, context: Option<&str>) {
+        let (hooks, subscribers) = self
+            .read()
+            .map(|this| (this.hooks.clone(), this.subscribers.clone()))
+            .await;
+
+        self.bump().await;
+
+        if !subscribers.is_empty() {
+            let generation = self.generation().await;
+            let value = self.data().await;
+
+            value
+                .read()
+                .map(|value| subscribers.publish(generation, &value))
+                .await;
+        }
+
+        hooks
+            .notify_as(
+                Event::Write,
+                self.path(),
+                &Node::File(self.clone()),
+                None,
+                context,
+            )
+            .await;
+    }
+}
+
+#[async_trait]
+impl<D, F> Permissions for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn readonly(&self) -> bool {
+        self.read().map(|this| this.readonly).await
+    }
+
+    async fn set_readonly(&self, readonly: bool) {
+        self.set_readonly_as(readonly, None).await;
+    }
+}
+
+#[async_trait]
+impl<D, F> Timestamps for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn accessed(&self) -> SystemTime {
+        self.times().map(|times| times.accessed).await
+    }
+
+    async fn created(&self) -> SystemTime {
+        self.times().map(|times| times.created).await
+    }
+
+    async fn modified(&self) -> SystemTime {
+        self.times().map(|times| times.modified).await
+    }
+}
+
+#[async_trait]
+impl<D, F> Versioned for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn generation(&self) -> u64 {
+        self.read()
+            .map(|this| this.generation.load(Ordering::SeqCst))
+            .await
+    }
+}
+
+#[async_trait]
+impl<D, F> Xattrs for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_xattr(&self, name: &str) -> Option<Vec<u8>> {
+        self.read().map(|this| this.xattrs.get(name).cloned()).await
+    }
+
+    async fn list_xattrs(&self) -> Vec<String> {
+        self.read()
+            .map(|this| {
+                let mut names = this.xattrs.keys().cloned().collect::<Vec<_>>();
+
+                names.sort();
+                names
+            })
+            .await
+    }
+
+    async fn remove_xattr(&self, name: &str) -> Result<Option<Vec<u8>>, WriteError> {
+        Node::File(self.clone())
+            .replace_xattr(name, None, None)
+            .await
+    }
+
+    async fn set_xattr(&self, name: &str, value: Vec<u8>) -> Result<Option<Vec<u8>>, WriteError> {
+        Node::File(self.clone())
+            .replace_xattr(name, Some(value), None)
+            .await
     }
 }
 
@@ -102,12 +429,175 @@ where
     F: ValueType,
 {
     #[must_use]
-    pub(crate) fn create(value: Option<F>, parent: (String, Reference<D, F>)) -> Self {
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    #[must_use]
+    pub(crate) fn create(
+        value: F,
+        parent: (Arc<OsStr>, Reference<D, F>),
+        context: Arc<Context<F>>,
+        hooks: Arc<Hooks<D, F>>,
+    ) -> Self {
+        let (value, deduped) = match &context.dedup {
+            Some(dedup) => (dedup.intern(value), true),
+            _ => (Value::new(value), false),
+        };
+
+        Self::allocate(value, deduped, parent, context, hooks)
+    }
+
+    fn allocate(
+        value: Value<F>,
+        deduped: bool,
+        parent: (Arc<OsStr>, Reference<D, F>),
+        context: Arc<Context<F>>,
+        hooks: Arc<Hooks<D, F>>,
+    ) -> Self {
+        let id = context.next_id();
+        let times = Times::new(context.clock.now());
+
         Self(Arc::new(RwLock::new(Internal {
+            advisory: Arc::new(RwLock::new(())),
+            context,
+            deduped,
+            digests: Digests::default(),
+            generation: Arc::new(AtomicU64::new(next_generation())),
+            history: Arc::new(StdMutex::new(VecDeque::new())),
+            hooks,
+            id,
+            meta: Metas::default(),
             parent,
-            value: Value::from_option(value),
+            readonly: false,
+            subscribers: Arc::default(),
+            times: Mutex::new(times),
+            value,
+            xattrs: HashMap::new(),
         })))
     }
+
+    #[must_use]
+    pub fn new(value: F) -> Self {
+        Self::create(
+            value,
+            (Arc::from(OsStr::new("")), Reference(Weak::new())),
+            Arc::default(),
+            Arc::default(),
+        )
+    }
+
+    pub(crate) async fn bump(&self) {
+        bump(&self.generations().await);
+    }
+
+    pub(crate) async fn generations(&self) -> Vec<Arc<AtomicU64>> {
+        let mut generations = vec![self.read().map(|this| this.generation.clone()).await];
+
+        if let Some(parent) = self.parent().await {
+            generations.extend(parent.generations().await);
+        }
+
+        generations
+    }
+
+    pub(crate) async fn set_parent(&self, parent: (Arc<OsStr>, Reference<D, F>)) {
+        self.write().map(|mut this| this.parent = parent).await;
+    }
+
+    pub(crate) async fn rebind(&self, context: &Arc<Context<F>>, hooks: &Arc<Hooks<D, F>>) {
+        let mut this = self.write().await;
+
+        if !Arc::ptr_eq(&this.context, context) {
+            this.unshare().await;
+            this.context = context.clone();
+            this.id = context.next_id();
+        }
+
+        this.hooks = hooks.clone();
+    }
+
+    pub(crate) async fn remove_meta_as<M>(&self, context: Option<&str>) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        let removed = self.write().map(|mut this| this.meta.remove()).await;
+
+        self.changed(context).await;
+        removed
+    }
+
+    pub(crate) async fn set_meta_as<M>(&self, value: M, context: Option<&str>) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        let replaced = self.write().map(|mut this| this.meta.insert(value)).await;
+
+        self.changed(context).await;
+        replaced
+    }
+
+    pub(crate) async fn set_readonly_as(&self, readonly: bool, context: Option<&str>) {
+        let hooks = self
+            .write()
+            .map(|mut this| {
+                this.readonly = readonly;
+                this.hooks.clone()
+            })
+            .await;
+
+        self.bump().await;
+
+        hooks
+            .journal(self.path(), |_, path| Record::SetReadonly {
+                path,
+                readonly,
+            })
+            .await;
+
+        self.changed(context).await;
+    }
+
+    pub(crate) async fn swap_xattr(
+        &self,
+        name: &str,
+        value: Option<Vec<u8>>,
+    ) -> (Option<Vec<u8>>, Arc<Hooks<D, F>>) {
+        let swapped = self
+            .write()
+            .map(|mut this| {
+                let previous = match value {
+                    Some(value) => this.xattrs.insert(String::from(name), value),
+                    _ => this.xattrs.remove(name),
+                };
+
+                (previous, this.hooks.clone())
+            })
+            .await;
+
+        self.bump().await;
+        swapped
+    }
+
+    async fn changed(&self, context: Option<&str>) {
+        let hooks = self.read().map(|this| this.hooks.clone()).await;
+
+        hooks
+            .notify_as(
+                Event::Metadata,
+                self.path(),
+                &Node::File(self.clone()),
+                None,
+                context,
+            )
+            .await;
+    }
+
+    async fn times(&self) -> Times {
+        self.read()
+            .then(|this| async move { *this.times.lock().await })
+            .await
+    }
 }
 
 // Internals
@@ -118,6 +608,43 @@ where
     D: ValueType,
     F: ValueType,
 {
-    parent: (String, Reference<D, F>),
+    advisory: Arc<RwLock<()>>,
+    context: Arc<Context<F>>,
+    deduped: bool,
+    digests: Digests,
+    generation: Arc<AtomicU64>,
+    history: Arc<StdMutex<VecDeque<F>>>,
+    hooks: Arc<Hooks<D, F>>,
+    id: NodeId,
+    meta: Metas,
+    parent: (Arc<OsStr>, Reference<D, F>),
+    readonly: bool,
+    subscribers: Arc<Subscribers<F>>,
+    times: Mutex<Times>,
     value: Value<F>,
+    xattrs: HashMap<String, Vec<u8>>,
+}
+
+// Internals - Methods
+
+impl<D, F> Internal<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn unshare(&mut self) {
+        if !self.deduped {
+            return;
+        }
+
+        if let Some(dedup) = &self.context.dedup {
+            if !dedup.release(&self.value) {
+                let value = self.value.read().map(|value| (dedup.snapshot)(&value)).await;
+
+                self.value = Value::new(value);
+            }
+        }
+
+        self.deduped = false;
+    }
 }
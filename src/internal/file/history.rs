@@ -0,0 +1,130 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use async_trait::async_trait;
+use futures::FutureExt;
+use thiserror::Error;
+
+use super::{
+    super::node::{
+        data::ValueType,
+        data_ext::{
+            DataExt,
+            WriteError,
+        },
+    },
+    File,
+};
+
+// History
+
+#[async_trait]
+pub trait History<F>
+where
+    F: ValueType,
+{
+    async fn history(&self) -> Vec<F>;
+
+    async fn revert(&self, version: usize) -> Result<(), RevertError>;
+}
+
+#[derive(Clone, Copy, Debug, Error)]
+pub enum RevertError {
+    #[error("version {0} is not present in the file history")]
+    UnknownVersion(usize),
+    #[error("internal error writing file")]
+    Write(#[from] WriteError),
+}
+
+// History - Implementation
+
+#[async_trait]
+impl<D, F> History<F> for File<D, F>
+where
+    D: ValueType,
+    F: ValueType + Clone,
+{
+    async fn history(&self) -> Vec<F> {
+        self.0
+            .read()
+            .map(|this| {
+                this.history
+                    .lock()
+                    .map(|values| values.iter().cloned().collect())
+                    .unwrap_or_default()
+            })
+            .await
+    }
+
+    async fn revert(&self, version: usize) -> Result<(), RevertError> {
+        let value = self
+            .0
+            .read()
+            .map(|this| {
+                this.history
+                    .lock()
+                    .ok()
+                    .and_then(|values| values.get(version).cloned())
+            })
+            .await
+            .ok_or(RevertError::UnknownVersion(version))?;
+
+        self.write(|mut current| *current = value)
+            .await
+            .map_err(RevertError::from)
+    }
+}
+
+// Snapshot
+
+pub type Snapshot<F> = fn(&F) -> F;
+
+// Versions
+
+pub struct Versions<F> {
+    limit: usize,
+    snapshot: Snapshot<F>,
+    values: Arc<Mutex<VecDeque<F>>>,
+}
+
+// Versions - Standard Traits
+
+impl<F> fmt::Debug for Versions<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Versions")
+            .field("limit", &self.limit)
+            .finish_non_exhaustive()
+    }
+}
+
+// Versions - Methods
+
+impl<F> Versions<F> {
+    pub(crate) const fn new(
+        limit: usize,
+        snapshot: Snapshot<F>,
+        values: Arc<Mutex<VecDeque<F>>>,
+    ) -> Self {
+        Self {
+            limit,
+            snapshot,
+            values,
+        }
+    }
+
+    pub(crate) fn record(&self, previous: &F) {
+        if let Ok(mut values) = self.values.lock() {
+            values.truncate(self.limit.saturating_sub(1));
+
+            if self.limit > 0 {
+                values.push_front((self.snapshot)(previous));
+            }
+        }
+    }
+}
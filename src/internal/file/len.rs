@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use futures::FutureExt;
+
+use super::{
+    super::node::{
+        data::{
+            Data,
+            ValueType,
+        },
+        data_len::DataLen,
+    },
+    File,
+};
+
+// Len
+
+#[async_trait]
+pub trait Len {
+    async fn is_empty(&self) -> bool;
+
+    async fn len(&self) -> usize;
+}
+
+// Len - Implementation
+
+#[async_trait]
+impl<D, F> Len for File<D, F>
+where
+    D: ValueType,
+    F: ValueType + DataLen,
+{
+    async fn is_empty(&self) -> bool {
+        self.len().map(|len| len == 0).await
+    }
+
+    async fn len(&self) -> usize {
+        self.data()
+            .then(|value| async move { value.read().map(|value| value.data_len()).await })
+            .await
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    future::Future,
+    sync::Arc,
+};
+
+use async_lock::{
+    RwLock,
+    RwLockReadGuardArc,
+    RwLockWriteGuardArc,
+};
+use async_trait::async_trait;
+use futures::{
+    future::{
+        self,
+        Either,
+    },
+    FutureExt,
+};
+
+use super::{
+    super::node::data::ValueType,
+    File,
+};
+
+// Lock
+
+#[async_trait]
+pub trait Lock {
+    async fn lock_exclusive(&self) -> ExclusiveLock;
+
+    async fn lock_exclusive_timeout<T>(&self, timeout: T) -> Option<ExclusiveLock>
+    where
+        T: Future<Output = ()> + Send;
+
+    async fn lock_shared(&self) -> SharedLock;
+
+    async fn lock_shared_timeout<T>(&self, timeout: T) -> Option<SharedLock>
+    where
+        T: Future<Output = ()> + Send;
+
+    async fn try_lock_exclusive(&self) -> Option<ExclusiveLock>;
+
+    async fn try_lock_shared(&self) -> Option<SharedLock>;
+}
+
+#[derive(Debug)]
+pub struct ExclusiveLock {
+    _guard: RwLockWriteGuardArc<()>,
+}
+
+#[derive(Debug)]
+pub struct SharedLock {
+    _guard: RwLockReadGuardArc<()>,
+}
+
+// Lock - Implementation
+
+#[async_trait]
+impl<D, F> Lock for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn lock_exclusive(&self) -> ExclusiveLock {
+        ExclusiveLock {
+            _guard: self.advisory().await.write_arc().await,
+        }
+    }
+
+    async fn lock_exclusive_timeout<T>(&self, timeout: T) -> Option<ExclusiveLock>
+    where
+        T: Future<Output = ()> + Send,
+    {
+        let advisory = self.advisory().await;
+
+        race(advisory.write_arc(), timeout)
+            .await
+            .map(|guard| ExclusiveLock { _guard: guard })
+    }
+
+    async fn lock_shared(&self) -> SharedLock {
+        SharedLock {
+            _guard: self.advisory().await.read_arc().await,
+        }
+    }
+
+    async fn lock_shared_timeout<T>(&self, timeout: T) -> Option<SharedLock>
+    where
+        T: Future<Output = ()> + Send,
+    {
+        let advisory = self.advisory().await;
+
+        race(advisory.read_arc(), timeout)
+            .await
+            .map(|guard| SharedLock { _guard: guard })
+    }
+
+    async fn try_lock_exclusive(&self) -> Option<ExclusiveLock> {
+        self.advisory()
+            .await
+            .try_write_arc()
+            .map(|guard| ExclusiveLock { _guard: guard })
+    }
+
+    async fn try_lock_shared(&self) -> Option<SharedLock> {
+        self.advisory()
+            .await
+            .try_read_arc()
+            .map(|guard| SharedLock { _guard: guard })
+    }
+}
+
+// File - Lock
+
+impl<D, F> File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn advisory(&self) -> Arc<RwLock<()>> {
+        self.read().map(|this| this.advisory.clone()).await
+    }
+}
+
+// Functions
+
+async fn race<L, T, G>(lock: L, timeout: T) -> Option<G>
+where
+    L: Future<Output = G>,
+    T: Future<Output = ()>,
+{
+    match future::select(Box::pin(lock), Box::pin(timeout)).await {
+        Either::Left((guard, _)) => Some(guard),
+        Either::Right(_) => None,
+    }
+}
@@ -0,0 +1,285 @@
+use std::{
+    fmt,
+    io,
+    mem,
+    pin::Pin,
+    sync::Arc,
+    task::{
+        Context,
+        Poll,
+    },
+};
+
+use async_trait::async_trait;
+use futures::{
+    future::BoxFuture,
+    ready,
+    AsyncRead,
+    AsyncWrite,
+    FutureExt,
+};
+
+use super::{
+    super::{
+        file_system::access::Action,
+        fs::from_write_error,
+        node::{
+            data::{
+                Data,
+                ValueType,
+            },
+            data_ext::{
+                write,
+                WriteError,
+            },
+            observe::{
+                Access,
+                Observe,
+            },
+            permissions::Permissions,
+        },
+    },
+    File,
+};
+
+// Open
+
+#[async_trait]
+pub trait Open<F>
+where
+    F: ValueType,
+{
+    async fn open_reader(&self) -> Reader
+    where
+        F: AsRef<[u8]>;
+
+    async fn open_writer(&self) -> Result<Writer<F>, WriteError>
+    where
+        F: From<Vec<u8>>;
+}
+
+// Open - Implementation
+
+#[async_trait]
+impl<D, F> Open<F> for File<D, F>
+where
+    D: ValueType + 'static,
+    F: ValueType + 'static,
+{
+    async fn open_reader(&self) -> Reader
+    where
+        F: AsRef<[u8]>,
+    {
+        let bytes = self.data().await.read().await.as_ref().to_vec();
+
+        self.observe(Access::Read).await;
+
+        Reader { bytes, position: 0 }
+    }
+
+    async fn open_writer(&self) -> Result<Writer<F>, WriteError>
+    where
+        F: From<Vec<u8>>,
+    {
+        self.open_writer_as(None).await
+    }
+}
+
+// File - Open
+
+impl<D, F> File<D, F>
+where
+    D: ValueType + 'static,
+    F: ValueType + 'static,
+{
+    pub(crate) async fn open_writer_as(
+        &self,
+        context: Option<Arc<str>>,
+    ) -> Result<Writer<F>, WriteError>
+    where
+        F: From<Vec<u8>>,
+    {
+        if self.readonly().await {
+            return Err(WriteError::PermissionDenied);
+        }
+
+        if !self.permit(Action::Write).await {
+            return Err(WriteError::AccessDenied);
+        }
+
+        let file = self.clone();
+        let commit: Commit<F> = Box::new(move |value| {
+            async move { write(&file, |mut current| *current = value, context.as_deref()).await }
+                .boxed()
+        });
+
+        Ok(Writer {
+            buffer: Vec::new(),
+            commit: Some(commit),
+            pending: None,
+        })
+    }
+}
+
+// Reader
+
+pub struct Reader {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+// Reader - Standard Traits
+
+impl fmt::Debug for Reader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reader")
+            .field("position", &self.position)
+            .finish_non_exhaustive()
+    }
+}
+
+// Reader - Library Traits
+
+impl AsyncRead for Reader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Poll::Ready(Ok(self.read_into(buf)))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl tokio::io::AsyncRead for Reader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let read = self.read_into(buf.initialize_unfilled());
+
+        buf.advance(read);
+        Poll::Ready(Ok(()))
+    }
+}
+
+// Reader - Methods
+
+impl Reader {
+    fn read_into(&mut self, buf: &mut [u8]) -> usize {
+        let remaining = self.bytes.get(self.position..).unwrap_or_default();
+        let read = remaining.len().min(buf.len());
+
+        buf[..read].copy_from_slice(&remaining[..read]);
+        self.position += read;
+
+        read
+    }
+}
+
+// Writer
+
+pub struct Writer<F>
+where
+    F: ValueType + From<Vec<u8>>,
+{
+    buffer: Vec<u8>,
+    commit: Option<Commit<F>>,
+    pending: Option<BoxFuture<'static, Result<(), WriteError>>>,
+}
+
+type Commit<F> = Box<dyn FnOnce(F) -> BoxFuture<'static, Result<(), WriteError>> + Send>;
+
+// Writer - Standard Traits
+
+impl<F> fmt::Debug for Writer<F>
+where
+    F: ValueType + From<Vec<u8>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Writer")
+            .field("buffer", &self.buffer)
+            .finish_non_exhaustive()
+    }
+}
+
+// Writer - Library Traits
+
+impl<F> AsyncWrite for Writer<F>
+where
+    F: ValueType + From<Vec<u8>>,
+{
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Poll::Ready(self.write_from(buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_commit(cx)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<F> tokio::io::AsyncWrite for Writer<F>
+where
+    F: ValueType + From<Vec<u8>>,
+{
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Poll::Ready(self.write_from(buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_commit(cx)
+    }
+}
+
+// Writer - Methods
+
+impl<F> Writer<F>
+where
+    F: ValueType + From<Vec<u8>>,
+{
+    fn write_from(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.commit {
+            Some(_) => {
+                self.buffer.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "writer is closed",
+            )),
+        }
+    }
+
+    fn poll_commit(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if let Some(commit) = self.commit.take() {
+            self.pending = Some(commit(F::from(mem::take(&mut self.buffer))));
+        }
+
+        if let Some(pending) = &mut self.pending {
+            let result = ready!(pending.poll_unpin(cx));
+
+            self.pending = None;
+            result.map_err(from_write_error)?;
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    fmt,
+    sync::Mutex,
+};
+
+use async_trait::async_trait;
+use futures::{
+    channel::mpsc::{
+        self,
+        UnboundedReceiver,
+    },
+    FutureExt,
+};
+
+use super::{
+    super::node::data::ValueType,
+    File,
+};
+
+// Subscribe
+
+#[async_trait]
+pub trait Subscribe<F>
+where
+    F: ValueType,
+{
+    async fn subscribe(&self) -> Subscription<u64>;
+
+    async fn subscribe_values(&self) -> Subscription<F>
+    where
+        F: Clone + 'static;
+}
+
+pub type Subscription<T> = UnboundedReceiver<T>;
+
+// Subscribe - Implementation
+
+#[async_trait]
+impl<D, F> Subscribe<F> for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn subscribe(&self) -> Subscription<u64> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        self.read()
+            .map(|this| {
+                this.subscribers.push(Box::new(move |generation, _| {
+                    sender.unbounded_send(generation).is_ok()
+                }));
+            })
+            .await;
+
+        receiver
+    }
+
+    async fn subscribe_values(&self) -> Subscription<F>
+    where
+        F: Clone + 'static,
+    {
+        let (sender, receiver) = mpsc::unbounded();
+
+        self.read()
+            .map(|this| {
+                this.subscribers.push(Box::new(move |_, value: &F| {
+                    sender.unbounded_send(value.clone()).is_ok()
+                }));
+            })
+            .await;
+
+        receiver
+    }
+}
+
+// Subscribers
+
+type Subscriber<F> = Box<dyn Fn(u64, &F) -> bool + Send + Sync>;
+
+pub struct Subscribers<F>(Mutex<Vec<Subscriber<F>>>);
+
+// Subscribers - Standard Traits
+
+impl<F> Default for Subscribers<F> {
+    fn default() -> Self {
+        Self(Mutex::default())
+    }
+}
+
+impl<F> fmt::Debug for Subscribers<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Subscribers")
+            .field(&self.0.lock().map_or(0, |subscribers| subscribers.len()))
+            .finish()
+    }
+}
+
+// Subscribers - Methods
+
+impl<F> Subscribers<F> {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.lock().map_or(true, |subscribers| subscribers.is_empty())
+    }
+
+    pub fn publish(&self, generation: u64, value: &F) {
+        if let Ok(mut subscribers) = self.0.lock() {
+            subscribers.retain(|subscriber| subscriber(generation, value));
+        }
+    }
+
+    fn push(&self, subscriber: Subscriber<F>) {
+        if let Ok(mut subscribers) = self.0.lock() {
+            subscribers.push(subscriber);
+        }
+    }
+}
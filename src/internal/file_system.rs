@@ -1,14 +1,78 @@
-use std::ops::Deref;
+pub mod access;
+pub mod audit;
+pub mod backend;
+pub mod branch;
+pub mod builder;
+pub mod clock;
+pub mod context;
+mod convert;
+pub mod dedup;
+#[cfg(feature = "tokio")]
+pub mod disk;
+#[cfg(any(feature = "toml", feature = "yaml"))]
+pub mod fixture;
+pub mod hooks;
+pub mod index;
+pub mod iter;
+pub mod journal;
+#[cfg(feature = "json")]
+mod json;
+pub mod lazy;
+pub mod lru;
+#[cfg(feature = "notify")]
+mod notify;
+pub mod options;
+pub mod prefix;
+pub mod quota;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+pub mod undo;
+pub mod watch;
+#[cfg(feature = "zip")]
+mod zip;
 
+use std::{
+    ops::Deref,
+    sync::{
+        Arc,
+        Mutex,
+        OnceLock,
+        RwLock,
+        Weak,
+    },
+};
+
+use self::{
+    audit::Audit,
+    builder::Builder,
+    clock::{
+        Clock,
+        SystemClock,
+    },
+    context::Context,
+    hooks::Hooks,
+    index::Index,
+    journal::Journal,
+    lazy::Loader,
+    lru::Lru,
+    options::Options,
+    undo::UndoLog,
+};
 use super::{
-    directory::Directory,
-    node::data::ValueType,
+    directory::{
+        size::Size,
+        Directory,
+    },
+    node::{
+        data::ValueType,
+        data_len::DataLen,
+    },
 };
 
 // FileSystem
 
 #[derive(Debug)]
-pub struct FileSystem<D, F>(pub(crate) Directory<D, F>)
+pub struct FileSystem<D, F>(pub(crate) Directory<D, F>, Option<Arc<UndoLog<D, F>>>)
 where
     D: ValueType,
     F: ValueType;
@@ -17,7 +81,7 @@ where
 
 impl<D, F> Default for FileSystem<D, F>
 where
-    D: ValueType,
+    D: ValueType + Default,
     F: ValueType,
 {
     fn default() -> Self {
@@ -41,11 +105,101 @@ where
 
 impl<D, F> FileSystem<D, F>
 where
-    D: ValueType,
+    D: ValueType + Default,
     F: ValueType,
 {
     #[must_use]
     pub fn new() -> Self {
-        Self(Directory::create_root())
+        Self::with_clock(SystemClock)
+    }
+
+    #[must_use]
+    pub fn with_clock<C>(clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        Self::with_options(Options::default().clock(clock))
+    }
+
+    #[must_use]
+    pub fn with_options(options: Options<F>) -> Self {
+        Self::with_root(options, D::default())
+    }
+}
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub fn builder() -> Builder<D, F> {
+        Builder::default()
+    }
+
+    #[must_use]
+    pub fn with_root(options: Options<F>, root: D) -> Self {
+        Self::with_hooks(options, root, None, None)
+    }
+
+    pub(crate) fn install_sinks(&self) {
+        let (options, hooks) = (&self.0.context().options, self.0.hooks());
+
+        let audit = Audit::new(options.audit, options.clock.clone(), options.on_audit.clone());
+
+        if let Some(audit) = audit {
+            let _ = hooks.audit.set(audit);
+        }
+
+        if let (Some(lru), Some(evict)) = (&hooks.lru, &options.on_evict) {
+            lru.install(evict.clone());
+        }
+    }
+
+    fn with_hooks(
+        options: Options<F>,
+        root: D,
+        journal: Option<Journal<D, F>>,
+        loader: Option<Loader<D, F>>,
+    ) -> Self {
+        let fs = Self::without_sinks(options, root, journal, loader);
+
+        fs.install_sinks();
+        fs
+    }
+
+    pub(crate) fn without_sinks(
+        options: Options<F>,
+        root: D,
+        journal: Option<Journal<D, F>>,
+        loader: Option<Loader<D, F>>,
+    ) -> Self {
+        let undo = options.undo.map(|limit| Arc::new(UndoLog::new(limit)));
+        let hooks = Hooks {
+            audit: OnceLock::new(),
+            callbacks: RwLock::default(),
+            index: options.index.then(Index::default),
+            interceptor: RwLock::default(),
+            journal,
+            loader,
+            lru: options.capacity.map(Lru::new),
+            undo: undo.as_ref().map_or_else(Weak::new, Arc::downgrade),
+            watchers: Mutex::default(),
+        };
+
+        Self(
+            Directory::create_root(root, Arc::new(Context::from(options)), Arc::new(hooks)),
+            undo,
+        )
+    }
+}
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType + DataLen,
+{
+    pub async fn total_size(&self) -> usize {
+        self.0.size().await
     }
 }
@@ -0,0 +1,57 @@
+use std::{
+    future::Future,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use futures::{
+    future::BoxFuture,
+    FutureExt,
+};
+
+use super::{
+    super::node::data::ValueType,
+    FileSystem,
+};
+
+// Action
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    Create,
+    Get,
+    Remove,
+    Write,
+}
+
+// Decision
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Decision {
+    Allow,
+    Deny,
+}
+
+// Interceptor
+
+pub type Interceptor = Arc<dyn Fn(Action, PathBuf) -> BoxFuture<'static, Decision> + Send + Sync>;
+
+// FileSystem - Access
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub fn intercept<I, R>(&self, interceptor: I)
+    where
+        I: Fn(Action, PathBuf) -> R + Send + Sync + 'static,
+        R: Future<Output = Decision> + Send + 'static,
+    {
+        if let Ok(mut current) = self.hooks().interceptor.write() {
+            *current = Some(Arc::new(move |action, path| {
+                interceptor(action, path).boxed()
+            }));
+        }
+    }
+}
@@ -0,0 +1,436 @@
+use std::{
+    collections::VecDeque,
+    ffi::OsStr,
+    fmt,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::SystemTime,
+};
+
+use async_lock::RwLockWriteGuard;
+
+use super::{
+    super::{
+        directory::{
+            attach::AttachError,
+            create::CreatedDirs,
+            create_all::CreateError,
+            entry::Entry,
+            merge::{
+                Conflict,
+                MergeError,
+                Resolution,
+            },
+            rename::RenameError,
+            retain::retain,
+            Directory,
+        },
+        file::{
+            open::Writer,
+            File,
+        },
+        node::{
+            data::{
+                Data,
+                ValueType,
+            },
+            data_ext::{
+                write,
+                WriteError,
+            },
+            observe::Observe,
+            permissions::Permissions,
+            Node,
+        },
+    },
+    backend::Stored,
+    clock::Clock,
+    FileSystem,
+};
+
+// Audit
+
+pub struct Audit {
+    capacity: Option<usize>,
+    clock: Arc<dyn Clock>,
+    entries: Mutex<VecDeque<AuditEntry>>,
+    sink: Option<AuditSink>,
+}
+
+pub type AuditSink = Arc<dyn Fn(&AuditEntry) + Send + Sync>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditEntry {
+    pub context: Option<String>,
+    pub from: Option<PathBuf>,
+    pub mutation: Mutation,
+    pub path: PathBuf,
+    pub time: SystemTime,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mutation {
+    Create,
+    Metadata,
+    Remove,
+    Rename,
+    Write,
+}
+
+// Audit - Standard Traits
+
+impl fmt::Debug for Audit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Audit")
+            .field("capacity", &self.capacity)
+            .field("sink", &self.sink.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+// Audit - Methods
+
+impl Audit {
+    pub(crate) fn new(
+        capacity: Option<usize>,
+        clock: Arc<dyn Clock>,
+        sink: Option<AuditSink>,
+    ) -> Option<Self> {
+        (capacity.is_some() || sink.is_some()).then(|| Self {
+            capacity,
+            clock,
+            entries: Mutex::new(VecDeque::new()),
+            sink,
+        })
+    }
+
+    pub(crate) fn record(
+        &self,
+        mutation: Mutation,
+        path: PathBuf,
+        from: Option<PathBuf>,
+        context: Option<&str>,
+    ) {
+        let entry = AuditEntry {
+            context: context.map(String::from),
+            from,
+            mutation,
+            path,
+            time: self.clock.now(),
+        };
+
+        if let Some(sink) = &self.sink {
+            sink(&entry);
+        }
+
+        if let (Some(capacity), Ok(mut entries)) = (self.capacity, self.entries.lock()) {
+            if capacity > 0 {
+                if entries.len() == capacity {
+                    entries.pop_front();
+                }
+
+                entries.push_back(entry);
+            }
+        }
+    }
+
+    fn entries(&self) -> Vec<AuditEntry> {
+        self.entries
+            .lock()
+            .map(|entries| entries.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+// Audited
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Audited(Arc<str>);
+
+// Audited - Methods
+
+impl Audited {
+    #[must_use]
+    pub fn context(&self) -> &str {
+        &self.0
+    }
+
+    pub async fn attach<D, F, N>(
+        &self,
+        dir: &Directory<D, F>,
+        name: N,
+        node: Node<D, F>,
+    ) -> Result<Option<Node<D, F>>, AttachError>
+    where
+        D: ValueType,
+        F: ValueType,
+        N: AsRef<OsStr> + Send,
+    {
+        dir.attach_as(name.as_ref(), node, Some(&self.0)).await
+    }
+
+    pub async fn create_all<D, F, I, C>(
+        &self,
+        dir: &Directory<D, F>,
+        entries: I,
+        create: C,
+    ) -> Result<(), CreateError>
+    where
+        D: ValueType,
+        F: ValueType,
+        I: IntoIterator<Item = (PathBuf, Stored<D, F>)> + Send,
+        I::IntoIter: Send,
+        C: Fn() -> D + Send + Sync,
+    {
+        dir.create_all_as(entries, create, Some(&self.0)).await
+    }
+
+    pub async fn create_dir<D, F, P>(
+        &self,
+        dir: &Directory<D, F>,
+        path: P,
+        value: D,
+    ) -> Result<Directory<D, F>, CreateError>
+    where
+        D: ValueType,
+        F: ValueType,
+        P: AsRef<Path> + Send,
+    {
+        dir.create_dir_as(path.as_ref(), value, Some(&self.0)).await
+    }
+
+    pub async fn create_dir_all<D, F, P>(
+        &self,
+        dir: &Directory<D, F>,
+        path: P,
+    ) -> Result<CreatedDirs<D, F>, CreateError>
+    where
+        D: ValueType + Default,
+        F: ValueType,
+        P: AsRef<Path> + Send,
+    {
+        dir.create_dir_all_as(path.as_ref(), Some(&self.0)).await
+    }
+
+    pub async fn create_file<D, F, P>(
+        &self,
+        dir: &Directory<D, F>,
+        path: P,
+        value: F,
+    ) -> Result<File<D, F>, CreateError>
+    where
+        D: ValueType,
+        F: ValueType,
+        P: AsRef<Path> + Send,
+    {
+        dir.create_file_as(path.as_ref(), value, Some(&self.0))
+            .await
+    }
+
+    pub async fn detach<D, F, N>(
+        &self,
+        dir: &Directory<D, F>,
+        name: N,
+    ) -> Result<Option<Node<D, F>>, WriteError>
+    where
+        D: ValueType,
+        F: ValueType,
+        N: AsRef<OsStr> + Send,
+    {
+        dir.detach_as(name.as_ref(), Some(&self.0)).await
+    }
+
+    pub async fn insert<D, F, N>(
+        &self,
+        dir: &Directory<D, F>,
+        name: N,
+        node: Node<D, F>,
+    ) -> Result<Option<Node<D, F>>, AttachError>
+    where
+        D: ValueType,
+        F: ValueType,
+        N: AsRef<OsStr> + Send,
+    {
+        dir.insert_as(name.as_ref(), node, Some(&self.0)).await
+    }
+
+    pub async fn merge_from<D, F, P>(
+        &self,
+        dir: &Directory<D, F>,
+        other: &Directory<D, F>,
+        policy: P,
+    ) -> Result<(), MergeError>
+    where
+        D: ValueType + Clone + PartialEq,
+        F: ValueType + Clone + PartialEq,
+        P: Fn(&Path, Conflict<'_, D, F>) -> Resolution<F> + Send + Sync,
+    {
+        dir.merge_from_as(other, policy, Some(&self.0)).await
+    }
+
+    pub async fn open_writer<D, F>(&self, file: &File<D, F>) -> Result<Writer<F>, WriteError>
+    where
+        D: ValueType + 'static,
+        F: ValueType + From<Vec<u8>> + 'static,
+    {
+        file.open_writer_as(Some(self.0.clone())).await
+    }
+
+    pub async fn or_insert_dir<D, F>(
+        &self,
+        entry: Entry<'_, D, F>,
+        value: D,
+    ) -> Result<Node<D, F>, WriteError>
+    where
+        D: ValueType,
+        F: ValueType,
+    {
+        entry.or_insert_dir_as(value, Some(&self.0)).await
+    }
+
+    pub async fn or_insert_file<D, F>(
+        &self,
+        entry: Entry<'_, D, F>,
+        value: F,
+    ) -> Result<Node<D, F>, WriteError>
+    where
+        D: ValueType,
+        F: ValueType,
+    {
+        entry.or_insert_file_as(value, Some(&self.0)).await
+    }
+
+    pub async fn remove_meta<D, F, M>(&self, node: &Node<D, F>) -> Option<M>
+    where
+        D: ValueType,
+        F: ValueType,
+        M: Send + Sync + 'static,
+    {
+        node.remove_meta_as(Some(&self.0)).await
+    }
+
+    pub async fn remove_xattr<D, F>(
+        &self,
+        node: &Node<D, F>,
+        name: &str,
+    ) -> Result<Option<Vec<u8>>, WriteError>
+    where
+        D: ValueType,
+        F: ValueType,
+    {
+        node.replace_xattr(name, None, Some(&self.0)).await
+    }
+
+    pub async fn rename<D, F, P, Q>(
+        &self,
+        dir: &Directory<D, F>,
+        from: P,
+        to: Q,
+    ) -> Result<(), RenameError>
+    where
+        D: ValueType,
+        F: ValueType,
+        P: AsRef<Path> + Send,
+        Q: AsRef<Path> + Send,
+    {
+        dir.rename_as(from.as_ref(), to.as_ref(), Some(&self.0))
+            .await
+    }
+
+    pub async fn retain<D, F, P>(&self, dir: &Directory<D, F>, mut predicate: P) -> usize
+    where
+        D: ValueType,
+        F: ValueType,
+        P: FnMut(&Path, &Node<D, F>) -> bool + Send,
+    {
+        retain(
+            dir,
+            PathBuf::from("/"),
+            &mut predicate,
+            false,
+            Some(&self.0),
+        )
+        .await
+    }
+
+    pub async fn retain_and_prune<D, F, P>(&self, dir: &Directory<D, F>, mut predicate: P) -> usize
+    where
+        D: ValueType,
+        F: ValueType,
+        P: FnMut(&Path, &Node<D, F>) -> bool + Send,
+    {
+        retain(dir, PathBuf::from("/"), &mut predicate, true, Some(&self.0)).await
+    }
+
+    pub async fn set_meta<D, F, M>(&self, node: &Node<D, F>, value: M) -> Option<M>
+    where
+        D: ValueType,
+        F: ValueType,
+        M: Send + Sync + 'static,
+    {
+        node.set_meta_as(value, Some(&self.0)).await
+    }
+
+    pub async fn set_readonly<D, F>(&self, node: &Node<D, F>, readonly: bool)
+    where
+        D: ValueType,
+        F: ValueType,
+    {
+        node.set_readonly_as(readonly, Some(&self.0)).await;
+    }
+
+    pub async fn set_xattr<D, F>(
+        &self,
+        node: &Node<D, F>,
+        name: &str,
+        value: Vec<u8>,
+    ) -> Result<Option<Vec<u8>>, WriteError>
+    where
+        D: ValueType,
+        F: ValueType,
+    {
+        node.replace_xattr(name, Some(value), Some(&self.0)).await
+    }
+
+    pub async fn write<N, V, T, W>(&self, node: &N, f: W) -> Result<T, WriteError>
+    where
+        N: Data<V> + Observe<V> + Permissions + Sync,
+        V: ValueType,
+        T: Send,
+        W: FnOnce(RwLockWriteGuard<'_, V>) -> T + Send,
+    {
+        write(node, f, Some(&self.0)).await
+    }
+}
+
+// FileSystem - Audit
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub fn audit_log(&self) -> Vec<AuditEntry> {
+        self.hooks()
+            .audit
+            .get()
+            .map(Audit::entries)
+            .unwrap_or_default()
+    }
+}
+
+// Functions
+
+pub fn audited<C>(context: C) -> Audited
+where
+    C: Into<String>,
+{
+    Audited(Arc::from(context.into()))
+}
@@ -0,0 +1,386 @@
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+        MutexGuard,
+    },
+};
+
+use futures::{
+    future,
+    FutureExt,
+};
+use thiserror::Error;
+
+use super::{
+    super::{
+        directory::{
+            get::{
+                Get,
+                GetError,
+                GetType,
+            },
+            get_ext::{
+                GetDirectoryError,
+                GetExt,
+                GetFileError,
+            },
+        },
+        node::{
+            data::ValueType,
+            data_ext::{
+                DataExt,
+                WriteError,
+            },
+            permissions::Permissions,
+        },
+    },
+    journal::{
+        Journal,
+        Record,
+        Sink,
+    },
+    lazy::Loaded,
+    options::Options,
+    FileSystem,
+};
+
+// Backend
+
+pub trait Backend<D, F>: Send + Sync {
+    fn list(&self, path: &Path) -> io::Result<Vec<OsString>>;
+
+    fn load(&self, path: &Path) -> io::Result<Option<Stored<D, F>>>;
+
+    fn readonly(&self, path: &Path) -> io::Result<bool>;
+
+    fn remove(&self, path: &Path) -> io::Result<()>;
+
+    fn set_readonly(&self, path: &Path, readonly: bool) -> io::Result<()>;
+
+    fn store(&self, path: &Path, stored: Stored<D, F>) -> io::Result<()>;
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Stored<D, F> {
+    Directory(D),
+    File(F),
+}
+
+#[derive(Debug, Error)]
+pub enum BackendError {
+    #[error("backend could not be read")]
+    Io(#[from] io::Error),
+    #[error("internal error getting node")]
+    Get(#[from] GetError),
+    #[error("internal error getting directory")]
+    GetDirectory(#[from] GetDirectoryError),
+    #[error("internal error getting file")]
+    GetFile(#[from] GetFileError),
+    #[error("internal error writing node")]
+    Write(#[from] WriteError),
+}
+
+// Backend - Implementations
+
+impl<D, F, B> Backend<D, F> for Arc<B>
+where
+    B: Backend<D, F> + ?Sized,
+{
+    fn list(&self, path: &Path) -> io::Result<Vec<OsString>> {
+        (**self).list(path)
+    }
+
+    fn load(&self, path: &Path) -> io::Result<Option<Stored<D, F>>> {
+        (**self).load(path)
+    }
+
+    fn readonly(&self, path: &Path) -> io::Result<bool> {
+        (**self).readonly(path)
+    }
+
+    fn remove(&self, path: &Path) -> io::Result<()> {
+        (**self).remove(path)
+    }
+
+    fn set_readonly(&self, path: &Path, readonly: bool) -> io::Result<()> {
+        (**self).set_readonly(path, readonly)
+    }
+
+    fn store(&self, path: &Path, stored: Stored<D, F>) -> io::Result<()> {
+        (**self).store(path, stored)
+    }
+}
+
+impl<D, F> Backend<D, F> for Mutex<BTreeMap<PathBuf, (Stored<D, F>, bool)>>
+where
+    D: Clone + Send,
+    F: Clone + Send,
+{
+    fn list(&self, path: &Path) -> io::Result<Vec<OsString>> {
+        lock(self).map(|map| {
+            map.keys()
+                .filter(|key| key.parent() == Some(path))
+                .filter_map(|key| key.file_name().map(OsString::from))
+                .collect()
+        })
+    }
+
+    fn load(&self, path: &Path) -> io::Result<Option<Stored<D, F>>> {
+        lock(self).map(|map| map.get(path).map(|(stored, _)| stored.clone()))
+    }
+
+    fn readonly(&self, path: &Path) -> io::Result<bool> {
+        lock(self).map(|map| map.get(path).is_some_and(|(_, readonly)| *readonly))
+    }
+
+    fn remove(&self, path: &Path) -> io::Result<()> {
+        lock(self).map(|mut map| map.retain(|key, _| !key.starts_with(path)))
+    }
+
+    fn set_readonly(&self, path: &Path, readonly: bool) -> io::Result<()> {
+        lock(self).map(|mut map| {
+            if let Some((_, current)) = map.get_mut(path) {
+                *current = readonly;
+            }
+        })
+    }
+
+    fn store(&self, path: &Path, stored: Stored<D, F>) -> io::Result<()> {
+        lock(self).map(|mut map| {
+            let readonly = map.get(path).is_some_and(|(_, readonly)| *readonly);
+
+            map.insert(path.to_path_buf(), (stored, readonly));
+        })
+    }
+}
+
+// FileSystem - Backend
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + Clone + Default + 'static,
+    F: ValueType + Clone + Default + 'static,
+{
+    pub async fn with_backend<B>(options: Options<F>, backend: B) -> Result<Self, BackendError>
+    where
+        B: Backend<D, F> + 'static,
+    {
+        let persist = Arc::new(Persist::new(backend));
+        let fs = Self::with_journal(options, persist.clone());
+
+        fs.load(&persist.backend).await?;
+        persist.ready.store(true, Ordering::SeqCst);
+
+        Ok(fs)
+    }
+
+    pub async fn with_lazy_backend<B>(options: Options<F>, backend: B) -> Result<Self, BackendError>
+    where
+        B: Backend<D, F> + 'static,
+    {
+        let persist = Arc::new(Persist::new(backend));
+        let loader = persist.clone();
+        let fs = Self::with_hooks(
+            options,
+            D::default(),
+            Some(Journal::new(persist.clone())),
+            Some(Box::new(move |path| {
+                future::ready(loader.children(&path)).boxed()
+            })),
+        );
+
+        fs.load_root(&persist.backend).await?;
+        fs.restore_readonly(&persist.backend, Path::new("/")).await?;
+        persist.ready.store(true, Ordering::SeqCst);
+
+        Ok(fs)
+    }
+
+    async fn load<B>(&self, backend: &B) -> Result<(), BackendError>
+    where
+        B: Backend<D, F>,
+    {
+        self.load_root(backend).await?;
+
+        let mut loaded = vec![PathBuf::from("/")];
+        let mut pending = children(backend, Path::new("/"))?;
+
+        while let Some(path) = pending.pop() {
+            loaded.push(path.clone());
+
+            match backend.load(&path)? {
+                Some(Stored::Directory(value)) => {
+                    self.get_dir_default(&path)
+                        .await?
+                        .write(|mut current| *current = value)
+                        .await?;
+                    pending.extend(children(backend, &path)?);
+                }
+                Some(Stored::File(value)) => {
+                    self.get_file_default(&path)
+                        .await?
+                        .write(|mut current| *current = value)
+                        .await?;
+                }
+                _ => {}
+            }
+        }
+
+        for path in loaded.into_iter().rev() {
+            self.restore_readonly(backend, &path).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn load_root<B>(&self, backend: &B) -> Result<(), BackendError>
+    where
+        B: Backend<D, F>,
+    {
+        if let Some(Stored::Directory(value)) = backend.load(Path::new("/"))? {
+            self.write(|mut current| *current = value).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn restore_readonly<B>(&self, backend: &B, path: &Path) -> Result<(), BackendError>
+    where
+        B: Backend<D, F>,
+    {
+        if backend.readonly(path)? {
+            if let Some(node) = self.get(path, GetType::default()).await? {
+                node.set_readonly(true).await;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Persist
+
+struct Persist<B> {
+    backend: B,
+    failed: Mutex<Option<io::Error>>,
+    ready: AtomicBool,
+}
+
+// Persist - Library Traits
+
+impl<D, F, B> Sink<D, F> for Persist<B>
+where
+    B: Backend<D, F>,
+{
+    fn append(&self, record: Record<D, F>) {
+        if !self.ready.load(Ordering::SeqCst) {
+            return;
+        }
+
+        if let Err(err) = self.apply(record) {
+            if let Ok(mut failed) = self.failed.lock() {
+                failed.get_or_insert(err);
+            }
+        }
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        let failed = lock(&self.failed)?.take();
+
+        failed.map_or(Ok(()), Err)
+    }
+}
+
+// Persist - Methods
+
+impl<B> Persist<B> {
+    const fn new(backend: B) -> Self {
+        Self {
+            backend,
+            failed: Mutex::new(None),
+            ready: AtomicBool::new(false),
+        }
+    }
+
+    fn apply<D, F>(&self, record: Record<D, F>) -> io::Result<()>
+    where
+        B: Backend<D, F>,
+    {
+        match record {
+            Record::CreateDir { path, value } | Record::WriteDir { path, value } => {
+                self.backend.store(&path, Stored::Directory(value))
+            }
+            Record::CreateFile { path, value } | Record::WriteFile { path, value } => {
+                self.backend.store(&path, Stored::File(value))
+            }
+            Record::Remove { path } => self.backend.remove(&path),
+            Record::Rename { from, to } => {
+                self.backend.remove(&to)?;
+
+                let mut pending = vec![(from.clone(), to)];
+
+                while let Some((from, to)) = pending.pop() {
+                    if let Some(stored) = self.backend.load(&from)? {
+                        self.backend.store(&to, stored)?;
+                        self.backend.set_readonly(&to, self.backend.readonly(&from)?)?;
+                    }
+
+                    pending.extend(
+                        self.backend
+                            .list(&from)?
+                            .into_iter()
+                            .map(|name| (from.join(&name), to.join(name))),
+                    );
+                }
+
+                self.backend.remove(&from)
+            }
+            Record::SetReadonly { path, readonly } => self.backend.set_readonly(&path, readonly),
+            Record::SetXattr { .. } => Ok(()),
+        }
+    }
+
+    fn children<D, F>(&self, path: &Path) -> Loaded<D, F>
+    where
+        B: Backend<D, F>,
+    {
+        let mut children = Vec::new();
+
+        for name in self.backend.list(path)? {
+            if let Some(stored) = self.backend.load(&path.join(&name))? {
+                children.push((name, stored));
+            }
+        }
+
+        Ok(children)
+    }
+}
+
+// Functions
+
+fn children<D, F, B>(backend: &B, path: &Path) -> io::Result<Vec<PathBuf>>
+where
+    B: Backend<D, F>,
+{
+    backend
+        .list(path)
+        .map(|names| names.into_iter().map(|name| path.join(name)).collect())
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> io::Result<MutexGuard<'_, T>> {
+    mutex
+        .lock()
+        .map_err(|_| io::Error::other("backend lock is poisoned"))
+}
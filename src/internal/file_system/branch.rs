@@ -0,0 +1,398 @@
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    ffi::OsString,
+    ops::{
+        Bound,
+        Deref,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+
+use async_lock::Mutex;
+
+use super::{
+    super::{
+        directory::{
+            attach::Attach,
+            get_ext::GetExt,
+            merge::{
+                MergeError,
+                Resolution,
+            },
+            tree::{
+                entries,
+                restore,
+                Tree,
+            },
+            Directory,
+        },
+        node::{
+            data::{
+                Data,
+                ValueType,
+            },
+            data_ext::{
+                DataExt,
+                WriteError,
+            },
+            permissions::Permissions,
+            Node,
+        },
+    },
+    FileSystem,
+};
+
+// Branch
+
+#[derive(Debug)]
+pub struct Branch<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    base: Mutex<Nodes<D, F>>,
+    fs: FileSystem<D, F>,
+    name: String,
+}
+
+// Branch - Standard Traits
+
+impl<D, F> Deref for Branch<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    type Target = FileSystem<D, F>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.fs
+    }
+}
+
+// Branch - Methods
+
+impl<D, F> Branch<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub fn into_inner(self) -> FileSystem<D, F> {
+        self.fs
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+// Diverged
+
+#[derive(Debug)]
+pub struct Diverged<'a, D, F> {
+    pub base: Version<'a, D, F>,
+    pub ours: Version<'a, D, F>,
+    pub theirs: Version<'a, D, F>,
+}
+
+#[derive(Debug)]
+pub enum Version<'a, D, F> {
+    Directory(&'a D),
+    File(&'a F),
+    Missing,
+}
+
+// FileSystem - Branch
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + Clone + 'static,
+    F: ValueType + Clone + 'static,
+{
+    pub async fn branch<N>(&self, name: N) -> Result<Branch<D, F>, WriteError>
+    where
+        N: Into<String>,
+    {
+        let (fs, base) = fork(&self.0).await?;
+
+        Ok(Branch {
+            base: Mutex::new(base),
+            fs,
+            name: name.into(),
+        })
+    }
+
+    #[allow(clippy::significant_drop_tightening)]
+    pub async fn merge_branch<R>(
+        &self,
+        branch: &Branch<D, F>,
+        resolve: R,
+    ) -> Result<(), MergeError>
+    where
+        D: PartialEq,
+        F: PartialEq,
+        R: Fn(&Path, Diverged<'_, D, F>) -> Resolution<F> + Send + Sync,
+    {
+        let mut base = branch.base.lock().await;
+        let ours = flatten(&self.0).await?;
+        let theirs = flatten(&branch.fs.0).await?;
+        let paths = base
+            .keys()
+            .chain(ours.keys())
+            .chain(theirs.keys())
+            .collect::<BTreeSet<_>>();
+
+        let mut skipped = Vec::<&Path>::new();
+
+        for path in paths {
+            if skipped.iter().any(|skipped| path.starts_with(skipped)) {
+                continue;
+            }
+
+            let (b, o, t) = (base.get(path), ours.get(path), theirs.get(path));
+
+            let removed_ours = o.is_none() && changed(&base, &theirs, path);
+            let removed_theirs = t.is_none() && changed(&base, &ours, path);
+
+            let target = if same(o, t) || (same(b, t) && !removed_ours) {
+                o.cloned()
+            } else if same(b, o) && !removed_theirs {
+                t.cloned()
+            } else {
+                let diverged = Diverged {
+                    base: version(b),
+                    ours: version(o),
+                    theirs: version(t),
+                };
+
+                match resolve(path, diverged) {
+                    Resolution::Ours => o.cloned(),
+                    Resolution::Theirs => t.cloned(),
+                    Resolution::Combined(value) => match (o, t) {
+                        (Some(Flat::Directory(_)), _) | (_, Some(Flat::Directory(_))) => {
+                            return Err(MergeError::Combined(path.clone()));
+                        }
+                        _ => Some(Flat::File(value)),
+                    },
+                }
+            };
+
+            let recurse = matches!(
+                (o, t, &target),
+                (
+                    Some(Flat::Directory(_)),
+                    Some(Flat::Directory(_)),
+                    Some(Flat::Directory(_))
+                )
+            );
+
+            if !same(o, target.as_ref()) {
+                apply(&self.0, path, o, target.clone()).await?;
+
+                if !recurse && matches!(target, Some(Flat::Directory(_))) {
+                    for (path, node) in below(&theirs, path) {
+                        apply(&self.0, path, None, Some(node.clone())).await?;
+                    }
+                }
+            }
+
+            if !recurse {
+                skipped.push(path);
+            }
+        }
+
+        *base = theirs;
+
+        Ok(())
+    }
+}
+
+// Flat
+
+#[derive(Clone, Debug)]
+enum Flat<D, F> {
+    Directory(D),
+    File(F),
+}
+
+type Nodes<D, F> = BTreeMap<PathBuf, Flat<D, F>>;
+
+async fn apply<D, F>(
+    root: &Directory<D, F>,
+    path: &Path,
+    current: Option<&Flat<D, F>>,
+    target: Option<Flat<D, F>>,
+) -> Result<(), MergeError>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    let missing = || MergeError::Missing(path.to_path_buf());
+
+    match (current, target) {
+        (Some(Flat::Directory(_)), Some(Flat::Directory(value))) => {
+            let dir = root.get_dir(path).await?.ok_or_else(missing)?;
+
+            dir.write(|mut current| *current = value).await?;
+        }
+        (Some(Flat::File(_)), Some(Flat::File(value))) => {
+            let file = root.get_file(path).await?.ok_or_else(missing)?;
+
+            file.write(|mut current| *current = value).await?;
+        }
+        (_, target) => {
+            let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+                return Err(missing());
+            };
+
+            let parent = root.get_dir(parent).await?.ok_or_else(missing)?;
+
+            match target {
+                Some(Flat::Directory(value)) => {
+                    parent.insert_dir(Arc::from(name), value, None).await?;
+                }
+                Some(Flat::File(value)) => {
+                    parent.insert_file(Arc::from(name), value, None).await?;
+                }
+                None => {
+                    parent.detach(name).await?.ok_or_else(missing)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn below<'a, D, F>(
+    nodes: &'a Nodes<D, F>,
+    path: &'a Path,
+) -> impl Iterator<Item = (&'a PathBuf, &'a Flat<D, F>)> {
+    nodes
+        .range::<Path, _>((Bound::Included(path), Bound::Unbounded))
+        .take_while(move |(key, _)| key.starts_with(path))
+        .filter(move |(key, _)| *key != path)
+}
+
+fn changed<D, F>(base: &Nodes<D, F>, side: &Nodes<D, F>, path: &Path) -> bool
+where
+    D: PartialEq,
+    F: PartialEq,
+{
+    below(base, path)
+        .chain(below(side, path))
+        .any(|(key, _)| !same(base.get(key), side.get(key)))
+}
+
+async fn flatten<D, F>(root: &Directory<D, F>) -> Result<Nodes<D, F>, WriteError>
+where
+    D: ValueType + Clone,
+    F: ValueType + Clone,
+{
+    let mut nodes = BTreeMap::new();
+    let mut pending = vec![(PathBuf::from("/"), root.clone())];
+
+    nodes.insert(
+        PathBuf::from("/"),
+        Flat::Directory(root.read(|value| value.clone()).await),
+    );
+
+    while let Some((path, dir)) = pending.pop() {
+        let entries = dir.entries().await.map_err(|_| WriteError::LoadFailed)?;
+
+        for (name, node) in entries {
+            let path = path.join(&*name);
+
+            match node {
+                Node::Directory(dir) => {
+                    let value = dir.read(|value| value.clone()).await;
+
+                    nodes.insert(path.clone(), Flat::Directory(value));
+                    pending.push((path, dir));
+                }
+                Node::File(file) => {
+                    let value = file.data().await.read().await.clone();
+
+                    nodes.insert(path, Flat::File(value));
+                }
+            }
+        }
+    }
+
+    Ok(nodes)
+}
+
+async fn fork<D, F>(root: &Directory<D, F>) -> Result<(FileSystem<D, F>, Nodes<D, F>), WriteError>
+where
+    D: ValueType + Clone + 'static,
+    F: ValueType + Clone + 'static,
+{
+    let value = root.read(|value| value.clone()).await;
+    let entries = entries(root).await.map_err(|_| WriteError::LoadFailed)?;
+    let nodes = nodes(&value, &entries);
+    let fs = FileSystem::without_sinks(root.context().options.clone(), value, None, None);
+
+    restore(&fs, entries).await?;
+    fs.set_readonly(root.readonly().await).await;
+    fs.install_sinks();
+
+    Ok((fs, nodes))
+}
+
+fn nodes<D, F>(value: &D, entries: &[(OsString, Tree<D, F>)]) -> Nodes<D, F>
+where
+    D: Clone,
+    F: Clone,
+{
+    let mut nodes = BTreeMap::new();
+    let mut pending = vec![(PathBuf::from("/"), entries)];
+
+    nodes.insert(PathBuf::from("/"), Flat::Directory(value.clone()));
+
+    while let Some((path, entries)) = pending.pop() {
+        for (name, tree) in entries {
+            let path = path.join(name);
+
+            match tree {
+                Tree::Directory { entries, value, .. } => {
+                    nodes.insert(path.clone(), Flat::Directory(value.clone()));
+                    pending.push((path, entries));
+                }
+                Tree::File { value, .. } => {
+                    nodes.insert(path, Flat::File(value.clone()));
+                }
+            }
+        }
+    }
+
+    nodes
+}
+
+fn same<D, F>(a: Option<&Flat<D, F>>, b: Option<&Flat<D, F>>) -> bool
+where
+    D: PartialEq,
+    F: PartialEq,
+{
+    match (a, b) {
+        (Some(Flat::Directory(a)), Some(Flat::Directory(b))) => a == b,
+        (Some(Flat::File(a)), Some(Flat::File(b))) => a == b,
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+const fn version<D, F>(node: Option<&Flat<D, F>>) -> Version<'_, D, F> {
+    match node {
+        Some(Flat::Directory(value)) => Version::Directory(value),
+        Some(Flat::File(value)) => Version::File(value),
+        None => Version::Missing,
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    fmt,
+    path::PathBuf,
+};
+
+use thiserror::Error;
+
+use super::{
+    super::{
+        directory::get_ext::{
+            GetDirectoryError,
+            GetExt,
+            GetFileError,
+        },
+        node::{
+            data::ValueType,
+            data_ext::{
+                DataExt,
+                WriteError,
+            },
+        },
+    },
+    options::Options,
+    FileSystem,
+};
+
+// Builder
+
+pub struct Builder<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    options: Options<F>,
+    root: Option<D>,
+    steps: Vec<Step<F>>,
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum BuildError {
+    #[error("internal error getting directory")]
+    GetDirectory(#[from] GetDirectoryError),
+    #[error("internal error getting file")]
+    GetFile(#[from] GetFileError),
+    #[error("internal error writing data")]
+    Write(#[from] WriteError),
+}
+
+enum Step<F> {
+    Dir(PathBuf),
+    File(PathBuf, F),
+}
+
+// Builder - Standard Traits
+
+impl<D, F> Default for Builder<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn default() -> Self {
+        Self {
+            options: Options::default(),
+            root: None,
+            steps: Vec::new(),
+        }
+    }
+}
+
+impl<D, F> fmt::Debug for Builder<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Builder")
+            .field("options", &self.options)
+            .field("steps", &self.steps.len())
+            .finish_non_exhaustive()
+    }
+}
+
+// Builder - Methods
+
+impl<D, F> Builder<D, F>
+where
+    D: ValueType + Default,
+    F: ValueType,
+{
+    pub async fn build(self) -> Result<FileSystem<D, F>, BuildError> {
+        let fs = FileSystem::with_root(self.options, self.root.unwrap_or_default());
+
+        for step in self.steps {
+            match step {
+                Step::Dir(path) => {
+                    fs.get_dir_or_create_with(path, D::default).await?;
+                }
+                Step::File(path, value) => match fs.get_file(&path).await? {
+                    Some(file) => file.write(|mut current| *current = value).await?,
+                    _ => {
+                        fs.get_file_or_create_with(path, || value).await?;
+                    }
+                },
+            }
+        }
+
+        Ok(fs)
+    }
+}
+
+impl<D, F> Builder<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub fn dir<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.steps.push(Step::Dir(path.into()));
+        self
+    }
+
+    #[must_use]
+    pub fn file<P>(mut self, path: P, value: F) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.steps.push(Step::File(path.into(), value));
+        self
+    }
+
+    #[must_use]
+    pub fn options(mut self, options: Options<F>) -> Self {
+        self.options = options;
+        self
+    }
+
+    #[must_use]
+    pub fn with_root_value(mut self, value: D) -> Self {
+        self.root = Some(value);
+        self
+    }
+}
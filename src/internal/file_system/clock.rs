@@ -0,0 +1,31 @@
+use std::time::SystemTime;
+
+// Clock
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> SystemTime;
+}
+
+// Clock - Blanket Implementation
+
+impl<C> Clock for C
+where
+    C: Fn() -> SystemTime + Send + Sync,
+{
+    fn now(&self) -> SystemTime {
+        self()
+    }
+}
+
+// SystemClock
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+// SystemClock - Library Traits
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
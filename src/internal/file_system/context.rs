@@ -0,0 +1,118 @@
+use std::{
+    fmt,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+use super::{
+    super::{
+        file::history::Snapshot,
+        node::identified::NodeId,
+    },
+    clock::Clock,
+    dedup::Dedup,
+    options::{
+        Options,
+        Order,
+    },
+    quota::{
+        Measure,
+        Quota,
+    },
+};
+
+// Context
+
+pub struct Context<F> {
+    pub(crate) case_insensitive: bool,
+    pub(crate) clamp_parent: bool,
+    pub(crate) clock: Arc<dyn Clock>,
+    pub(crate) dedup: Option<Dedup<F>>,
+    pub(crate) history: Option<(usize, Snapshot<F>)>,
+    pub(crate) max_components: Option<usize>,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) max_traversal: Option<usize>,
+    pub(crate) measure: Option<Measure<F>>,
+    pub(crate) options: Options<F>,
+    pub(crate) order: Order,
+    pub(crate) quota: Quota,
+    pub(crate) shards: usize,
+    ids: AtomicU64,
+}
+
+// Context - Standard Traits
+
+impl<F> Default for Context<F> {
+    fn default() -> Self {
+        Self::from(Options::default())
+    }
+}
+
+impl<F> fmt::Debug for Context<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context")
+            .field("case_insensitive", &self.case_insensitive)
+            .field("clamp_parent", &self.clamp_parent)
+            .field("dedup", &self.dedup.is_some())
+            .field("history", &self.history.map(|(history, _)| history))
+            .field("max_components", &self.max_components)
+            .field("max_depth", &self.max_depth)
+            .field("max_traversal", &self.max_traversal)
+            .field("order", &self.order)
+            .field("quota", &self.quota)
+            .field("shards", &self.shards)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> From<Options<F>> for Context<F> {
+    fn from(options: Options<F>) -> Self {
+        Self {
+            case_insensitive: options.case_insensitive,
+            clamp_parent: options.clamp_parent,
+            clock: options.clock.clone(),
+            dedup: options
+                .dedup
+                .map(|(bytes, snapshot)| Dedup::new(bytes, snapshot)),
+            history: options.history,
+            max_components: options.max_components,
+            max_depth: options.max_depth,
+            max_traversal: options.max_traversal,
+            measure: options.max_size.map(|(_, measure)| measure),
+            options: options.clone(),
+            order: options.order,
+            quota: Quota::new(
+                options.max_children,
+                options.max_nodes,
+                options.max_size.map(|(max_size, _)| max_size),
+            ),
+            shards: options.shards,
+            ids: AtomicU64::new(1),
+        }
+    }
+}
+
+// Context - Methods
+
+impl<F> Context<F> {
+    pub(crate) fn next_id(&self) -> NodeId {
+        NodeId(self.ids.fetch_add(1, Ordering::Relaxed))
+    }
+
+    pub(crate) fn len(&self, value: &F) -> usize {
+        self.measure.map_or(0, |measure| (measure.len)(value))
+    }
+
+    pub(crate) fn resize(&self, from: usize, to: &F) {
+        self.quota.resize(from, self.len(to));
+    }
+
+    pub(crate) fn take(&self, value: &mut F) -> Option<F> {
+        self.measure.map(|measure| (measure.take)(value))
+    }
+}
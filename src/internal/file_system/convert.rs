@@ -0,0 +1,100 @@
+use std::{
+    collections::HashMap,
+    hash::BuildHasher,
+    path::PathBuf,
+};
+
+use super::{
+    super::{
+        directory::get_ext::GetExt,
+        node::{
+            data::{
+                Data,
+                ValueType,
+            },
+            data_ext::DataExt,
+            Node,
+        },
+    },
+    FileSystem,
+};
+
+// FileSystem - Standard Traits
+
+impl<D, F, S> From<HashMap<PathBuf, F, S>> for FileSystem<D, F>
+where
+    D: ValueType + Default,
+    F: ValueType,
+    S: BuildHasher,
+{
+    fn from(files: HashMap<PathBuf, F, S>) -> Self {
+        files.into_iter().collect()
+    }
+}
+
+impl<D, F> FromIterator<(PathBuf, F)> for FileSystem<D, F>
+where
+    D: ValueType + Default,
+    F: ValueType,
+{
+    fn from_iter<I>(files: I) -> Self
+    where
+        I: IntoIterator<Item = (PathBuf, F)>,
+    {
+        let mut fs = Self::new();
+
+        fs.extend(files);
+        fs
+    }
+}
+
+// FileSystem - Methods
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub async fn to_map(&self) -> HashMap<PathBuf, F>
+    where
+        F: Clone,
+    {
+        let mut files = HashMap::new();
+        let mut pending = vec![(PathBuf::from("/"), self.0.clone())];
+
+        while let Some((path, dir)) = pending.pop() {
+            for (name, node) in dir.entries().await.unwrap_or_default() {
+                let path = path.join(&*name);
+
+                match node {
+                    Node::Directory(dir) => pending.push((path, dir)),
+                    Node::File(file) => {
+                        let value = file.data().await.read().await.clone();
+
+                        files.insert(path, value);
+                    }
+                }
+            }
+        }
+
+        files
+    }
+
+    pub(crate) async fn insert_all<I>(&self, files: I)
+    where
+        I: IntoIterator<Item = (PathBuf, F)>,
+        D: Default,
+    {
+        for (path, value) in files {
+            match self.get_file(&path).await {
+                Ok(Some(file)) => {
+                    let _ = file.write(|mut current| *current = value).await;
+                }
+                Ok(None) => {
+                    let _ = self.get_file_or_create_with(&path, || value).await;
+                }
+                Err(_) => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        Arc,
+        Mutex,
+        Weak,
+    },
+};
+
+use async_lock::RwLock;
+
+use super::super::{
+    file::history::Snapshot,
+    node::{
+        data::{
+            Value,
+            ValueType,
+        },
+        digest::hash,
+    },
+};
+
+// Bytes
+
+pub type Bytes<F> = fn(&F) -> &[u8];
+
+// Dedup
+
+pub struct Dedup<F> {
+    bytes: Bytes<F>,
+    pool: Mutex<HashMap<u64, Vec<Weak<RwLock<F>>>>>,
+    pub(crate) snapshot: Snapshot<F>,
+}
+
+// Dedup - Standard Traits
+
+impl<F> fmt::Debug for Dedup<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dedup").finish_non_exhaustive()
+    }
+}
+
+// Dedup - Methods
+
+impl<F> Dedup<F> {
+    pub(crate) fn new(bytes: Bytes<F>, snapshot: Snapshot<F>) -> Self {
+        Self {
+            bytes,
+            pool: Mutex::new(HashMap::new()),
+            snapshot,
+        }
+    }
+}
+
+impl<F> Dedup<F>
+where
+    F: ValueType,
+{
+    pub(crate) fn intern(&self, value: F) -> Value<F> {
+        let key = hash((self.bytes)(&value));
+        let Ok(mut pool) = self.pool.lock() else {
+            return Value::new(value);
+        };
+
+        let candidates = pool.entry(key).or_default();
+
+        candidates.retain(|candidate| candidate.strong_count() > 0);
+
+        let shared = candidates
+            .iter()
+            .filter_map(Weak::upgrade)
+            .find(|candidate| {
+                candidate
+                    .try_read()
+                    .is_some_and(|candidate| (self.bytes)(&candidate) == (self.bytes)(&value))
+            });
+
+        shared.map_or_else(
+            || {
+                let value = Value::new(value);
+
+                candidates.push(Arc::downgrade(&value.0));
+                value
+            },
+            Value,
+        )
+    }
+
+    pub(crate) fn release(&self, value: &Value<F>) -> bool {
+        let Ok(mut pool) = self.pool.lock() else {
+            return false;
+        };
+
+        if Arc::strong_count(&value.0) > 1 {
+            return false;
+        }
+
+        for candidates in pool.values_mut() {
+            candidates.retain(|candidate| !candidate.ptr_eq(&Arc::downgrade(&value.0)));
+        }
+
+        pool.retain(|_, candidates| !candidates.is_empty());
+
+        true
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    ffi::OsString,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use thiserror::Error;
+use tokio::fs;
+
+use super::{
+    super::{
+        directory::{
+            attach::is_normal,
+            get_ext::{
+                file_or_create_with,
+                GetDirectoryError,
+                GetExt,
+                GetFileError,
+            },
+        },
+        node::{
+            data::ValueType,
+            data_ext::{
+                DataExt,
+                WriteError,
+            },
+            Node,
+        },
+    },
+    options::Options,
+    FileSystem,
+};
+
+// DiskError
+
+#[derive(Debug, Error)]
+pub enum DiskError {
+    #[error("target path already exists: {0}")]
+    AlreadyExists(PathBuf),
+    #[error("disk entry could not be read or written")]
+    Io(#[from] io::Error),
+    #[error("internal error getting directory")]
+    GetDirectory(#[from] GetDirectoryError),
+    #[error("internal error getting file")]
+    GetFile(#[from] GetFileError),
+    #[error("node name is not a single path component: {0:?}")]
+    InvalidName(OsString),
+    #[error("disk entry is neither a regular file nor a directory: {0}")]
+    Unsupported(PathBuf),
+    #[error("internal error writing file")]
+    Write(#[from] WriteError),
+}
+
+// WriteMode
+
+#[derive(Clone, Copy, Debug, Default)]
+pub enum WriteMode {
+    #[default]
+    Create,
+    Merge,
+    Overwrite,
+}
+
+// FileSystem - Disk
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + Default,
+    F: ValueType,
+{
+    pub async fn from_disk<P, L>(path: P, loader: L) -> Result<Self, DiskError>
+    where
+        P: AsRef<Path> + Send,
+        L: Fn(Vec<u8>) -> F + Send + Sync,
+    {
+        Self::from_disk_with(path, D::default, loader).await
+    }
+}
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub async fn from_disk_with<P, C, L>(path: P, dirs: C, loader: L) -> Result<Self, DiskError>
+    where
+        P: AsRef<Path> + Send,
+        C: Fn() -> D + Send + Sync,
+        L: Fn(Vec<u8>) -> F + Send + Sync,
+    {
+        let fs = Self::with_root(Options::default(), dirs());
+        let mut pending = vec![(path.as_ref().to_path_buf(), PathBuf::from("/"))];
+
+        while let Some((source, target)) = pending.pop() {
+            let mut entries = fs::read_dir(&source).await?;
+
+            while let Some(entry) = entries.next_entry().await? {
+                let file_type = entry.file_type().await?;
+                let target = target.join(entry.file_name());
+
+                if file_type.is_dir() {
+                    fs.get_dir_or_create_with(&target, &dirs).await?;
+                    pending.push((entry.path(), target));
+                } else if file_type.is_file() {
+                    let bytes = fs::read(entry.path()).await?;
+
+                    file_or_create_with(&fs.0, &target, &dirs, || loader(bytes)).await?;
+                } else {
+                    return Err(DiskError::Unsupported(entry.path()));
+                }
+            }
+        }
+
+        Ok(fs)
+    }
+
+    pub async fn write_to_disk<P, S>(
+        &self,
+        path: P,
+        mode: WriteMode,
+        serializer: S,
+    ) -> Result<(), DiskError>
+    where
+        P: AsRef<Path> + Send,
+        S: Fn(&F) -> Vec<u8> + Send + Sync,
+    {
+        let path = path.as_ref().to_path_buf();
+        let mut dirs = Vec::new();
+        let mut files = Vec::new();
+        let mut pending = vec![(path.clone(), self.0.clone())];
+
+        while let Some((target, dir)) = pending.pop() {
+            for (name, node) in dir.entries().await? {
+                if !is_normal(&name) {
+                    return Err(DiskError::InvalidName(name.to_os_string()));
+                }
+
+                let target = target.join(&*name);
+
+                match node {
+                    Node::Directory(dir) => {
+                        dirs.push(target.clone());
+                        pending.push((target, dir));
+                    }
+                    Node::File(file) => files.push((target, file)),
+                }
+            }
+        }
+
+        let existing = match fs::symlink_metadata(&path).await {
+            Ok(metadata) => Some(metadata),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        match (mode, existing) {
+            (WriteMode::Create, Some(_)) => return Err(DiskError::AlreadyExists(path)),
+            (WriteMode::Overwrite, Some(metadata)) if metadata.is_dir() => {
+                fs::remove_dir_all(&path).await?;
+            }
+            (WriteMode::Overwrite, Some(_)) => fs::remove_file(&path).await?,
+            _ => {}
+        }
+
+        fs::create_dir_all(&path).await?;
+
+        for target in dirs {
+            fs::create_dir_all(&target).await?;
+        }
+
+        for (target, file) in files {
+            let bytes = file.read(|value| serializer(&value)).await;
+
+            fs::write(&target, bytes).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+use super::{
+    super::directory::get_ext::{
+        GetDirectoryError,
+        GetExt,
+        GetFileError,
+    },
+    FileSystem,
+};
+
+// FixtureError
+
+#[derive(Debug, Error)]
+pub enum FixtureError {
+    #[cfg(feature = "toml")]
+    #[error("fixture is not a valid TOML table of paths to contents")]
+    Toml(#[from] toml::de::Error),
+    #[cfg(feature = "yaml")]
+    #[error("fixture is not a valid YAML mapping of paths to contents")]
+    Yaml(#[from] serde_yaml::Error),
+    #[error("internal error getting directory")]
+    GetDirectory(#[from] GetDirectoryError),
+    #[error("internal error getting file")]
+    GetFile(#[from] GetFileError),
+}
+
+// FileSystem - Fixture
+
+impl FileSystem<(), String> {
+    #[cfg(feature = "toml")]
+    pub async fn from_toml(fixture: &str) -> Result<Self, FixtureError> {
+        Self::from_fixture(toml::from_str(fixture)?).await
+    }
+
+    #[cfg(feature = "yaml")]
+    pub async fn from_yaml(fixture: &str) -> Result<Self, FixtureError> {
+        Self::from_fixture(serde_yaml::from_str(fixture)?).await
+    }
+
+    #[allow(clippy::match_bool)]
+    async fn from_fixture(fixture: BTreeMap<String, String>) -> Result<Self, FixtureError> {
+        let fs = Self::new();
+
+        for (path, contents) in fixture {
+            match path.ends_with('/') {
+                true => {
+                    fs.get_dir_default(path).await?;
+                }
+                _ => {
+                    fs.get_file_or_create_with(path, || contents).await?;
+                }
+            }
+        }
+
+        Ok(fs)
+    }
+}
@@ -0,0 +1,302 @@
+use std::{
+    fmt,
+    future::Future,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        OnceLock,
+        RwLock,
+        Weak,
+    },
+};
+
+use futures::{
+    channel::mpsc::UnboundedSender,
+    future::BoxFuture,
+    FutureExt,
+};
+
+use super::{
+    super::node::{
+        data::ValueType,
+        Node,
+    },
+    access::{
+        Action,
+        Decision,
+        Interceptor,
+    },
+    audit::{
+        Audit,
+        Mutation,
+    },
+    index::Index,
+    journal::{
+        Journal,
+        Record,
+    },
+    lazy::Loader,
+    lru::Lru,
+    undo::{
+        Operation,
+        UndoLog,
+    },
+    watch::Change,
+    FileSystem,
+};
+
+// Hooks
+
+pub struct Hooks<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) audit: OnceLock<Audit>,
+    pub(crate) callbacks: RwLock<Vec<(Event, Callback<D, F>)>>,
+    pub(crate) index: Option<Index<D, F>>,
+    pub(crate) interceptor: RwLock<Option<Interceptor>>,
+    pub(crate) journal: Option<Journal<D, F>>,
+    pub(crate) loader: Option<Loader<D, F>>,
+    pub(crate) lru: Option<Lru<D, F>>,
+    pub(crate) undo: Weak<UndoLog<D, F>>,
+    pub(crate) watchers: Mutex<Vec<UnboundedSender<Change>>>,
+}
+
+// Hooks - Standard Traits
+
+impl<D, F> Default for Hooks<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn default() -> Self {
+        Self {
+            audit: OnceLock::new(),
+            callbacks: RwLock::default(),
+            index: None,
+            interceptor: RwLock::default(),
+            journal: None,
+            loader: None,
+            lru: None,
+            undo: Weak::new(),
+            watchers: Mutex::default(),
+        }
+    }
+}
+
+impl<D, F> fmt::Debug for Hooks<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hooks")
+            .field("audit", &self.audit)
+            .field("callbacks", &self.callbacks.read().map_or(0, |callbacks| callbacks.len()))
+            .field("index", &self.index)
+            .field(
+                "interceptor",
+                &self
+                    .interceptor
+                    .read()
+                    .is_ok_and(|interceptor| interceptor.is_some()),
+            )
+            .field("journal", &self.journal.is_some())
+            .field("loader", &self.loader.is_some())
+            .field("lru", &self.lru)
+            .field("undo", &self.undo.upgrade())
+            .field("watchers", &self.watchers.lock().map_or(0, |watchers| watchers.len()))
+            .finish()
+    }
+}
+
+// Hooks - Methods
+
+impl<D, F> Hooks<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) async fn journal<P, R>(&self, path: P, record: R)
+    where
+        P: Future<Output = PathBuf> + Send,
+        R: FnOnce(&Journal<D, F>, PathBuf) -> Record<D, F> + Send,
+    {
+        if let Some(journal) = &self.journal {
+            journal.append(record(journal, path.await));
+        }
+    }
+
+    pub(crate) async fn notify<P>(&self, event: Event, path: P, node: &Node<D, F>)
+    where
+        P: Future<Output = PathBuf> + Send,
+    {
+        self.notify_as(event, path, node, None, None).await;
+    }
+
+    pub(crate) async fn notify_as<P>(
+        &self,
+        event: Event,
+        path: P,
+        node: &Node<D, F>,
+        from: Option<PathBuf>,
+        context: Option<&str>,
+    ) where
+        P: Future<Output = PathBuf> + Send,
+    {
+        let callbacks = match self.callbacks.read() {
+            Ok(callbacks) => callbacks
+                .iter()
+                .filter(|(registered, _)| *registered == event)
+                .map(|(_, callback)| callback.clone())
+                .collect::<Vec<_>>(),
+            _ => return,
+        };
+
+        let watched = self
+            .watchers
+            .lock()
+            .is_ok_and(|watchers| !watchers.is_empty());
+
+        if callbacks.is_empty() && !watched && self.audit.get().is_none() {
+            return;
+        }
+
+        let path = path.await;
+
+        if let Some(audit) = self.audit.get() {
+            audit.record(
+                match event {
+                    Event::Create => Mutation::Create,
+                    Event::Metadata => Mutation::Metadata,
+                    Event::Remove => Mutation::Remove,
+                    Event::Rename => Mutation::Rename,
+                    Event::Write => Mutation::Write,
+                },
+                path.clone(),
+                from,
+                context,
+            );
+        }
+
+        if watched {
+            self.watch(event, &path);
+        }
+
+        for callback in callbacks {
+            callback(path.clone(), node.clone()).await;
+        }
+    }
+
+    pub(crate) async fn permit<P>(&self, action: Action, path: P) -> bool
+    where
+        P: Future<Output = PathBuf> + Send,
+    {
+        let interceptor = match self.interceptor.read() {
+            Ok(interceptor) => interceptor.clone(),
+            _ => return false,
+        };
+
+        match interceptor {
+            Some(interceptor) => interceptor(action, path.await).await == Decision::Allow,
+            _ => true,
+        }
+    }
+
+    fn watch(&self, kind: Event, path: &Path) {
+        if let Ok(mut watchers) = self.watchers.lock() {
+            watchers.retain(|watcher| {
+                let path = path.to_path_buf();
+
+                watcher.unbounded_send(Change { kind, path }).is_ok()
+            });
+        }
+    }
+
+    pub(crate) fn record<O>(&self, operation: O)
+    where
+        O: FnOnce() -> Operation<D, F>,
+    {
+        if let Some(undo) = self.undo.upgrade() {
+            undo.record(operation());
+        }
+    }
+}
+
+// Event
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Event {
+    Create,
+    Metadata,
+    Remove,
+    Rename,
+    Write,
+}
+
+// Callback
+
+pub type Callback<D, F> = Arc<dyn Fn(PathBuf, Node<D, F>) -> BoxFuture<'static, ()> + Send + Sync>;
+
+// FileSystem - Hooks
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub fn on_create<H, R>(&self, hook: H)
+    where
+        H: Fn(PathBuf, Node<D, F>) -> R + Send + Sync + 'static,
+        R: Future<Output = ()> + Send + 'static,
+    {
+        self.register(Event::Create, hook);
+    }
+
+    pub fn on_metadata<H, R>(&self, hook: H)
+    where
+        H: Fn(PathBuf, Node<D, F>) -> R + Send + Sync + 'static,
+        R: Future<Output = ()> + Send + 'static,
+    {
+        self.register(Event::Metadata, hook);
+    }
+
+    pub fn on_remove<H, R>(&self, hook: H)
+    where
+        H: Fn(PathBuf, Node<D, F>) -> R + Send + Sync + 'static,
+        R: Future<Output = ()> + Send + 'static,
+    {
+        self.register(Event::Remove, hook);
+    }
+
+    pub fn on_rename<H, R>(&self, hook: H)
+    where
+        H: Fn(PathBuf, Node<D, F>) -> R + Send + Sync + 'static,
+        R: Future<Output = ()> + Send + 'static,
+    {
+        self.register(Event::Rename, hook);
+    }
+
+    pub fn on_write<H, R>(&self, hook: H)
+    where
+        H: Fn(PathBuf, Node<D, F>) -> R + Send + Sync + 'static,
+        R: Future<Output = ()> + Send + 'static,
+    {
+        self.register(Event::Write, hook);
+    }
+
+    pub(crate) fn register<H, R>(&self, event: Event, hook: H)
+    where
+        H: Fn(PathBuf, Node<D, F>) -> R + Send + Sync + 'static,
+        R: Future<Output = ()> + Send + 'static,
+    {
+        if let Ok(mut callbacks) = self.hooks().callbacks.write() {
+            callbacks.push((event, Arc::new(move |path, node| hook(path, node).boxed())));
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    collections::{
+        BTreeSet,
+        HashMap,
+    },
+    fmt,
+    future,
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        Weak,
+    },
+};
+
+use async_lock::RwLock;
+
+use super::{
+    super::{
+        directory::{
+            get::{
+                Get,
+                GetError,
+                GetType,
+            },
+            get_ext::GetExt,
+            visit::{
+                Flow,
+                Visit,
+            },
+            Directory,
+            Shared,
+        },
+        file::{
+            File,
+            Internal,
+        },
+        node::{
+            data::ValueType,
+            Node,
+        },
+    },
+    access::Action,
+    FileSystem,
+};
+
+// Index
+
+pub struct Index<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    state: Mutex<State<D, F>>,
+}
+
+// Index - Standard Traits
+
+impl<D, F> Default for Index<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(State {
+                nodes: HashMap::new(),
+                paths: BTreeSet::new(),
+            }),
+        }
+    }
+}
+
+impl<D, F> fmt::Debug for Index<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Index")
+            .field("len", &self.state.lock().map_or(0, |state| state.nodes.len()))
+            .finish()
+    }
+}
+
+// Index - Methods
+
+impl<D, F> Index<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) fn get(&self, path: &Path) -> Option<Node<D, F>> {
+        self.state.lock().ok()?.nodes.get(path)?.upgrade()
+    }
+
+    pub(crate) async fn flatten(path: PathBuf, node: &Node<D, F>) -> Vec<(PathBuf, Node<D, F>)> {
+        let mut nodes = Vec::new();
+        let mut pending = vec![(path, node.clone())];
+
+        while let Some((path, node)) = pending.pop() {
+            if let Node::Directory(dir) = &node {
+                for (name, node) in dir.children.entries().await {
+                    pending.push((path.join(&*name), node));
+                }
+            }
+
+            nodes.push((path, node));
+        }
+
+        nodes
+    }
+
+    pub(crate) fn replace_tree(&self, path: &Path, nodes: Vec<(PathBuf, Node<D, F>)>) {
+        if let Ok(mut state) = self.state.lock() {
+            state.remove_tree(path);
+
+            for (path, node) in nodes {
+                state.paths.insert(path.clone());
+                state.nodes.insert(path, Entry::from(&node));
+            }
+        }
+    }
+
+    pub(crate) fn prefix(&self, path: &Path) -> Vec<(PathBuf, Node<D, F>)> {
+        let Ok(state) = self.state.lock() else {
+            return Vec::new();
+        };
+
+        state
+            .paths
+            .range(path.to_path_buf()..)
+            .skip_while(|indexed| *indexed == path)
+            .take_while(|indexed| indexed.starts_with(path))
+            .filter_map(|indexed| {
+                state
+                    .nodes
+                    .get(indexed)
+                    .and_then(Entry::upgrade)
+                    .map(|node| (indexed.clone(), node))
+            })
+            .collect()
+    }
+}
+
+// State
+
+struct State<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    nodes: HashMap<PathBuf, Entry<D, F>>,
+    paths: BTreeSet<PathBuf>,
+}
+
+// State - Methods
+
+impl<D, F> State<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn remove_tree(&mut self, path: &Path) {
+        let removed = self
+            .paths
+            .range(path.to_path_buf()..)
+            .take_while(|indexed| indexed.starts_with(path))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for path in removed {
+            self.paths.remove(&path);
+            self.nodes.remove(&path);
+        }
+    }
+}
+
+// Entry
+
+enum Entry<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    Directory(Weak<Shared<D, F>>),
+    File(Weak<RwLock<Internal<D, F>>>),
+}
+
+// Entry - Standard Traits
+
+impl<D, F> From<&Node<D, F>> for Entry<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn from(node: &Node<D, F>) -> Self {
+        match node {
+            Node::Directory(dir) => Self::Directory(Arc::downgrade(&dir.0)),
+            Node::File(file) => Self::File(Arc::downgrade(&file.0)),
+        }
+    }
+}
+
+// Entry - Methods
+
+impl<D, F> Entry<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn upgrade(&self) -> Option<Node<D, F>> {
+        match self {
+            Self::Directory(dir) => dir.upgrade().map(|dir| Node::Directory(Directory(dir))),
+            Self::File(file) => file.upgrade().map(|file| Node::File(File(file))),
+        }
+    }
+}
+
+// FileSystem - Index
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub async fn lookup<P>(&self, path: P) -> Result<Option<Node<D, F>>, GetError>
+    where
+        P: AsRef<Path> + Send,
+    {
+        let path = path.as_ref();
+        let indexed = match (&self.0.hooks().index, normalized(path)) {
+            (Some(index), true) => index.get(path),
+            _ => None,
+        };
+
+        match indexed {
+            Some(node) => {
+                let last = path.components().count().saturating_sub(1);
+
+                self.0.accept(Some(node), || path.to_path_buf(), last).await
+            }
+            _ => self.0.get(path, GetType::default()).await,
+        }
+    }
+
+    #[allow(clippy::match_bool)]
+    pub async fn lookup_prefix<P>(&self, path: P) -> Vec<(PathBuf, Node<D, F>)>
+    where
+        P: AsRef<Path> + Send,
+    {
+        let path = path.as_ref();
+
+        if let (Some(index), None, true) = (
+            &self.0.hooks().index,
+            &self.0.hooks().loader,
+            normalized(path),
+        ) {
+            let permitted = self
+                .0
+                .hooks()
+                .permit(Action::Get, future::ready(path.to_path_buf()))
+                .await;
+
+            return match permitted {
+                true => index.prefix(path),
+                _ => Vec::new(),
+            };
+        }
+
+        let Ok(Some(dir)) = self.0.get_dir(path).await else {
+            return Vec::new();
+        };
+
+        let mut found = Vec::new();
+
+        dir.visit(|relative, node| {
+            found.push((path.join(relative.strip_prefix("/").unwrap_or(relative)), node.clone()));
+
+            Flow::Continue
+        })
+        .await;
+
+        found
+    }
+}
+
+// Functions
+
+fn normalized(path: &Path) -> bool {
+    let mut components = path.components();
+
+    components.next() == Some(Component::RootDir)
+        && components.all(|component| matches!(component, Component::Normal(_)))
+}
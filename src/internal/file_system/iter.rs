@@ -0,0 +1,130 @@
+use std::{
+    collections::VecDeque,
+    path::PathBuf,
+};
+
+use futures::{
+    executor::block_on,
+    stream::{
+        self,
+        BoxStream,
+    },
+    StreamExt,
+};
+
+use super::{
+    super::{
+        directory::{
+            walk::Walked,
+            Directory,
+        },
+        node::{
+            data::ValueType,
+            Node,
+        },
+    },
+    FileSystem,
+};
+
+// FileSystem - Standard Traits
+
+impl<D, F> Extend<(PathBuf, F)> for FileSystem<D, F>
+where
+    D: ValueType + Default,
+    F: ValueType,
+{
+    fn extend<I>(&mut self, files: I)
+    where
+        I: IntoIterator<Item = (PathBuf, F)>,
+    {
+        block_on(self.insert_all(files));
+    }
+}
+
+impl<D, F> IntoIterator for FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    type IntoIter = IntoIter<D, F>;
+    type Item = Walked<D, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            pending: vec![(PathBuf::from("/"), self.0)],
+            ready: VecDeque::new(),
+        }
+    }
+}
+
+// FileSystem - Methods
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + 'static,
+    F: ValueType + 'static,
+{
+    #[must_use]
+    pub fn into_stream(self) -> BoxStream<'static, Walked<D, F>> {
+        stream::unfold(self.into_iter(), |mut iter| async move {
+            iter.next_async().await.map(|walked| (walked, iter))
+        })
+        .boxed()
+    }
+}
+
+// IntoIter
+
+#[derive(Debug)]
+pub struct IntoIter<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pending: Vec<(PathBuf, Directory<D, F>)>,
+    ready: VecDeque<Walked<D, F>>,
+}
+
+// IntoIter - Standard Traits
+
+impl<D, F> Iterator for IntoIter<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    type Item = Walked<D, F>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        block_on(self.next_async())
+    }
+}
+
+// IntoIter - Methods
+
+impl<D, F> IntoIter<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn next_async(&mut self) -> Option<Walked<D, F>> {
+        loop {
+            if let Some((path, node)) = self.ready.pop_front() {
+                if let Node::Directory(dir) = &node {
+                    self.pending.push((path.clone(), dir.clone()));
+                }
+
+                return Some((path, node));
+            }
+
+            let (path, dir) = self.pending.pop()?;
+
+            self.ready.extend(
+                dir.entries()
+                    .await
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(name, node)| (path.join(&*name), node)),
+            );
+        }
+    }
+}
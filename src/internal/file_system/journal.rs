@@ -0,0 +1,359 @@
+use std::{
+    fmt,
+    io,
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use thiserror::Error;
+
+use super::{
+    super::{
+        directory::{
+            entry::Entry,
+            get::{
+                Get,
+                GetError,
+                GetType,
+            },
+            get_ext::{
+                GetDirectoryError,
+                GetExt,
+                GetFileError,
+            },
+            rename::{
+                Rename,
+                RenameError,
+            },
+        },
+        file::history::Snapshot,
+        node::{
+            data::ValueType,
+            data_ext::{
+                DataExt,
+                WriteError,
+            },
+            permissions::Permissions,
+            xattrs::Xattrs,
+        },
+    },
+    options::Options,
+    FileSystem,
+};
+
+// Record
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(
+        Deserialize,
+        Serialize
+    )
+)]
+pub enum Record<D, F> {
+    CreateDir { path: PathBuf, value: D },
+    CreateFile { path: PathBuf, value: F },
+    Remove { path: PathBuf },
+    Rename { from: PathBuf, to: PathBuf },
+    SetReadonly { path: PathBuf, readonly: bool },
+    SetXattr { path: PathBuf, name: String, value: Option<Vec<u8>> },
+    WriteDir { path: PathBuf, value: D },
+    WriteFile { path: PathBuf, value: F },
+}
+
+// Sink
+
+pub trait Sink<D, F>: Send + Sync {
+    fn append(&self, record: Record<D, F>);
+
+    fn flush(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Sink - Implementations
+
+impl<D, F, S> Sink<D, F> for Arc<S>
+where
+    S: Sink<D, F> + ?Sized,
+{
+    fn append(&self, record: Record<D, F>) {
+        (**self).append(record);
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        (**self).flush()
+    }
+}
+
+impl<D, F> Sink<D, F> for Mutex<Vec<Record<D, F>>>
+where
+    D: Send,
+    F: Send,
+{
+    fn append(&self, record: Record<D, F>) {
+        if let Ok(mut records) = self.lock() {
+            records.push(record);
+        }
+    }
+}
+
+// ReplayError
+
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    #[error("internal error getting node")]
+    Get(#[from] GetError),
+    #[error("internal error getting directory")]
+    GetDirectory(#[from] GetDirectoryError),
+    #[error("internal error getting file")]
+    GetFile(#[from] GetFileError),
+    #[error("journal referenced a missing node: {0}")]
+    NotFound(PathBuf),
+    #[error("internal error renaming node")]
+    Rename(#[from] RenameError),
+    #[error("internal error writing node")]
+    Write(#[from] WriteError),
+}
+
+// FileSystem - Journal
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + Clone + Default + 'static,
+    F: ValueType + Clone + 'static,
+{
+    #[must_use]
+    pub fn with_journal<S>(options: Options<F>, sink: S) -> Self
+    where
+        S: Sink<D, F> + 'static,
+    {
+        Self::with_hooks(options, D::default(), Some(Journal::new(sink)), None)
+    }
+}
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub fn flush(&self) -> io::Result<()> {
+        self.0.hooks().journal.as_ref().map_or(Ok(()), Journal::flush)
+    }
+}
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + Default,
+    F: ValueType + Default,
+{
+    pub async fn replay<I>(records: I) -> Result<Self, ReplayError>
+    where
+        I: IntoIterator<Item = Record<D, F>> + Send,
+        I::IntoIter: Send,
+    {
+        let fs = Self::new();
+
+        for record in records {
+            fs.apply(record).await?;
+        }
+
+        Ok(fs)
+    }
+
+    async fn apply(&self, record: Record<D, F>) -> Result<(), ReplayError> {
+        match record {
+            Record::CreateDir { path, value } => {
+                self.get_dir_default(path)
+                    .await?
+                    .write(|mut current| *current = value)
+                    .await?;
+            }
+            Record::CreateFile { path, value } => {
+                self.get_file_default(path)
+                    .await?
+                    .write(|mut current| *current = value)
+                    .await?;
+            }
+            Record::Remove { path } => {
+                let parent = path.parent().unwrap_or(&path);
+                let name = path.file_name().unwrap_or_default();
+
+                match self.get_dir(parent).await?.as_ref() {
+                    Some(parent) => match parent.entry(name).await {
+                        Entry::Occupied(entry) => {
+                            entry.remove().await?;
+                        }
+                        Entry::Vacant(_) => return Err(ReplayError::NotFound(path)),
+                    },
+                    _ => return Err(ReplayError::NotFound(path)),
+                }
+            }
+            Record::Rename { from, to } => self.rename(from, to).await?,
+            Record::SetReadonly { path, readonly } => {
+                self.get(&path, GetType::default())
+                    .await?
+                    .ok_or_else(|| ReplayError::NotFound(path.clone()))?
+                    .set_readonly(readonly)
+                    .await;
+            }
+            Record::SetXattr { path, name, value } => {
+                let node = self
+                    .get(&path, GetType::default())
+                    .await?
+                    .ok_or_else(|| ReplayError::NotFound(path.clone()))?;
+
+                match value {
+                    Some(value) => node.set_xattr(&name, value).await?,
+                    _ => node.remove_xattr(&name).await?,
+                };
+            }
+            Record::WriteDir { path, value } => {
+                self.get_dir(&path)
+                    .await?
+                    .ok_or_else(|| ReplayError::NotFound(path.clone()))?
+                    .write(|mut current| *current = value)
+                    .await?;
+            }
+            Record::WriteFile { path, value } => {
+                self.get_file(&path)
+                    .await?
+                    .ok_or_else(|| ReplayError::NotFound(path.clone()))?
+                    .write(|mut current| *current = value)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Journal
+
+pub struct Journal<D, F> {
+    dir: (Snapshot<D>, Write<D>),
+    file: (Snapshot<F>, Write<F>),
+    sink: Arc<dyn Sink<D, F>>,
+}
+
+type Write<V> = Arc<dyn Fn(PathBuf, V) + Send + Sync>;
+
+// Journal - Standard Traits
+
+impl<D, F> Clone for Journal<D, F> {
+    fn clone(&self) -> Self {
+        Self {
+            dir: self.dir.clone(),
+            file: self.file.clone(),
+            sink: self.sink.clone(),
+        }
+    }
+}
+
+impl<D, F> fmt::Debug for Journal<D, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Journal").finish_non_exhaustive()
+    }
+}
+
+// Journal - Methods
+
+impl<D, F> Journal<D, F>
+where
+    D: Clone + 'static,
+    F: Clone + 'static,
+{
+    pub(crate) fn new<S>(sink: S) -> Self
+    where
+        S: Sink<D, F> + 'static,
+    {
+        let sink: Arc<dyn Sink<D, F>> = Arc::new(sink);
+        let dir = sink.clone();
+        let file = sink.clone();
+
+        Self {
+            dir: (
+                D::clone,
+                Arc::new(move |path, value| dir.append(Record::WriteDir { path, value })),
+            ),
+            file: (
+                F::clone,
+                Arc::new(move |path, value| file.append(Record::WriteFile { path, value })),
+            ),
+            sink,
+        }
+    }
+}
+
+impl<D, F> Journal<D, F> {
+    pub(crate) fn append(&self, record: Record<D, F>) {
+        self.sink.append(record);
+    }
+
+    pub(crate) fn flush(&self) -> io::Result<()> {
+        self.sink.flush()
+    }
+
+    pub(crate) fn append_dir(&self, path: PathBuf) -> Append<D> {
+        Append::new(path, self.dir.clone())
+    }
+
+    pub(crate) fn append_file(&self, path: PathBuf) -> Append<F> {
+        Append::new(path, self.file.clone())
+    }
+
+    pub(crate) fn snapshot_dir(&self, value: &D) -> D {
+        (self.dir.0)(value)
+    }
+
+    pub(crate) fn snapshot_file(&self, value: &F) -> F {
+        (self.file.0)(value)
+    }
+}
+
+// Append
+
+pub struct Append<V> {
+    path: PathBuf,
+    snapshot: Snapshot<V>,
+    write: Write<V>,
+}
+
+// Append - Standard Traits
+
+impl<V> fmt::Debug for Append<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Append")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+// Append - Methods
+
+impl<V> Append<V> {
+    fn new(path: PathBuf, (snapshot, write): (Snapshot<V>, Write<V>)) -> Self {
+        Self {
+            path,
+            snapshot,
+            write,
+        }
+    }
+
+    pub(crate) fn append(&self, value: &V) {
+        (self.write)(self.path.clone(), (self.snapshot)(value));
+    }
+
+    pub(crate) fn snapshot(&self, value: &V) -> V {
+        (self.snapshot)(value)
+    }
+}
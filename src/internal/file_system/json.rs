@@ -0,0 +1,84 @@
+use std::{
+    collections::BTreeMap,
+    ffi::{
+        OsStr,
+        OsString,
+    },
+    sync::Arc,
+};
+
+use futures::{
+    future::BoxFuture,
+    FutureExt,
+};
+use serde::de::DeserializeOwned;
+
+use super::{
+    super::{
+        directory::{
+            attach::is_normal,
+            json::{
+                Json,
+                JsonError,
+            },
+            Directory,
+        },
+        node::data::ValueType,
+    },
+    options::Options,
+    FileSystem,
+};
+
+// FileSystem - Json
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + Default + DeserializeOwned + 'static,
+    F: ValueType + Default + DeserializeOwned + 'static,
+{
+    pub async fn from_json(json: &str) -> Result<Self, JsonError> {
+        match serde_json::from_str::<Json<D, F>>(json)? {
+            Json::Dir { value, children } => {
+                let fs = Self::with_root(Options::default(), value);
+
+                restore(&fs, children).await?;
+
+                Ok(fs)
+            }
+            Json::File { .. } => Err(JsonError::InvalidRoot),
+        }
+    }
+}
+
+// Functions
+
+fn restore<D, F>(
+    dir: &Directory<D, F>,
+    children: BTreeMap<String, Json<D, F>>,
+) -> BoxFuture<'_, Result<(), JsonError>>
+where
+    D: ValueType + 'static,
+    F: ValueType + 'static,
+{
+    async move {
+        for (name, json) in children {
+            if !is_normal(OsStr::new(&name)) {
+                return Err(JsonError::InvalidName(OsString::from(name)));
+            }
+
+            let name = Arc::from(OsStr::new(&name));
+
+            match json {
+                Json::Dir { value, children } => {
+                    restore(&dir.insert_dir(name, value, None).await?, children).await?;
+                }
+                Json::File { value } => {
+                    dir.insert_file(name, value, None).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+    .boxed()
+}
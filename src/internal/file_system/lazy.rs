@@ -0,0 +1,45 @@
+use std::{
+    ffi::OsString,
+    future::Future,
+    io,
+    path::PathBuf,
+};
+
+use futures::{
+    future::BoxFuture,
+    FutureExt,
+};
+
+use super::{
+    super::node::data::ValueType,
+    backend::Stored,
+    options::Options,
+    FileSystem,
+};
+
+// Loader
+
+pub type Loaded<D, F> = io::Result<Vec<(OsString, Stored<D, F>)>>;
+pub type Loader<D, F> = Box<dyn Fn(PathBuf) -> BoxFuture<'static, Loaded<D, F>> + Send + Sync>;
+
+// FileSystem - Lazy
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + Default + 'static,
+    F: ValueType + 'static,
+{
+    #[must_use]
+    pub fn with_loader<L, P>(options: Options<F>, loader: L) -> Self
+    where
+        L: Fn(PathBuf) -> P + Send + Sync + 'static,
+        P: Future<Output = Loaded<D, F>> + Send + 'static,
+    {
+        Self::with_hooks(
+            options,
+            D::default(),
+            None,
+            Some(Box::new(move |path| loader(path).boxed())),
+        )
+    }
+}
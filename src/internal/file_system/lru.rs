@@ -0,0 +1,252 @@
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    fmt,
+    path::Path,
+    sync::{
+        Arc,
+        Mutex,
+        OnceLock,
+        Weak,
+    },
+};
+
+use async_lock::RwLock;
+use futures::{
+    future::{
+        self,
+        BoxFuture,
+    },
+    FutureExt,
+};
+
+use super::{
+    super::{
+        file::{
+            File,
+            Internal,
+        },
+        node::{
+            child::Child,
+            data::ValueType,
+            located::Located,
+            named::Named,
+            Node,
+        },
+    },
+    hooks::Event,
+    journal::Record,
+};
+
+// Evict
+
+pub type Evict = Arc<dyn Fn(&Path) + Send + Sync>;
+
+// Lru
+
+pub struct Lru<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    capacity: usize,
+    evict: OnceLock<Evict>,
+    state: Mutex<State<D, F>>,
+}
+
+// Lru - Standard Traits
+
+impl<D, F> fmt::Debug for Lru<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lru")
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+// Lru - Methods
+
+impl<D, F> Lru<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            evict: OnceLock::new(),
+            state: Mutex::new(State {
+                order: BTreeMap::new(),
+                tick: 0,
+                ticks: HashMap::new(),
+            }),
+        }
+    }
+
+    pub(crate) fn evict(&self) -> BoxFuture<'_, ()> {
+        async move {
+            while let Some(file) = self.pop() {
+                let (Some(parent), Some(name)) = (file.parent().await, file.name().await) else {
+                    continue;
+                };
+
+                if !parent
+                    .children
+                    .get(&name)
+                    .await
+                    .is_some_and(|node| node.same_node(&Node::File(file.clone())))
+                {
+                    continue;
+                }
+
+                let path = file.path().await;
+
+                let Ok(Some(_)) = parent.unlink(&name).await else {
+                    continue;
+                };
+
+                if let Some(journal) = &parent.hooks().journal {
+                    journal.append(Record::Remove { path: path.clone() });
+                }
+
+                parent
+                    .hooks()
+                    .notify(
+                        Event::Remove,
+                        future::ready(path.clone()),
+                        &Node::File(file.clone()),
+                    )
+                    .await;
+
+                if let Some(evict) = self.evict.get() {
+                    evict(&path);
+                }
+            }
+        }
+        .boxed()
+    }
+
+    pub(crate) fn install(&self, evict: Evict) {
+        let _ = self.evict.set(evict);
+    }
+
+    pub(crate) async fn forget(&self, node: &Node<D, F>) {
+        for file in files(node).await {
+            if let Ok(mut state) = self.state.lock() {
+                if let Some(tick) = state.ticks.remove(&key(&file)) {
+                    state.order.remove(&tick);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn touch(&self, file: &File<D, F>) {
+        if let Ok(mut state) = self.state.lock() {
+            if state.ticks.contains_key(&key(file)) {
+                state.insert(file);
+            }
+        }
+    }
+
+    pub(crate) fn track(&self, file: &File<D, F>) {
+        if let Ok(mut state) = self.state.lock() {
+            state.insert(file);
+        }
+    }
+
+    pub(crate) async fn track_tree(&self, node: &Node<D, F>) {
+        for file in files(node).await {
+            self.track(&file);
+        }
+    }
+
+    fn pop(&self) -> Option<File<D, F>> {
+        self.state.lock().ok()?.pop(self.capacity)
+    }
+}
+
+// State
+
+struct State<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    order: BTreeMap<u64, Weak<RwLock<Internal<D, F>>>>,
+    tick: u64,
+    ticks: HashMap<usize, u64>,
+}
+
+// State - Methods
+
+impl<D, F> State<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn insert(&mut self, file: &File<D, F>) {
+        self.tick += 1;
+
+        if let Some(tick) = self.ticks.insert(key(file), self.tick) {
+            self.order.remove(&tick);
+        }
+
+        self.order.insert(self.tick, Arc::downgrade(&file.0));
+    }
+
+    fn pop(&mut self, capacity: usize) -> Option<File<D, F>> {
+        while self.ticks.len() > capacity {
+            let (_, file) = self.order.pop_first()?;
+
+            self.ticks.remove(&(file.as_ptr() as usize));
+
+            if let Some(file) = file.upgrade() {
+                return Some(File(file));
+            }
+        }
+
+        None
+    }
+}
+
+// Functions
+
+async fn files<D, F>(node: &Node<D, F>) -> Vec<File<D, F>>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    let mut files = Vec::new();
+    let mut pending = vec![node.clone()];
+
+    while let Some(node) = pending.pop() {
+        match node {
+            Node::Directory(dir) => {
+                pending.extend(
+                    dir.children
+                        .entries()
+                        .await
+                        .into_iter()
+                        .map(|(_, node)| node),
+                );
+            }
+            Node::File(file) => files.push(file),
+        }
+    }
+
+    files
+}
+
+fn key<D, F>(file: &File<D, F>) -> usize
+where
+    D: ValueType,
+    F: ValueType,
+{
+    Arc::as_ptr(&file.0) as usize
+}
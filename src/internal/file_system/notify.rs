@@ -0,0 +1,36 @@
+use notify_types::event::{
+    CreateKind,
+    DataChange,
+    EventKind,
+    MetadataKind,
+    ModifyKind,
+    RemoveKind,
+    RenameMode,
+};
+
+use super::{
+    hooks::Event,
+    watch::Change,
+};
+
+// Event - Notify
+
+impl From<Event> for EventKind {
+    fn from(event: Event) -> Self {
+        match event {
+            Event::Create => Self::Create(CreateKind::Any),
+            Event::Metadata => Self::Modify(ModifyKind::Metadata(MetadataKind::Any)),
+            Event::Remove => Self::Remove(RemoveKind::Any),
+            Event::Rename => Self::Modify(ModifyKind::Name(RenameMode::To)),
+            Event::Write => Self::Modify(ModifyKind::Data(DataChange::Any)),
+        }
+    }
+}
+
+// Change - Notify
+
+impl From<Change> for notify_types::event::Event {
+    fn from(change: Change) -> Self {
+        Self::new(EventKind::from(change.kind)).add_path(change.path)
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    fmt,
+    mem,
+    path::Path,
+    sync::Arc,
+};
+
+use super::{
+    super::{
+        file::history::Snapshot,
+        node::data_len::DataLen,
+    },
+    audit::{
+        AuditEntry,
+        AuditSink,
+    },
+    clock::{
+        Clock,
+        SystemClock,
+    },
+    dedup::Bytes,
+    lru::Evict,
+    quota::Measure,
+};
+
+// Options
+
+pub struct Options<F> {
+    pub(crate) audit: Option<usize>,
+    pub(crate) capacity: Option<usize>,
+    pub(crate) case_insensitive: bool,
+    pub(crate) clamp_parent: bool,
+    pub(crate) clock: Arc<dyn Clock>,
+    pub(crate) dedup: Option<(Bytes<F>, Snapshot<F>)>,
+    pub(crate) history: Option<(usize, Snapshot<F>)>,
+    pub(crate) index: bool,
+    pub(crate) max_children: Option<usize>,
+    pub(crate) max_components: Option<usize>,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) max_nodes: Option<usize>,
+    pub(crate) max_size: Option<(usize, Measure<F>)>,
+    pub(crate) max_traversal: Option<usize>,
+    pub(crate) on_audit: Option<AuditSink>,
+    pub(crate) on_evict: Option<Evict>,
+    pub(crate) order: Order,
+    pub(crate) shards: usize,
+    pub(crate) undo: Option<usize>,
+}
+
+// Options - Standard Traits
+
+impl<F> Default for Options<F> {
+    fn default() -> Self {
+        Self {
+            audit: None,
+            capacity: None,
+            case_insensitive: false,
+            clamp_parent: false,
+            clock: Arc::new(SystemClock),
+            dedup: None,
+            history: None,
+            index: false,
+            max_children: None,
+            max_components: None,
+            max_depth: None,
+            max_nodes: None,
+            max_size: None,
+            max_traversal: None,
+            on_audit: None,
+            on_evict: None,
+            order: Order::default(),
+            shards: 1,
+            undo: None,
+        }
+    }
+}
+
+impl<F> Clone for Options<F> {
+    fn clone(&self) -> Self {
+        Self {
+            audit: self.audit,
+            capacity: self.capacity,
+            case_insensitive: self.case_insensitive,
+            clamp_parent: self.clamp_parent,
+            clock: self.clock.clone(),
+            dedup: self.dedup,
+            history: self.history,
+            index: self.index,
+            max_children: self.max_children,
+            max_components: self.max_components,
+            max_depth: self.max_depth,
+            max_nodes: self.max_nodes,
+            max_size: self.max_size,
+            max_traversal: self.max_traversal,
+            on_audit: self.on_audit.clone(),
+            on_evict: self.on_evict.clone(),
+            order: self.order,
+            shards: self.shards,
+            undo: self.undo,
+        }
+    }
+}
+
+impl<F> fmt::Debug for Options<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Options")
+            .field("audit", &self.audit)
+            .field("capacity", &self.capacity)
+            .field("case_insensitive", &self.case_insensitive)
+            .field("clamp_parent", &self.clamp_parent)
+            .field("dedup", &self.dedup.is_some())
+            .field("history", &self.history.map(|(history, _)| history))
+            .field("index", &self.index)
+            .field("max_children", &self.max_children)
+            .field("max_components", &self.max_components)
+            .field("max_depth", &self.max_depth)
+            .field("max_nodes", &self.max_nodes)
+            .field("max_size", &self.max_size.map(|(max_size, _)| max_size))
+            .field("max_traversal", &self.max_traversal)
+            .field("order", &self.order)
+            .field("shards", &self.shards)
+            .field("undo", &self.undo)
+            .finish_non_exhaustive()
+    }
+}
+
+// Options - Methods
+
+impl<F> Options<F> {
+    #[must_use]
+    pub const fn audit(mut self, audit: usize) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    #[must_use]
+    pub const fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    #[must_use]
+    pub const fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    #[must_use]
+    pub const fn clamp_parent(mut self, clamp_parent: bool) -> Self {
+        self.clamp_parent = clamp_parent;
+        self
+    }
+
+    #[must_use]
+    pub fn clock<C>(mut self, clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    #[must_use]
+    pub const fn index(mut self, index: bool) -> Self {
+        self.index = index;
+        self
+    }
+
+    #[must_use]
+    pub const fn max_children(mut self, max_children: usize) -> Self {
+        self.max_children = Some(max_children);
+        self
+    }
+
+    #[must_use]
+    pub const fn max_components(mut self, max_components: usize) -> Self {
+        self.max_components = Some(max_components);
+        self
+    }
+
+    #[must_use]
+    pub const fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    #[must_use]
+    pub const fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
+    #[must_use]
+    pub const fn max_traversal(mut self, max_traversal: usize) -> Self {
+        self.max_traversal = Some(max_traversal);
+        self
+    }
+
+    #[must_use]
+    pub fn on_audit<A>(mut self, on_audit: A) -> Self
+    where
+        A: Fn(&AuditEntry) + Send + Sync + 'static,
+    {
+        self.on_audit = Some(Arc::new(on_audit));
+        self
+    }
+
+    #[must_use]
+    pub fn on_evict<E>(mut self, on_evict: E) -> Self
+    where
+        E: Fn(&Path) + Send + Sync + 'static,
+    {
+        self.on_evict = Some(Arc::new(on_evict));
+        self
+    }
+
+    #[must_use]
+    pub const fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    #[must_use]
+    pub const fn shards(mut self, shards: usize) -> Self {
+        self.shards = shards;
+        self
+    }
+
+    #[must_use]
+    pub const fn undo(mut self, undo: usize) -> Self {
+        self.undo = Some(undo);
+        self
+    }
+}
+
+impl<F> Options<F>
+where
+    F: Clone,
+{
+    #[must_use]
+    pub fn history(mut self, history: usize) -> Self {
+        self.history = Some((history, F::clone));
+        self
+    }
+}
+
+impl<F> Options<F>
+where
+    F: AsRef<[u8]> + Clone,
+{
+    #[must_use]
+    pub fn dedup(mut self) -> Self {
+        self.dedup = Some((F::as_ref, F::clone));
+        self
+    }
+}
+
+impl<F> Options<F>
+where
+    F: DataLen + Default,
+{
+    #[must_use]
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some((
+            max_size,
+            Measure {
+                len: F::data_len,
+                take: mem::take,
+            },
+        ));
+        self
+    }
+}
+
+// Order
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Order {
+    #[default]
+    Hashed,
+    Insertion,
+    #[cfg(feature = "im")]
+    Persistent,
+    Sorted,
+}
@@ -0,0 +1,66 @@
+use std::{
+    collections::BTreeSet,
+    path::PathBuf,
+};
+
+use super::{
+    super::node::{
+        data::ValueType,
+        Node,
+    },
+    FileSystem,
+};
+
+// Listing
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Listing {
+    pub common_prefixes: Vec<String>,
+    pub keys: Vec<String>,
+}
+
+// FileSystem - Prefix
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub async fn list_prefix(&self, prefix: &str, delimiter: Option<&str>) -> Listing {
+        let scope = prefix.rfind('/').map_or("", |index| &prefix[..index]);
+        let mut common_prefixes = BTreeSet::new();
+        let mut keys = BTreeSet::new();
+
+        for (path, node) in self.lookup_prefix(PathBuf::from("/").join(scope)).await {
+            if let Node::Directory(_) = node {
+                continue;
+            }
+
+            let key = path
+                .strip_prefix("/")
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .into_owned();
+
+            let Some(rest) = key.strip_prefix(prefix) else {
+                continue;
+            };
+
+            let common = delimiter.and_then(|delimiter| {
+                rest.find(delimiter)
+                    .map(|index| format!("{prefix}{}", &rest[..index + delimiter.len()]))
+            });
+
+            if let Some(common) = common {
+                common_prefixes.insert(common);
+            } else {
+                keys.insert(key);
+            }
+        }
+
+        Listing {
+            common_prefixes: common_prefixes.into_iter().collect(),
+            keys: keys.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    fmt,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+};
+
+// Quota
+
+#[derive(Debug, Default)]
+pub struct Quota {
+    pub(crate) max_children: Option<usize>,
+    pub(crate) max_nodes: Option<usize>,
+    pub(crate) max_size: Option<usize>,
+    nodes: AtomicUsize,
+    size: AtomicUsize,
+}
+
+// Quota - Methods
+
+impl Quota {
+    pub(crate) const fn new(
+        max_children: Option<usize>,
+        max_nodes: Option<usize>,
+        max_size: Option<usize>,
+    ) -> Self {
+        Self {
+            max_children,
+            max_nodes,
+            max_size,
+            nodes: AtomicUsize::new(0),
+            size: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn exceeded(&self) -> bool {
+        self.max_size
+            .is_some_and(|max| self.size.load(Ordering::SeqCst) > max)
+    }
+
+    pub(crate) fn admit(&self, children: usize, size: usize) -> bool {
+        self.admit_tree(children, 1, size)
+    }
+
+    pub(crate) fn admit_tree(&self, children: usize, nodes: usize, size: usize) -> bool {
+        if self.max_children.is_some_and(|max| children >= max)
+            || !Self::update(&self.nodes, self.max_nodes, 0, nodes)
+        {
+            return false;
+        }
+
+        if Self::update(&self.size, self.max_size, 0, size) {
+            return true;
+        }
+
+        self.release(nodes, 0);
+
+        false
+    }
+
+    pub(crate) fn fits(&self, nodes: usize, size: usize) -> bool {
+        Self::within(&self.nodes, self.max_nodes, nodes)
+            && Self::within(&self.size, self.max_size, size)
+    }
+
+    pub(crate) fn release(&self, nodes: usize, size: usize) {
+        Self::update(&self.nodes, None, nodes, 0);
+        Self::update(&self.size, None, size, 0);
+    }
+
+    pub(crate) fn restore(&self, nodes: usize, size: usize) {
+        Self::update(&self.nodes, None, 0, nodes);
+        Self::update(&self.size, None, 0, size);
+    }
+
+    pub(crate) fn resize(&self, from: usize, to: usize) {
+        Self::update(&self.size, None, from, to);
+    }
+
+    fn within(counter: &AtomicUsize, max: Option<usize>, add: usize) -> bool {
+        max.is_none_or(|max| counter.load(Ordering::SeqCst) + add <= max)
+    }
+
+    fn update(counter: &AtomicUsize, max: Option<usize>, remove: usize, add: usize) -> bool {
+        counter
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+                let next = current.saturating_sub(remove) + add;
+
+                match max {
+                    Some(max) if add > remove && next > max => None,
+                    _ => Some(next),
+                }
+            })
+            .is_ok()
+    }
+}
+
+// Measure
+
+pub struct Measure<F> {
+    pub(crate) len: fn(&F) -> usize,
+    pub(crate) take: fn(&mut F) -> F,
+}
+
+// Measure - Standard Traits
+
+impl<F> Clone for Measure<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for Measure<F> {}
+
+impl<F> fmt::Debug for Measure<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Measure").finish_non_exhaustive()
+    }
+}
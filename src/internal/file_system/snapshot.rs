@@ -0,0 +1,60 @@
+use serde::de::DeserializeOwned;
+
+use super::{
+    super::{
+        directory::{
+            snapshot::{
+                Image,
+                SnapshotError,
+                MAGIC,
+                VERSION,
+            },
+            tree::{
+                restore,
+                Tree,
+            },
+        },
+        node::{
+            data::ValueType,
+            permissions::Permissions,
+        },
+    },
+    options::Options,
+    FileSystem,
+};
+
+// FileSystem - Snapshot
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + DeserializeOwned + 'static,
+    F: ValueType + DeserializeOwned + 'static,
+{
+    pub async fn load_snapshot(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let image = postcard::from_bytes::<Image<D, F>>(bytes)?;
+
+        if image.magic != MAGIC {
+            return Err(SnapshotError::InvalidFormat);
+        }
+
+        if image.version != VERSION {
+            return Err(SnapshotError::UnsupportedVersion(image.version));
+        }
+
+        match image.root {
+            Tree::Directory {
+                entries,
+                readonly,
+                value,
+            } => {
+                let fs = Self::with_root(Options::default(), value);
+
+                restore(&fs, entries).await?;
+                fs.set_readonly(readonly).await;
+
+                Ok(fs)
+            }
+            Tree::File { .. } => Err(SnapshotError::InvalidFormat),
+        }
+    }
+}
@@ -0,0 +1,485 @@
+use std::{
+    collections::VecDeque,
+    ffi::OsStr,
+    fmt,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use futures::FutureExt;
+use thiserror::Error;
+
+use super::{
+    super::{
+        directory::{
+            Directory,
+            Reference,
+        },
+        node::{
+            data::ValueType,
+            data_ext::WriteError,
+            located::Located,
+            permissions::Permissions,
+            xattrs::Xattrs,
+            Node,
+        },
+    },
+    access::Action,
+    hooks::Event,
+    journal::Record,
+    FileSystem,
+};
+
+// UndoError
+
+#[derive(Clone, Copy, Debug, Error)]
+pub enum UndoError {
+    #[error("file system has changed since the operation was recorded")]
+    Diverged,
+    #[error("internal error writing file system")]
+    Write(#[from] WriteError),
+}
+
+// UndoLog
+
+pub struct UndoLog<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    limit: usize,
+    operations: Mutex<Operations<D, F>>,
+}
+
+// UndoLog - Standard Traits
+
+impl<D, F> fmt::Debug for UndoLog<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UndoLog")
+            .field("limit", &self.limit)
+            .finish_non_exhaustive()
+    }
+}
+
+// UndoLog - Methods
+
+impl<D, F> UndoLog<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub(crate) const fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            operations: Mutex::new(Operations {
+                redo: Vec::new(),
+                undo: VecDeque::new(),
+            }),
+        }
+    }
+
+    pub(crate) fn record(&self, operation: Operation<D, F>) {
+        if let Ok(mut operations) = self.operations.lock() {
+            operations.redo.clear();
+            operations.undo.push_back(operation);
+
+            while operations.undo.len() > self.limit {
+                operations.undo.pop_front();
+            }
+        }
+    }
+
+    fn pop_redo(&self) -> Option<Operation<D, F>> {
+        self.operations
+            .lock()
+            .ok()
+            .and_then(|mut operations| operations.redo.pop())
+    }
+
+    fn pop_undo(&self) -> Option<Operation<D, F>> {
+        self.operations
+            .lock()
+            .ok()
+            .and_then(|mut operations| operations.undo.pop_back())
+    }
+
+    fn push_redo(&self, operation: Operation<D, F>) {
+        if let Ok(mut operations) = self.operations.lock() {
+            operations.redo.push(operation);
+        }
+    }
+
+    fn push_undo(&self, operation: Operation<D, F>) {
+        if let Ok(mut operations) = self.operations.lock() {
+            operations.undo.push_back(operation);
+        }
+    }
+}
+
+// FileSystem - Undo
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    pub async fn redo(&self) -> Result<bool, UndoError> {
+        let Some(undo) = &self.1 else {
+            return Ok(false);
+        };
+
+        let Some(operation) = undo.pop_redo() else {
+            return Ok(false);
+        };
+
+        match operation.apply().await {
+            Ok(()) => {
+                undo.push_undo(operation);
+                Ok(true)
+            }
+            Err(err) => {
+                undo.push_redo(operation);
+                Err(err)
+            }
+        }
+    }
+
+    pub async fn undo(&self) -> Result<bool, UndoError> {
+        let Some(undo) = &self.1 else {
+            return Ok(false);
+        };
+
+        let Some(operation) = undo.pop_undo() else {
+            return Ok(false);
+        };
+
+        match operation.revert().await {
+            Ok(()) => {
+                undo.push_redo(operation);
+                Ok(true)
+            }
+            Err(err) => {
+                undo.push_undo(operation);
+                Err(err)
+            }
+        }
+    }
+}
+
+// Operation
+
+#[derive(Debug)]
+pub enum Operation<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    Insert {
+        dir: Reference<D, F>,
+        name: Arc<OsStr>,
+        node: Node<D, F>,
+        replaced: Option<Node<D, F>>,
+    },
+    Remove {
+        dir: Reference<D, F>,
+        name: Arc<OsStr>,
+        node: Node<D, F>,
+    },
+    Rename {
+        from: (Reference<D, F>, Arc<OsStr>),
+        to: (Reference<D, F>, Arc<OsStr>),
+        node: Node<D, F>,
+        replaced: Option<Node<D, F>>,
+    },
+    Xattr {
+        node: Node<D, F>,
+        name: String,
+        previous: Option<Vec<u8>>,
+        value: Option<Vec<u8>>,
+    },
+}
+
+// Operation - Methods
+
+impl<D, F> Operation<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn apply(&self) -> Result<(), UndoError> {
+        let edits = match self {
+            Self::Insert {
+                dir,
+                name,
+                node,
+                replaced,
+            } => {
+                let dir = upgrade(dir)?;
+                let mut edits = replaced
+                    .iter()
+                    .map(|replaced| Edit::Take(dir.clone(), name, replaced))
+                    .collect::<Vec<_>>();
+
+                edits.push(Edit::Put(dir, name, node));
+                edits
+            }
+            Self::Remove { dir, name, node } => vec![Edit::Take(upgrade(dir)?, name, node)],
+            Self::Rename {
+                from: (from, from_name),
+                to: (to, to_name),
+                node,
+                replaced,
+            } => {
+                let to = upgrade(to)?;
+                let mut edits = vec![Edit::Take(upgrade(from)?, from_name, node)];
+
+                edits.extend(
+                    replaced
+                        .iter()
+                        .map(|replaced| Edit::Take(to.clone(), to_name, replaced)),
+                );
+                edits.push(Edit::Put(to, to_name, node));
+                edits
+            }
+            Self::Xattr {
+                node,
+                name,
+                previous,
+                value,
+            } => return xattr(node, name, previous.as_deref(), value.as_deref()).await,
+        };
+
+        perform(&edits).await
+    }
+
+    async fn revert(&self) -> Result<(), UndoError> {
+        let edits = match self {
+            Self::Insert {
+                dir,
+                name,
+                node,
+                replaced,
+            } => {
+                let dir = upgrade(dir)?;
+                let mut edits = vec![Edit::Take(dir.clone(), name, node)];
+
+                edits.extend(
+                    replaced
+                        .iter()
+                        .map(|replaced| Edit::Put(dir.clone(), name, replaced)),
+                );
+                edits
+            }
+            Self::Remove { dir, name, node } => vec![Edit::Put(upgrade(dir)?, name, node)],
+            Self::Rename {
+                from: (from, from_name),
+                to: (to, to_name),
+                node,
+                replaced,
+            } => {
+                let to = upgrade(to)?;
+                let mut edits = vec![Edit::Take(to.clone(), to_name, node)];
+
+                edits.extend(
+                    replaced
+                        .iter()
+                        .map(|replaced| Edit::Put(to.clone(), to_name, replaced)),
+                );
+                edits.push(Edit::Put(upgrade(from)?, from_name, node));
+                edits
+            }
+            Self::Xattr {
+                node,
+                name,
+                previous,
+                value,
+            } => return xattr(node, name, value.as_deref(), previous.as_deref()).await,
+        };
+
+        perform(&edits).await
+    }
+}
+
+// Edit
+
+enum Edit<'a, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    Put(Directory<D, F>, &'a Arc<OsStr>, &'a Node<D, F>),
+    Take(Directory<D, F>, &'a Arc<OsStr>, &'a Node<D, F>),
+}
+
+// Edit - Methods
+
+impl<D, F> Edit<'_, D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn check(&self, previous: &[Self]) -> Result<(), UndoError> {
+        let (dir, name, action) = match self {
+            Self::Put(dir, name, _) => {
+                let vacated = previous.iter().any(|edit| {
+                    matches!(edit, Self::Take(taken, taken_name, _)
+                        if taken.ptr_eq(dir) && taken_name == name)
+                });
+
+                if !vacated && dir.get_child(name).await?.is_some() {
+                    return Err(UndoError::Diverged);
+                }
+
+                (dir, name, Action::Create)
+            }
+            Self::Take(dir, name, node) => {
+                if !dir
+                    .get_child(name)
+                    .await?
+                    .is_some_and(|current| current.same_node(node))
+                {
+                    return Err(UndoError::Diverged);
+                }
+
+                (dir, name, Action::Remove)
+            }
+        };
+
+        let name: &OsStr = name;
+
+        if dir.readonly().await {
+            return Err(WriteError::PermissionDenied.into());
+        }
+
+        dir.authorize(action, name).await.map_err(UndoError::from)
+    }
+
+    async fn run(&self) -> Result<(), UndoError> {
+        match self {
+            Self::Put(dir, name, node) => put(dir, name, node).await,
+            Self::Take(dir, name, node) => take(dir, name, node).await,
+        }
+    }
+
+    async fn rollback(&self) -> Result<(), UndoError> {
+        match self {
+            Self::Put(dir, name, node) => take(dir, name, node).await,
+            Self::Take(dir, name, node) => put(dir, name, node).await,
+        }
+    }
+}
+
+// Operations
+
+struct Operations<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    redo: Vec<Operation<D, F>>,
+    undo: VecDeque<Operation<D, F>>,
+}
+
+// Functions
+
+async fn perform<D, F>(edits: &[Edit<'_, D, F>]) -> Result<(), UndoError>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    for (index, edit) in edits.iter().enumerate() {
+        edit.check(&edits[..index]).await?;
+    }
+
+    for (index, edit) in edits.iter().enumerate() {
+        if let Err(err) = edit.run().await {
+            for edit in edits[..index].iter().rev() {
+                edit.rollback().await?;
+            }
+
+            return Err(err);
+        }
+    }
+
+    Ok(())
+}
+
+async fn put<D, F>(
+    dir: &Directory<D, F>,
+    name: &Arc<OsStr>,
+    node: &Node<D, F>,
+) -> Result<(), UndoError>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    if !dir.link_vacant(name.clone(), node.clone()).await? {
+        return Err(UndoError::Diverged);
+    }
+
+    dir.journal_tree(name, node).await;
+    dir.hooks()
+        .notify(Event::Create, dir.path().map(|path| path.join(&**name)), node)
+        .await;
+
+    Ok(())
+}
+
+async fn take<D, F>(dir: &Directory<D, F>, name: &OsStr, node: &Node<D, F>) -> Result<(), UndoError>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    if dir
+        .unlink_if(name, |current| current.same_node(node))
+        .await?
+        .is_none()
+    {
+        return Err(UndoError::Diverged);
+    }
+
+    dir.hooks()
+        .journal(dir.path().map(|path| path.join(name)), |_, path| {
+            Record::Remove { path }
+        })
+        .await;
+    dir.hooks()
+        .notify(Event::Remove, dir.path().map(|path| path.join(name)), node)
+        .await;
+
+    Ok(())
+}
+
+async fn xattr<D, F>(
+    node: &Node<D, F>,
+    name: &str,
+    expected: Option<&[u8]>,
+    value: Option<&[u8]>,
+) -> Result<(), UndoError>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    if node.get_xattr(name).await.as_deref() != expected {
+        return Err(UndoError::Diverged);
+    }
+
+    node.swap_xattr(name, value.map(<[u8]>::to_vec), None)
+        .await?;
+
+    Ok(())
+}
+
+fn upgrade<D, F>(dir: &Reference<D, F>) -> Result<Directory<D, F>, UndoError>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    dir.upgrade().ok_or(UndoError::Diverged)
+}
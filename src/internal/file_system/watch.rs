@@ -0,0 +1,186 @@
+use std::{
+    future::{
+        self,
+        Future,
+    },
+    path::PathBuf,
+    task::Poll,
+    time::Duration,
+};
+
+use futures::{
+    channel::mpsc,
+    future::{
+        select,
+        BoxFuture,
+        Either,
+    },
+    stream::{
+        self,
+        BoxStream,
+    },
+    FutureExt,
+    Stream,
+    StreamExt,
+};
+use indexmap::IndexMap;
+
+use super::{
+    super::node::data::ValueType,
+    hooks::Event,
+    FileSystem,
+};
+
+// Change
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Change {
+    pub kind: Event,
+    pub path: PathBuf,
+}
+
+// Debounce
+
+pub trait Debounce: Stream<Item = Change> + Sized {
+    fn debounce<S, T>(self, window: Duration, sleep: S) -> BoxStream<'static, Change>
+    where
+        S: Fn(Duration) -> T + Send + 'static,
+        T: Future<Output = ()> + Send + 'static;
+}
+
+// Debounce - Blanket Implementation
+
+impl<W> Debounce for W
+where
+    W: Stream<Item = Change> + Send + 'static,
+{
+    fn debounce<S, T>(self, window: Duration, sleep: S) -> BoxStream<'static, Change>
+    where
+        S: Fn(Duration) -> T + Send + 'static,
+        T: Future<Output = ()> + Send + 'static,
+    {
+        let state = State {
+            changes: self.boxed().fuse(),
+            pending: IndexMap::new(),
+            ready: Vec::new(),
+        };
+
+        stream::unfold((state, sleep), move |(mut state, sleep)| async move {
+            loop {
+                if let Some(change) = state.ready.pop() {
+                    return Some((change, (state, sleep)));
+                }
+
+                if state.pending.is_empty() {
+                    let change = state.changes.next().await?;
+
+                    state.coalesce(change, sleep(window).boxed());
+                    continue;
+                }
+
+                let next = {
+                    let State { changes, pending, .. } = &mut state;
+
+                    match select(changes.next(), expired(pending)).await {
+                        Either::Left((change, _)) => Either::Left(change),
+                        Either::Right((paths, _)) => Either::Right(paths),
+                    }
+                };
+
+                match next {
+                    Either::Left(Some(change)) => state.coalesce(change, sleep(window).boxed()),
+                    Either::Left(None) => state.flush(),
+                    Either::Right(paths) => state.expire(&paths),
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+// State
+
+struct State {
+    changes: stream::Fuse<BoxStream<'static, Change>>,
+    pending: IndexMap<PathBuf, (Event, Timer)>,
+    ready: Vec<Change>,
+}
+
+type Timer = BoxFuture<'static, ()>;
+
+// State - Methods
+
+impl State {
+    fn coalesce(&mut self, change: Change, timer: Timer) {
+        match (self.pending.get_mut(&change.path), change.kind) {
+            (Some((Event::Create, _)), Event::Remove) => {
+                self.pending.shift_remove(&change.path);
+            }
+            (Some((Event::Create, pending)), _) => *pending = timer,
+            (Some(pending), kind) => *pending = (kind, timer),
+            (None, kind) => {
+                self.pending.insert(change.path, (kind, timer));
+            }
+        }
+    }
+
+    fn expire(&mut self, paths: &[PathBuf]) {
+        let expired = paths
+            .iter()
+            .filter_map(|path| self.pending.shift_remove_entry(path))
+            .rev()
+            .map(|(path, (kind, _))| Change { kind, path })
+            .collect::<Vec<_>>();
+
+        self.ready.extend(expired);
+    }
+
+    fn flush(&mut self) {
+        self.ready.extend(
+            self.pending
+                .drain(..)
+                .rev()
+                .map(|(path, (kind, _))| Change { kind, path }),
+        );
+    }
+}
+
+// FileSystem - Watch
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub fn watch(&self) -> BoxStream<'static, Change> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        if let Ok(mut watchers) = self.hooks().watchers.lock() {
+            watchers.push(sender);
+        }
+
+        receiver.boxed()
+    }
+}
+
+// Functions
+
+fn expired(
+    pending: &mut IndexMap<PathBuf, (Event, Timer)>,
+) -> impl Future<Output = Vec<PathBuf>> + '_ {
+    future::poll_fn(move |cx| {
+        let paths = pending
+            .iter_mut()
+            .filter_map(|(path, (_, timer))| {
+                timer.poll_unpin(cx).is_ready().then(|| path.clone())
+            })
+            .collect::<Vec<_>>();
+
+        if paths.is_empty() {
+            Poll::Pending
+        } else {
+            Poll::Ready(paths)
+        }
+    })
+}
@@ -0,0 +1,90 @@
+use std::{
+    collections::HashSet,
+    io::{
+        Read,
+        Seek,
+    },
+};
+
+use ::zip::ZipArchive;
+
+use super::{
+    super::{
+        directory::{
+            get_ext::{
+                file_or_create_with,
+                GetExt,
+            },
+            zip::ZipError,
+        },
+        node::data::ValueType,
+    },
+    options::Options,
+    FileSystem,
+};
+
+// FileSystem - Zip
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType + Default,
+    F: ValueType + From<Vec<u8>>,
+{
+    pub async fn from_zip<R>(reader: R) -> Result<Self, ZipError>
+    where
+        R: Read + Seek + Send,
+    {
+        Self::from_zip_with(reader, D::default).await
+    }
+}
+
+impl<D, F> FileSystem<D, F>
+where
+    D: ValueType,
+    F: ValueType + From<Vec<u8>>,
+{
+    pub async fn from_zip_with<R, C>(reader: R, dirs: C) -> Result<Self, ZipError>
+    where
+        R: Read + Seek + Send,
+        C: Fn() -> D + Send + Sync,
+    {
+        let mut archive = ZipArchive::new(reader)?;
+        let fs = Self::with_root(Options::default(), dirs());
+        let mut paths = HashSet::new();
+
+        for index in 0..archive.len() {
+            let (path, bytes) = {
+                let mut entry = archive.by_index(index)?;
+                let path = entry.enclosed_name().ok_or_else(|| {
+                    ZipError::UnsafePath(String::from_utf8_lossy(entry.name_raw()).into_owned())
+                })?;
+
+                if !paths.insert(path.clone()) {
+                    return Err(ZipError::Duplicate(
+                        String::from_utf8_lossy(entry.name_raw()).into_owned(),
+                    ));
+                }
+
+                if entry.is_dir() {
+                    (path, None)
+                } else {
+                    let mut bytes = Vec::new();
+
+                    entry.read_to_end(&mut bytes)?;
+                    (path, Some(bytes))
+                }
+            };
+
+            match bytes {
+                Some(bytes) => {
+                    file_or_create_with(&fs.0, path, &dirs, || F::from(bytes)).await?;
+                }
+                _ => {
+                    fs.get_dir_or_create_with(path, &dirs).await?;
+                }
+            }
+        }
+
+        Ok(fs)
+    }
+}
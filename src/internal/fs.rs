@@ -0,0 +1,243 @@
+use std::{
+    ffi::{
+        OsStr,
+        OsString,
+    },
+    io::{
+        Error,
+        ErrorKind,
+        Result,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use super::{
+    directory::{
+        get::{
+            Get,
+            GetError,
+            GetErrorKind,
+            GetType,
+        },
+        get_ext::{
+            GetDirectoryError,
+            GetExt,
+            GetFileError,
+        },
+        Directory,
+    },
+    node::{
+        data::ValueType,
+        data_ext::{
+            DataExt,
+            WriteError,
+        },
+        Node,
+    },
+};
+
+// Functions
+
+pub async fn create_dir_all<D, F, P>(dir: &Directory<D, F>, path: P) -> Result<()>
+where
+    D: ValueType + Default,
+    F: ValueType + Default,
+    P: AsRef<Path> + Send,
+{
+    match dir.get_dir_default(path).await {
+        Ok(_) => Ok(()),
+        Err(GetDirectoryError::UnexpectedFile) => Err(ErrorKind::AlreadyExists.into()),
+        Err(GetDirectoryError::Get(err)) => Err(from_get_error(err)),
+    }
+}
+
+pub async fn metadata<D, F, P>(dir: &Directory<D, F>, path: P) -> Result<Metadata>
+where
+    D: ValueType,
+    F: ValueType,
+    P: AsRef<Path> + Send,
+{
+    match dir.get(path, GetType::default()).await {
+        Ok(Some(node)) => Ok(Metadata::from(&node)),
+        Ok(None) => Err(ErrorKind::NotFound.into()),
+        Err(err) => Err(from_get_error(err)),
+    }
+}
+
+pub async fn read<D, F, P>(dir: &Directory<D, F>, path: P) -> Result<F>
+where
+    D: ValueType,
+    F: ValueType + Clone,
+    P: AsRef<Path> + Send,
+{
+    match dir.get_file(path).await {
+        Ok(Some(file)) => Ok(file.read(|value| value.clone()).await),
+        Ok(None) => Err(ErrorKind::NotFound.into()),
+        Err(err) => Err(from_get_file_error(err)),
+    }
+}
+
+pub async fn read_dir<D, F, P>(dir: &Directory<D, F>, path: P) -> Result<Vec<DirEntry<D, F>>>
+where
+    D: ValueType,
+    F: ValueType,
+    P: AsRef<Path> + Send,
+{
+    let path = path.as_ref().to_path_buf();
+
+    match dir.get_dir(&path).await {
+        Ok(Some(dir)) => Ok(dir
+            .entries()
+            .await?
+            .into_iter()
+            .map(|(name, node)| DirEntry {
+                path: path.join(&*name),
+                name: name.to_os_string(),
+                node,
+            })
+            .collect()),
+        Ok(None) => Err(ErrorKind::NotFound.into()),
+        Err(err) => Err(from_get_directory_error(err)),
+    }
+}
+
+pub async fn write<D, F, P>(dir: &Directory<D, F>, path: P, value: F) -> Result<()>
+where
+    D: ValueType + Default,
+    F: ValueType + Default,
+    P: AsRef<Path> + Send,
+{
+    let path = path.as_ref();
+    let name = path
+        .file_name()
+        .ok_or_else(|| Error::from(ErrorKind::InvalidInput))?;
+    let parent = match dir.get_dir(path.parent().unwrap_or(path)).await {
+        Ok(Some(parent)) => parent,
+        Ok(None) => return Err(ErrorKind::NotFound.into()),
+        Err(err) => return Err(from_get_directory_error(err)),
+    };
+
+    match parent.get_file_default(name).await {
+        Ok(file) => file
+            .write(|mut current| *current = value)
+            .await
+            .map_err(from_write_error),
+        Err(err) => Err(from_get_file_error(err)),
+    }
+}
+
+pub fn from_get_directory_error(err: GetDirectoryError) -> Error {
+    match err {
+        GetDirectoryError::UnexpectedFile => ErrorKind::NotADirectory.into(),
+        GetDirectoryError::Get(err) => from_get_error(err),
+    }
+}
+
+pub fn from_get_error(err: GetError) -> Error {
+    match err.kind() {
+        GetErrorKind::UnexpectedFile => Error::new(ErrorKind::NotADirectory, err),
+        GetErrorKind::AccessDenied | GetErrorKind::PermissionDenied => {
+            Error::new(ErrorKind::PermissionDenied, err)
+        }
+        GetErrorKind::QuotaExceeded => Error::new(ErrorKind::StorageFull, err),
+        _ => Error::new(ErrorKind::InvalidInput, err),
+    }
+}
+
+pub fn from_get_file_error(err: GetFileError) -> Error {
+    match err {
+        GetFileError::UnexpectedDirectory => ErrorKind::IsADirectory.into(),
+        GetFileError::Get(err) => from_get_error(err),
+    }
+}
+
+pub fn from_write_error(err: WriteError) -> Error {
+    match err {
+        WriteError::AccessDenied | WriteError::PermissionDenied => {
+            Error::new(ErrorKind::PermissionDenied, err)
+        }
+        WriteError::Borrowed | WriteError::Conflict => Error::new(ErrorKind::ResourceBusy, err),
+        WriteError::InvalidName => Error::new(ErrorKind::InvalidInput, err),
+        WriteError::LoadFailed => Error::other(err),
+        WriteError::QuotaExceeded => Error::new(ErrorKind::StorageFull, err),
+    }
+}
+
+// Metadata
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Metadata {
+    is_dir: bool,
+}
+
+// Metadata - Standard Traits
+
+impl<D, F> From<&Node<D, F>> for Metadata
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn from(node: &Node<D, F>) -> Self {
+        Self {
+            is_dir: matches!(node, Node::Directory(_)),
+        }
+    }
+}
+
+// Metadata - Methods
+
+impl Metadata {
+    #[must_use]
+    pub const fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+
+    #[must_use]
+    pub const fn is_file(&self) -> bool {
+        !self.is_dir
+    }
+}
+
+// DirEntry
+
+#[derive(Debug)]
+pub struct DirEntry<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    name: OsString,
+    node: Node<D, F>,
+    path: PathBuf,
+}
+
+// DirEntry - Methods
+
+impl<D, F> DirEntry<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub fn file_name(&self) -> &OsStr {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn metadata(&self) -> Metadata {
+        Metadata::from(&self.node)
+    }
+
+    #[must_use]
+    pub const fn node(&self) -> &Node<D, F> {
+        &self.node
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
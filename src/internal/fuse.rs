@@ -0,0 +1,580 @@
+#![allow(unsafe_code)]
+
+use std::{
+    ffi::{
+        CString,
+        OsStr,
+    },
+    fmt,
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io::{
+        self,
+        ErrorKind,
+        Read,
+        Write,
+    },
+    os::unix::{
+        ffi::OsStrExt,
+        io::AsRawFd,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    thread::{
+        self,
+        JoinHandle,
+    },
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+use futures::executor::block_on;
+
+use super::{
+    file_system::FileSystem,
+    inodes::{
+        Attr,
+        Inodes,
+    },
+    node::{
+        data::ValueType,
+        NodeKind,
+    },
+};
+
+// Protocol
+
+// The subset of the kernel FUSE protocol (fuse.h, version 7) served by a
+// mount. Requests for any other opcode are answered with ENOSYS, which the
+// kernel treats as "not supported" and does not send again.
+
+const BIG_WRITES: u32 = 1 << 5;
+const BUFFER: usize = MAX_WRITE as usize + 4096;
+const MAJOR: u32 = 7;
+const MAX_WRITE: u32 = 128 * 1024;
+const MINOR: u32 = 31;
+const ROOT: u64 = 1;
+const SET_SIZE: u32 = 1 << 3;
+const TTL: u64 = 1;
+
+const LOOKUP: u32 = 1;
+const FORGET: u32 = 2;
+const GETATTR: u32 = 3;
+const SETATTR: u32 = 4;
+const UNLINK: u32 = 10;
+const OPEN: u32 = 14;
+const READ: u32 = 15;
+const WRITE: u32 = 16;
+const STATFS: u32 = 17;
+const RELEASE: u32 = 18;
+const FSYNC: u32 = 20;
+const FLUSH: u32 = 25;
+const INIT: u32 = 26;
+const OPENDIR: u32 = 27;
+const READDIR: u32 = 28;
+const RELEASEDIR: u32 = 29;
+const FSYNCDIR: u32 = 30;
+const CREATE: u32 = 35;
+const INTERRUPT: u32 = 36;
+const DESTROY: u32 = 38;
+const BATCH_FORGET: u32 = 42;
+
+// Mount
+
+pub struct Mount {
+    path: PathBuf,
+    session: Option<JoinHandle<()>>,
+}
+
+// Mount - Standard Traits
+
+impl fmt::Debug for Mount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mount")
+            .field("path", &self.path)
+            .field("mounted", &self.session.is_some())
+            .finish()
+    }
+}
+
+impl Drop for Mount {
+    fn drop(&mut self) {
+        if self.release().is_err() {
+            unmount(&self.path, libc::MNT_DETACH).ok();
+        }
+    }
+}
+
+// Mount - Methods
+
+impl Mount {
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn unmount(mut self) -> io::Result<()> {
+        self.release()
+    }
+
+    fn release(&mut self) -> io::Result<()> {
+        match self.session.take() {
+            Some(session) => match unmount(&self.path, 0) {
+                Ok(()) => session
+                    .join()
+                    .map_err(|_| io::Error::other("fuse session panicked")),
+                Err(err) => {
+                    self.session = Some(session);
+                    Err(err)
+                }
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+// Session
+
+struct Session<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    device: File,
+    gid: u32,
+    inodes: Inodes<D, F>,
+    minor: u32,
+    uid: u32,
+}
+
+// Session - Methods
+
+impl<D, F> Session<D, F>
+where
+    D: ValueType,
+    F: ValueType + AsRef<[u8]> + AsMut<Vec<u8>> + From<Vec<u8>>,
+{
+    fn run(mut self) {
+        let mut buffer = vec![0; BUFFER];
+
+        loop {
+            let len = match (&self.device).read(&mut buffer) {
+                Ok(len) => len,
+                Err(err) if retry(&err) => continue,
+                Err(_) => break,
+            };
+
+            let Some((opcode, unique, node, body)) = header(&buffer[..len]) else {
+                continue;
+            };
+
+            let reply = block_on(self.handle(opcode, node, body));
+
+            if let Some(reply) = reply {
+                self.reply(unique, reply);
+            }
+
+            if opcode == DESTROY {
+                break;
+            }
+        }
+    }
+
+    async fn handle(&mut self, opcode: u32, node: u64, body: &[u8]) -> Option<io::Result<Vec<u8>>> {
+        let ino = self.ino(node);
+
+        Some(match opcode {
+            INIT => self.init(body),
+            LOOKUP => match name(body, 0) {
+                Ok(name) => self
+                    .inodes
+                    .lookup(ino, name)
+                    .await
+                    .map(|attr| self.entry(&attr)),
+                Err(err) => Err(err),
+            },
+            FORGET => {
+                self.forget(node).await;
+                return None;
+            }
+            BATCH_FORGET => {
+                let count = u32_at(body, 0).unwrap_or(0);
+
+                for index in 0..count as usize {
+                    if let Ok(node) = u64_at(body, 8 + index * 16) {
+                        self.forget(node).await;
+                    }
+                }
+
+                return None;
+            }
+            INTERRUPT => return None,
+            GETATTR => self
+                .inodes
+                .getattr(ino)
+                .await
+                .map(|attr| self.attr_out(&attr)),
+            SETATTR => self.setattr(ino, body).await,
+            OPEN | OPENDIR => Ok(open_out()),
+            READ => match (u64_at(body, 8), u32_at(body, 16)) {
+                (Ok(offset), Ok(size)) => self.inodes.read(ino, offset, size).await,
+                (Err(err), _) | (_, Err(err)) => Err(err),
+            },
+            WRITE => self.write(ino, body).await,
+            READDIR => self.readdir(ino, body).await,
+            CREATE => self.create(ino, body).await,
+            UNLINK => match name(body, 0) {
+                Ok(name) => self.inodes.unlink(ino, name).await.map(|()| Vec::new()),
+                Err(err) => Err(err),
+            },
+            STATFS => Ok(statfs_out()),
+            RELEASE | RELEASEDIR | FLUSH | FSYNC | FSYNCDIR | DESTROY => Ok(Vec::new()),
+            _ => Err(io::Error::from_raw_os_error(libc::ENOSYS)),
+        })
+    }
+
+    fn reply(&self, unique: u64, reply: io::Result<Vec<u8>>) {
+        let (error, body) = match reply {
+            Ok(body) => (0, body),
+            Err(err) => (-errno(&err), Vec::new()),
+        };
+        let len = u32::try_from(16 + body.len()).unwrap_or(u32::MAX);
+        let mut out = Vec::with_capacity(16 + body.len());
+
+        out.extend_from_slice(&len.to_ne_bytes());
+        out.extend_from_slice(&error.to_ne_bytes());
+        out.extend_from_slice(&unique.to_ne_bytes());
+        out.extend_from_slice(&body);
+
+        (&self.device).write_all(&out).ok();
+    }
+
+    async fn create(&self, parent: u64, body: &[u8]) -> io::Result<Vec<u8>> {
+        let offset = if self.minor < 12 { 8 } else { 16 };
+        let attr = self.inodes.create(parent, name(body, offset)?).await?;
+        let mut out = self.entry(&attr);
+
+        out.extend_from_slice(&open_out());
+
+        Ok(out)
+    }
+
+    async fn forget(&self, node: u64) {
+        if node != ROOT {
+            self.inodes.forget(node).await;
+        }
+    }
+
+    fn init(&mut self, body: &[u8]) -> io::Result<Vec<u8>> {
+        let (major, minor) = (u32_at(body, 0)?, u32_at(body, 4)?);
+        let readahead = u32_at(body, 8)?;
+        let flags = u32_at(body, 12)? & BIG_WRITES;
+
+        if major < MAJOR {
+            return Err(io::Error::from_raw_os_error(libc::EPROTO));
+        }
+
+        self.minor = if major > MAJOR {
+            MINOR
+        } else {
+            minor.min(MINOR)
+        };
+
+        let mut out = Vec::with_capacity(64);
+
+        for value in [MAJOR, self.minor, readahead, flags] {
+            out.extend_from_slice(&value.to_ne_bytes());
+        }
+
+        out.extend_from_slice(&16_u16.to_ne_bytes());
+        out.extend_from_slice(&12_u16.to_ne_bytes());
+        out.extend_from_slice(&MAX_WRITE.to_ne_bytes());
+        out.extend_from_slice(&1_u32.to_ne_bytes());
+        out.resize(if self.minor < 23 { 24 } else { 64 }, 0);
+
+        Ok(out)
+    }
+
+    async fn readdir(&self, ino: u64, body: &[u8]) -> io::Result<Vec<u8>> {
+        let offset = usize::try_from(u64_at(body, 8)?).unwrap_or(usize::MAX);
+        let size = u32_at(body, 16)? as usize;
+        let mut out = Vec::new();
+
+        for (index, entry) in self
+            .inodes
+            .readdir(ino)
+            .await?
+            .iter()
+            .enumerate()
+            .skip(offset)
+        {
+            let name = entry.name.as_bytes();
+            let len = (24 + name.len() + 7) & !7;
+
+            if out.len() + len > size {
+                break;
+            }
+
+            out.extend_from_slice(&self.node(entry.ino).to_ne_bytes());
+            out.extend_from_slice(&(index as u64 + 1).to_ne_bytes());
+            out.extend_from_slice(&u32::try_from(name.len()).unwrap_or(0).to_ne_bytes());
+            out.extend_from_slice(&(mode(entry.kind, false) >> 12).to_ne_bytes());
+            out.extend_from_slice(name);
+            out.resize(out.len() + len - 24 - name.len(), 0);
+        }
+
+        Ok(out)
+    }
+
+    async fn setattr(&self, ino: u64, body: &[u8]) -> io::Result<Vec<u8>> {
+        let attr = match u32_at(body, 0)? & SET_SIZE {
+            0 => self.inodes.getattr(ino).await?,
+            _ => self.inodes.truncate(ino, u64_at(body, 16)?).await?,
+        };
+
+        Ok(self.attr_out(&attr))
+    }
+
+    async fn write(&self, ino: u64, body: &[u8]) -> io::Result<Vec<u8>> {
+        let offset = u64_at(body, 8)?;
+        let size = u32_at(body, 16)? as usize;
+        let start = if self.minor < 9 { 24 } else { 40 };
+        let data = body
+            .get(start..start + size)
+            .ok_or_else(|| io::Error::from(ErrorKind::InvalidInput))?;
+        let written = self.inodes.write(ino, offset, data).await?;
+        let mut out = written.to_ne_bytes().to_vec();
+
+        out.resize(8, 0);
+
+        Ok(out)
+    }
+
+    fn attr(&self, attr: &Attr) -> Vec<u8> {
+        let (atime, atime_nsec) = timestamp(attr.accessed);
+        let (mtime, mtime_nsec) = timestamp(attr.modified);
+        let mut out = Vec::with_capacity(88);
+
+        for value in [
+            self.node(attr.ino),
+            attr.size,
+            attr.size.div_ceil(512),
+            atime,
+            mtime,
+            mtime,
+        ] {
+            out.extend_from_slice(&value.to_ne_bytes());
+        }
+
+        for value in [
+            atime_nsec,
+            mtime_nsec,
+            mtime_nsec,
+            mode(attr.kind, attr.readonly),
+            match attr.kind {
+                NodeKind::Directory => 2,
+                NodeKind::File => 1,
+            },
+            self.uid,
+            self.gid,
+            0,
+            4096,
+            0,
+        ] {
+            out.extend_from_slice(&value.to_ne_bytes());
+        }
+
+        out
+    }
+
+    fn attr_out(&self, attr: &Attr) -> Vec<u8> {
+        let mut out = Vec::with_capacity(104);
+
+        out.extend_from_slice(&TTL.to_ne_bytes());
+        out.resize(16, 0);
+        out.extend_from_slice(&self.attr(attr));
+        out
+    }
+
+    fn entry(&self, attr: &Attr) -> Vec<u8> {
+        let mut out = Vec::with_capacity(128);
+
+        for value in [self.node(attr.ino), 0, TTL, TTL] {
+            out.extend_from_slice(&value.to_ne_bytes());
+        }
+
+        out.resize(40, 0);
+        out.extend_from_slice(&self.attr(attr));
+        out
+    }
+
+    const fn ino(&self, node: u64) -> u64 {
+        match node {
+            ROOT => self.inodes.root(),
+            _ => node,
+        }
+    }
+
+    const fn node(&self, ino: u64) -> u64 {
+        if ino == self.inodes.root() { ROOT } else { ino }
+    }
+}
+
+// Functions
+
+pub async fn mount<D, F, P>(fs: &FileSystem<D, F>, path: P) -> io::Result<Mount>
+where
+    D: ValueType + 'static,
+    F: ValueType + AsRef<[u8]> + AsMut<Vec<u8>> + From<Vec<u8>> + 'static,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref().to_path_buf();
+    let device = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/fuse")?;
+    let options = format!(
+        "fd={},rootmode=40000,user_id={},group_id={}",
+        device.as_raw_fd(),
+        // SAFETY: getuid and getgid cannot fail and have no preconditions.
+        unsafe { libc::getuid() },
+        unsafe { libc::getgid() },
+    );
+    let target = CString::new(path.as_os_str().as_bytes())?;
+    let options = CString::new(options)?;
+
+    // SAFETY: every pointer is to a NUL-terminated string that outlives the call.
+    let mounted = unsafe {
+        libc::mount(
+            c"memfs".as_ptr(),
+            target.as_ptr(),
+            c"fuse".as_ptr(),
+            libc::MS_NOSUID | libc::MS_NODEV,
+            options.as_ptr().cast(),
+        )
+    };
+
+    if mounted != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let session = Session {
+        device,
+        // SAFETY: as above.
+        gid: unsafe { libc::getgid() },
+        inodes: Inodes::new(fs).await,
+        minor: MINOR,
+        // SAFETY: as above.
+        uid: unsafe { libc::getuid() },
+    };
+
+    match thread::Builder::new()
+        .name(String::from("memfs-fuse"))
+        .spawn(move || session.run())
+    {
+        Ok(session) => Ok(Mount {
+            path,
+            session: Some(session),
+        }),
+        Err(err) => {
+            unmount(&path, libc::MNT_DETACH).ok();
+            Err(err)
+        }
+    }
+}
+
+fn unmount(path: &Path, flags: i32) -> io::Result<()> {
+    let target = CString::new(path.as_os_str().as_bytes())?;
+
+    // SAFETY: target is a NUL-terminated string that outlives the call.
+    match unsafe { libc::umount2(target.as_ptr(), flags) } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
+    }
+}
+
+fn errno(err: &io::Error) -> i32 {
+    err.raw_os_error().unwrap_or_else(|| match err.kind() {
+        ErrorKind::AlreadyExists => libc::EEXIST,
+        ErrorKind::DirectoryNotEmpty => libc::ENOTEMPTY,
+        ErrorKind::FileTooLarge => libc::EFBIG,
+        ErrorKind::InvalidInput => libc::EINVAL,
+        ErrorKind::IsADirectory => libc::EISDIR,
+        ErrorKind::NotADirectory => libc::ENOTDIR,
+        ErrorKind::NotFound => libc::ENOENT,
+        ErrorKind::PermissionDenied => libc::EACCES,
+        ErrorKind::ResourceBusy => libc::EBUSY,
+        ErrorKind::StorageFull => libc::ENOSPC,
+        _ => libc::EIO,
+    })
+}
+
+fn header(request: &[u8]) -> Option<(u32, u64, u64, &[u8])> {
+    let opcode = u32_at(request, 4).ok()?;
+    let unique = u64_at(request, 8).ok()?;
+    let node = u64_at(request, 16).ok()?;
+
+    request.get(40..).map(|body| (opcode, unique, node, body))
+}
+
+const fn mode(kind: NodeKind, readonly: bool) -> u32 {
+    match (kind, readonly) {
+        (NodeKind::Directory, false) => libc::S_IFDIR | 0o755,
+        (NodeKind::Directory, true) => libc::S_IFDIR | 0o555,
+        (NodeKind::File, false) => libc::S_IFREG | 0o644,
+        (NodeKind::File, true) => libc::S_IFREG | 0o444,
+    }
+}
+
+fn name(body: &[u8], offset: usize) -> io::Result<&OsStr> {
+    body.get(offset..)
+        .and_then(|name| name.split(|byte| *byte == 0).next())
+        .map(OsStr::from_bytes)
+        .ok_or_else(|| ErrorKind::InvalidInput.into())
+}
+
+fn open_out() -> Vec<u8> {
+    vec![0; 16]
+}
+
+fn retry(err: &io::Error) -> bool {
+    matches!(
+        err.raw_os_error(),
+        Some(libc::ENOENT | libc::EINTR | libc::EAGAIN)
+    )
+}
+
+fn statfs_out() -> Vec<u8> {
+    let mut out = vec![0; 80];
+
+    out[40..44].copy_from_slice(&4096_u32.to_ne_bytes());
+    out[44..48].copy_from_slice(&255_u32.to_ne_bytes());
+    out[48..52].copy_from_slice(&4096_u32.to_ne_bytes());
+    out
+}
+
+fn timestamp(time: SystemTime) -> (u64, u32) {
+    time.duration_since(UNIX_EPOCH)
+        .map(|time| (time.as_secs(), time.subsec_nanos()))
+        .unwrap_or_default()
+}
+
+fn u32_at(body: &[u8], offset: usize) -> io::Result<u32> {
+    body.get(offset..offset + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_ne_bytes)
+        .ok_or_else(|| ErrorKind::InvalidInput.into())
+}
+
+fn u64_at(body: &[u8], offset: usize) -> io::Result<u64> {
+    body.get(offset..offset + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_ne_bytes)
+        .ok_or_else(|| ErrorKind::InvalidInput.into())
+}
@@ -0,0 +1,312 @@
+use std::{
+    collections::HashMap,
+    ffi::{
+        OsStr,
+        OsString,
+    },
+    io::{
+        Error,
+        ErrorKind,
+        Result,
+    },
+    time::SystemTime,
+};
+
+use async_lock::Mutex;
+use futures::FutureExt;
+
+use super::{
+    directory::{
+        entry::Entry,
+        Directory,
+    },
+    file::File,
+    file_system::FileSystem,
+    fs::from_write_error,
+    node::{
+        data::{
+            Data,
+            ValueType,
+        },
+        data_ext::DataExt,
+        identified::Identified,
+        permissions::Permissions,
+        timestamps::Timestamps,
+        Node,
+        NodeKind,
+    },
+};
+
+// Inodes
+
+// The largest file a write may produce when the file system has no `max_size`
+// quota, so that a write at a large offset cannot allocate without bound.
+const DEFAULT_LIMIT: usize = 64 * 1024 * 1024;
+
+#[derive(Debug)]
+pub struct Inodes<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    limit: usize,
+    nodes: Mutex<HashMap<u64, Node<D, F>>>,
+    root: u64,
+}
+
+// Inodes - Methods
+
+impl<D, F> Inodes<D, F>
+where
+    D: ValueType,
+    F: ValueType + AsRef<[u8]>,
+{
+    pub async fn new(fs: &FileSystem<D, F>) -> Self {
+        let root = Node::Directory(fs.0.clone());
+        let ino = u64::from(root.id().await);
+        let limit = fs.0.context().quota.max_size.unwrap_or(DEFAULT_LIMIT);
+
+        Self {
+            limit,
+            nodes: Mutex::new(HashMap::from([(ino, root)])),
+            root: ino,
+        }
+    }
+
+    pub async fn create(&self, parent: u64, name: &OsStr) -> Result<Attr>
+    where
+        F: From<Vec<u8>>,
+    {
+        let dir = self.dir(parent).await?;
+
+        dir.populate().await?;
+
+        let entry = dir.entry(name).await;
+
+        match entry {
+            Entry::Vacant(entry) => {
+                let file = entry
+                    .insert_file(F::from(Vec::new()))
+                    .await
+                    .map_err(from_write_error)?;
+
+                Ok(self.track(Node::File(file)).await)
+            }
+            Entry::Occupied(_) => Err(ErrorKind::AlreadyExists.into()),
+        }
+    }
+
+    pub async fn forget(&self, ino: u64) {
+        self.nodes.lock().map(|mut nodes| nodes.remove(&ino)).await;
+    }
+
+    pub async fn getattr(&self, ino: u64) -> Result<Attr> {
+        match self.node(ino).await {
+            Some(node) => Ok(Attr::from_node(ino, &node).await),
+            _ => Err(ErrorKind::NotFound.into()),
+        }
+    }
+
+    pub async fn lookup(&self, parent: u64, name: &OsStr) -> Result<Attr> {
+        let dir = self.dir(parent).await?;
+
+        dir.populate().await?;
+
+        match dir.get_child(name).await.map_err(from_write_error)? {
+            Some(node) => Ok(self.track(node).await),
+            _ => Err(ErrorKind::NotFound.into()),
+        }
+    }
+
+    pub async fn read(&self, ino: u64, offset: u64, size: u32) -> Result<Vec<u8>> {
+        let offset = usize::try_from(offset).unwrap_or(usize::MAX);
+        let size = usize::try_from(size).unwrap_or(usize::MAX);
+
+        Ok(self
+            .file(ino)
+            .await?
+            .read(|value| {
+                let bytes = (*value).as_ref();
+                let start = offset.min(bytes.len());
+                let end = start.saturating_add(size).min(bytes.len());
+
+                bytes[start..end].to_vec()
+            })
+            .await)
+    }
+
+    pub async fn readdir(&self, ino: u64) -> Result<Vec<DirEntry>> {
+        let dir = self.dir(ino).await?;
+        let mut entries = Vec::new();
+
+        for (name, node) in dir.entries().await? {
+            entries.push(DirEntry {
+                ino: self.track(node.clone()).await.ino,
+                kind: node.kind(),
+                name: name.to_os_string(),
+            });
+        }
+
+        Ok(entries)
+    }
+
+    #[must_use]
+    pub const fn root(&self) -> u64 {
+        self.root
+    }
+
+    pub async fn truncate(&self, ino: u64, size: u64) -> Result<Attr>
+    where
+        F: AsMut<Vec<u8>>,
+    {
+        let size = usize::try_from(size).map_err(|_| Error::from(ErrorKind::InvalidInput))?;
+
+        if size > self.limit {
+            return Err(ErrorKind::FileTooLarge.into());
+        }
+
+        self.file(ino)
+            .await?
+            .write(|mut value| (*value).as_mut().resize(size, 0))
+            .await
+            .map_err(from_write_error)?;
+
+        self.getattr(ino).await
+    }
+
+    pub async fn unlink(&self, parent: u64, name: &OsStr) -> Result<()> {
+        let dir = self.dir(parent).await?;
+
+        dir.populate().await?;
+
+        let entry = dir.entry(name).await;
+
+        match entry {
+            Entry::Occupied(entry) => match entry.get() {
+                Node::File(_) => entry.remove().await.map(drop).map_err(from_write_error),
+                Node::Directory(_) => Err(ErrorKind::IsADirectory.into()),
+            },
+            Entry::Vacant(_) => Err(ErrorKind::NotFound.into()),
+        }
+    }
+
+    pub async fn write(&self, ino: u64, offset: u64, data: &[u8]) -> Result<u32>
+    where
+        F: AsMut<Vec<u8>>,
+    {
+        let offset = usize::try_from(offset).map_err(|_| Error::from(ErrorKind::InvalidInput))?;
+        let written =
+            u32::try_from(data.len()).map_err(|_| Error::from(ErrorKind::InvalidInput))?;
+        let end = offset
+            .checked_add(data.len())
+            .ok_or_else(|| Error::from(ErrorKind::InvalidInput))?;
+
+        if end > self.limit {
+            return Err(ErrorKind::FileTooLarge.into());
+        }
+
+        self.file(ino)
+            .await?
+            .write(|mut value| {
+                let bytes = (*value).as_mut();
+
+                if bytes.len() < end {
+                    bytes.resize(end, 0);
+                }
+
+                bytes[offset..end].copy_from_slice(data);
+            })
+            .await
+            .map(|()| written)
+            .map_err(from_write_error)
+    }
+
+    async fn dir(&self, ino: u64) -> Result<Directory<D, F>> {
+        match self.node(ino).await {
+            Some(Node::Directory(dir)) => Ok(dir),
+            Some(Node::File(_)) => Err(ErrorKind::NotADirectory.into()),
+            _ => Err(ErrorKind::NotFound.into()),
+        }
+    }
+
+    async fn file(&self, ino: u64) -> Result<File<D, F>> {
+        match self.node(ino).await {
+            Some(Node::File(file)) => Ok(file),
+            Some(Node::Directory(_)) => Err(ErrorKind::IsADirectory.into()),
+            _ => Err(ErrorKind::NotFound.into()),
+        }
+    }
+
+    async fn node(&self, ino: u64) -> Option<Node<D, F>> {
+        self.nodes
+            .lock()
+            .map(|nodes| nodes.get(&ino).cloned())
+            .await
+    }
+
+    async fn track(&self, node: Node<D, F>) -> Attr {
+        let ino = u64::from(node.id().await);
+        let attr = Attr::from_node(ino, &node).await;
+
+        self.nodes
+            .lock()
+            .map(|mut nodes| {
+                nodes.entry(ino).or_insert(node);
+            })
+            .await;
+
+        attr
+    }
+}
+
+// Attr
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Attr {
+    pub accessed: SystemTime,
+    pub created: SystemTime,
+    pub ino: u64,
+    pub kind: NodeKind,
+    pub modified: SystemTime,
+    pub readonly: bool,
+    pub size: u64,
+}
+
+// Attr - Methods
+
+impl Attr {
+    async fn from_node<D, F>(ino: u64, node: &Node<D, F>) -> Self
+    where
+        D: ValueType,
+        F: ValueType + AsRef<[u8]>,
+    {
+        let size = match node {
+            Node::Directory(_) => 0,
+            Node::File(file) => file
+                .data()
+                .then(
+                    |value| async move { value.read().map(|value| (*value).as_ref().len()).await },
+                )
+                .await,
+        };
+
+        Self {
+            accessed: node.accessed().await,
+            created: node.created().await,
+            ino,
+            kind: node.kind(),
+            modified: node.modified().await,
+            readonly: node.readonly().await,
+            size: u64::try_from(size).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+// DirEntry
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DirEntry {
+    pub ino: u64,
+    pub kind: NodeKind,
+    pub name: OsString,
+}
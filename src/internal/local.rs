@@ -0,0 +1,493 @@
+use std::{
+    cell::{
+        BorrowError,
+        Cell,
+        Ref,
+        RefCell,
+        RefMut,
+    },
+    collections::BTreeMap,
+    ffi::{
+        OsStr,
+        OsString,
+    },
+    ops::Deref,
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+    rc::{
+        Rc,
+        Weak,
+    },
+};
+
+use super::{
+    directory::{
+        get::{
+            GetError,
+            GetErrorKind,
+        },
+        get_ext::{
+            GetDirectoryError,
+            GetFileError,
+        },
+    },
+    node::data_ext::WriteError,
+};
+
+// FileSystem
+
+#[derive(Debug)]
+pub struct FileSystem<D, F>(Directory<D, F>);
+
+// FileSystem - Standard Traits
+
+impl<D, F> Default for FileSystem<D, F>
+where
+    D: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D, F> Deref for FileSystem<D, F> {
+    type Target = Directory<D, F>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// FileSystem - Methods
+
+impl<D, F> FileSystem<D, F>
+where
+    D: Default,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_root(D::default())
+    }
+}
+
+impl<D, F> FileSystem<D, F> {
+    #[must_use]
+    pub fn with_root(root: D) -> Self {
+        Self(Directory::create(root, None))
+    }
+}
+
+// Directory
+
+#[derive(Debug)]
+pub struct Directory<D, F>(Rc<DirectoryInternal<D, F>>);
+
+// Directory - Standard Traits
+
+impl<D, F> Clone for Directory<D, F> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<D, F> Eq for Directory<D, F> {}
+
+impl<D, F> PartialEq for Directory<D, F> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+// Directory - Methods
+
+impl<D, F> Directory<D, F> {
+    fn create(value: D, parent: Option<(OsString, Parent<D, F>)>) -> Self {
+        Self(Rc::new(DirectoryInternal {
+            children: RefCell::new(BTreeMap::new()),
+            parent,
+            readonly: Cell::new(false),
+            value: RefCell::new(value),
+        }))
+    }
+
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.0.children.borrow().len()
+    }
+
+    #[must_use]
+    pub fn count_dir(&self) -> usize {
+        self.count_predicate(|node| matches!(node, Node::Directory(_)))
+    }
+
+    #[must_use]
+    pub fn count_file(&self) -> usize {
+        self.count_predicate(|node| matches!(node, Node::File(_)))
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> Vec<(OsString, Node<D, F>)> {
+        self.0
+            .children
+            .borrow()
+            .iter()
+            .map(|(name, node)| (name.clone(), node.clone()))
+            .collect()
+    }
+
+    pub fn get<P>(&self, path: P) -> Result<Option<Node<D, F>>, GetError>
+    where
+        P: AsRef<Path>,
+    {
+        self.resolve(path.as_ref(), None::<(fn() -> D, Init<D, F>)>)
+    }
+
+    pub fn get_dir<P>(&self, path: P) -> Result<Option<Self>, GetDirectoryError>
+    where
+        P: AsRef<Path>,
+    {
+        match self.get(path)? {
+            Some(Node::Directory(dir)) => Ok(Some(dir)),
+            Some(Node::File(_)) => Err(GetDirectoryError::UnexpectedFile),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn get_dir_default<P>(&self, path: P) -> Result<Self, GetDirectoryError>
+    where
+        P: AsRef<Path>,
+        D: Default,
+    {
+        match self.get_default(path.as_ref(), |parent: Parent<D, F>, name| {
+            Node::Directory(Self::create(D::default(), Some((name, parent))))
+        })? {
+            Node::Directory(dir) => Ok(dir),
+            Node::File(_) => Err(GetDirectoryError::UnexpectedFile),
+        }
+    }
+
+    pub fn get_file<P>(&self, path: P) -> Result<Option<File<D, F>>, GetFileError>
+    where
+        P: AsRef<Path>,
+    {
+        match self.get(path)? {
+            Some(Node::Directory(_)) => Err(GetFileError::UnexpectedDirectory),
+            Some(Node::File(file)) => Ok(Some(file)),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn get_file_default<P>(&self, path: P) -> Result<File<D, F>, GetFileError>
+    where
+        P: AsRef<Path>,
+        D: Default,
+        F: Default,
+    {
+        match self.get_default(path.as_ref(), |parent: Parent<D, F>, name| {
+            Node::File(File::create(F::default(), (name, parent)))
+        })? {
+            Node::Directory(_) => Err(GetFileError::UnexpectedDirectory),
+            Node::File(file) => Ok(file),
+        }
+    }
+
+    #[must_use]
+    pub fn is_root(&self) -> bool {
+        self.0.parent.is_none()
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Option<OsString> {
+        self.0.parent.as_ref().map(|(name, _)| name.clone())
+    }
+
+    #[must_use]
+    pub fn parent(&self) -> Option<Self> {
+        self.0
+            .parent
+            .as_ref()
+            .and_then(|(_, parent)| parent.upgrade())
+            .map(Self)
+    }
+
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        match (self.name(), self.parent()) {
+            (Some(name), Some(parent)) => parent.path().join(name),
+            _ => PathBuf::from("/"),
+        }
+    }
+
+    #[must_use]
+    pub fn readonly(&self) -> bool {
+        self.0.readonly.get()
+    }
+
+    pub fn read<T, R>(&self, f: R) -> Result<T, BorrowError>
+    where
+        R: FnOnce(Ref<'_, D>) -> T,
+    {
+        self.0.value.try_borrow().map(f)
+    }
+
+    pub fn remove<N>(&self, name: N) -> Result<Option<Node<D, F>>, WriteError>
+    where
+        N: AsRef<OsStr>,
+    {
+        if self.readonly() {
+            return Err(WriteError::PermissionDenied);
+        }
+
+        Ok(self.0.children.borrow_mut().remove(name.as_ref()))
+    }
+
+    pub fn set_readonly(&self, readonly: bool) {
+        self.0.readonly.set(readonly);
+    }
+
+    pub fn write<T, W>(&self, f: W) -> Result<T, WriteError>
+    where
+        W: FnOnce(RefMut<'_, D>) -> T,
+    {
+        if self.readonly() {
+            return Err(WriteError::PermissionDenied);
+        }
+
+        self.0
+            .value
+            .try_borrow_mut()
+            .map(f)
+            .map_err(|_| WriteError::Borrowed)
+    }
+
+    fn child(&self, name: &OsStr) -> Option<Node<D, F>> {
+        self.0.children.borrow().get(name).cloned()
+    }
+
+    fn count_predicate<P>(&self, predicate: P) -> usize
+    where
+        P: Fn(&Node<D, F>) -> bool,
+    {
+        self.0
+            .children
+            .borrow()
+            .values()
+            .filter(|node| predicate(node))
+            .count()
+    }
+
+    fn get_default<C>(&self, path: &Path, create: C) -> Result<Node<D, F>, GetError>
+    where
+        C: FnOnce(Parent<D, F>, OsString) -> Node<D, F>,
+        D: Default,
+    {
+        let index = path.components().count().saturating_sub(1);
+
+        self.resolve(path, Some((D::default as fn() -> D, create)))?
+            .ok_or_else(|| GetError::new(GetErrorKind::Other, path, index))
+    }
+
+    #[allow(clippy::match_bool)]
+    fn get_root(&self) -> Result<Option<Node<D, F>>, GetErrorKind> {
+        match self.is_root() {
+            true => Ok(Some(Node::Directory(self.clone()))),
+            _ => Err(GetErrorKind::UnexpectedRoot),
+        }
+    }
+
+    fn get_or_create<C>(&self, name: &OsStr, create: C) -> Result<Node<D, F>, GetErrorKind>
+    where
+        C: FnOnce(Parent<D, F>, OsString) -> Node<D, F>,
+    {
+        if let Some(node) = self.child(name) {
+            return Ok(node);
+        }
+
+        if self.readonly() {
+            return Err(GetErrorKind::PermissionDenied);
+        }
+
+        let node = create(Rc::downgrade(&self.0), name.to_os_string());
+
+        self.0
+            .children
+            .borrow_mut()
+            .insert(name.to_os_string(), node.clone());
+
+        Ok(node)
+    }
+
+    fn resolve<C>(
+        &self,
+        path: &Path,
+        create: Option<(fn() -> D, C)>,
+    ) -> Result<Option<Node<D, F>>, GetError>
+    where
+        C: FnOnce(Parent<D, F>, OsString) -> Node<D, F>,
+    {
+        let (parent, mut init) = create.unzip();
+        let mut current = Some(Node::Directory(self.clone()));
+        let mut components = path.components().enumerate().peekable();
+
+        while let Some((index, component)) = components.next() {
+            let next = match current.as_ref() {
+                Some(Node::Directory(dir)) => match component {
+                    Component::CurDir => Ok(current.clone()),
+                    Component::Prefix(_) => Err(GetErrorKind::UnexpectedPrefix),
+                    Component::RootDir => dir.get_root(),
+                    Component::ParentDir => dir
+                        .parent()
+                        .map(|parent| Some(Node::Directory(parent)))
+                        .ok_or(GetErrorKind::UnexpectedOrphan),
+                    Component::Normal(name) => match (components.peek(), parent) {
+                        (Some(_), Some(parent)) => dir
+                            .get_or_create(name, |reference, name| {
+                                Node::Directory(Self::create(parent(), Some((name, reference))))
+                            })
+                            .map(Some),
+                        (Some(_), _) => Ok(dir.child(name)),
+                        _ => init.take().map_or_else(
+                            || Ok(dir.child(name)),
+                            |init| dir.get_or_create(name, init).map(Some),
+                        ),
+                    },
+                },
+                Some(Node::File(_)) => Err(GetErrorKind::UnexpectedFile),
+                _ => return Ok(None),
+            };
+
+            current = next.map_err(|kind| GetError::new(kind, path, index))?;
+        }
+
+        Ok(current)
+    }
+}
+
+// DirectoryInternal
+
+#[derive(Debug)]
+pub struct DirectoryInternal<D, F> {
+    children: RefCell<BTreeMap<OsString, Node<D, F>>>,
+    parent: Option<(OsString, Parent<D, F>)>,
+    readonly: Cell<bool>,
+    value: RefCell<D>,
+}
+
+// File
+
+#[derive(Debug)]
+pub struct File<D, F>(Rc<FileInternal<D, F>>);
+
+// File - Standard Traits
+
+impl<D, F> Clone for File<D, F> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<D, F> Eq for File<D, F> {}
+
+impl<D, F> PartialEq for File<D, F> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+// File - Methods
+
+impl<D, F> File<D, F> {
+    fn create(value: F, parent: (OsString, Parent<D, F>)) -> Self {
+        Self(Rc::new(FileInternal {
+            parent,
+            readonly: Cell::new(false),
+            value: RefCell::new(value),
+        }))
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Option<OsString> {
+        Some(self.0.parent.0.clone())
+    }
+
+    #[must_use]
+    pub fn parent(&self) -> Option<Directory<D, F>> {
+        self.0.parent.1.upgrade().map(Directory)
+    }
+
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        match (self.name(), self.parent()) {
+            (Some(name), Some(parent)) => parent.path().join(name),
+            _ => PathBuf::from("/"),
+        }
+    }
+
+    #[must_use]
+    pub fn readonly(&self) -> bool {
+        self.0.readonly.get()
+    }
+
+    pub fn read<T, R>(&self, f: R) -> Result<T, BorrowError>
+    where
+        R: FnOnce(Ref<'_, F>) -> T,
+    {
+        self.0.value.try_borrow().map(f)
+    }
+
+    pub fn set_readonly(&self, readonly: bool) {
+        self.0.readonly.set(readonly);
+    }
+
+    pub fn write<T, W>(&self, f: W) -> Result<T, WriteError>
+    where
+        W: FnOnce(RefMut<'_, F>) -> T,
+    {
+        if self.readonly() {
+            return Err(WriteError::PermissionDenied);
+        }
+
+        self.0
+            .value
+            .try_borrow_mut()
+            .map(f)
+            .map_err(|_| WriteError::Borrowed)
+    }
+}
+
+// FileInternal
+
+#[derive(Debug)]
+pub struct FileInternal<D, F> {
+    parent: (OsString, Parent<D, F>),
+    readonly: Cell<bool>,
+    value: RefCell<F>,
+}
+
+// Node
+
+#[derive(Debug)]
+pub enum Node<D, F> {
+    Directory(Directory<D, F>),
+    File(File<D, F>),
+}
+
+// Node - Standard Traits
+
+impl<D, F> Clone for Node<D, F> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Directory(dir) => Self::Directory(dir.clone()),
+            Self::File(file) => Self::File(file.clone()),
+        }
+    }
+}
+
+// Types
+
+type Init<D, F> = fn(Parent<D, F>, OsString) -> Node<D, F>;
+
+type Parent<D, F> = Weak<DirectoryInternal<D, F>>;
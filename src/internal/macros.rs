@@ -0,0 +1,49 @@
+// Macros
+
+#[macro_export]
+macro_rules! memfs {
+    (@entries $dir:expr; $($name:expr => $kind:tt $(($($value:expr)?))?),* $(,)?) => {
+        $(
+            $crate::memfs!(@entry $dir; $name; $kind $(($($value)?))?);
+        )*
+    };
+    (@entry $dir:expr; $name:expr; { $($children:tt)* }) => {
+        {
+            let name = $name;
+            let dir = $dir
+                .entry(name)
+                .await
+                .or_insert_dir(::core::default::Default::default())
+                .await?
+                .into_dir()?;
+
+            $crate::memfs!(@entries dir; $($children)*);
+        }
+    };
+    (@entry $dir:expr; $name:expr; file($($value:expr)?)) => {
+        {
+            let name = $name;
+
+            $dir.entry(name)
+                .await
+                .or_insert_file($crate::memfs!(@value $($value)?))
+                .await?
+                .into_file()?;
+        }
+    };
+    (@value) => {
+        ::core::default::Default::default()
+    };
+    (@value $value:expr) => {
+        $value
+    };
+    ($($entries:tt)*) => {
+        async {
+            let fs = $crate::FileSystem::new();
+
+            $crate::memfs!(@entries fs; $($entries)*);
+
+            ::core::result::Result::Ok::<_, $crate::Error>(fs)
+        }
+    };
+}
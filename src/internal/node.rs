@@ -1,20 +1,66 @@
 pub mod child;
+#[cfg(feature = "bytes")]
+pub mod chunks;
+pub mod content;
 pub mod data;
 pub mod data_ext;
+pub mod data_len;
+pub mod depth;
+pub mod descendants;
+pub mod digest;
+pub mod identified;
 pub mod located;
+pub mod meta;
 pub mod named;
+pub mod observe;
+pub mod parent;
+pub mod permissions;
 pub mod root;
+pub mod siblings;
+pub mod text;
+pub mod timestamps;
+pub mod versioned;
+pub mod xattrs;
+
+use std::{
+    ffi::OsString,
+    sync::Arc,
+    time::SystemTime,
+};
 
 use async_trait::async_trait;
+use thiserror::Error;
 
 use self::{
     child::Child,
     data::ValueType,
+    data_ext::WriteError,
+    digest::Digest,
+    identified::{
+        Identified,
+        NodeId,
+    },
+    located::Located,
+    meta::Meta,
     named::Named,
+    observe::Observe,
+    permissions::Permissions,
+    timestamps::Timestamps,
+    versioned::Versioned,
+    xattrs::Xattrs,
 };
 use super::{
     directory::Directory,
     file::File,
+    file_system::{
+        access::Action,
+        hooks::{
+            Event,
+            Hooks,
+        },
+        journal::Record,
+        undo::Operation,
+    },
 };
 
 // Node
@@ -29,6 +75,14 @@ where
     File(File<D, F>),
 }
 
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum NodeError {
+    #[error("expected directory, but file found")]
+    UnexpectedFile,
+    #[error("expected file, but directory found")]
+    UnexpectedDirectory,
+}
+
 // Node - Standard Traits
 
 impl<D, F> Clone for Node<D, F>
@@ -44,6 +98,47 @@ where
     }
 }
 
+impl<D, F> Eq for Node<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+}
+
+impl<D, F> PartialEq for Node<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.same_node(other)
+    }
+}
+
+impl<D, F> TryFrom<Node<D, F>> for Directory<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    type Error = NodeError;
+
+    fn try_from(node: Node<D, F>) -> Result<Self, Self::Error> {
+        node.into_dir()
+    }
+}
+
+impl<D, F> TryFrom<Node<D, F>> for File<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    type Error = NodeError;
+
+    fn try_from(node: Node<D, F>) -> Result<Self, Self::Error> {
+        node.into_file()
+    }
+}
+
 // Node - Library Traits
 
 #[async_trait]
@@ -60,16 +155,319 @@ where
     }
 }
 
+#[async_trait]
+impl<D, F> Identified for Node<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn id(&self) -> NodeId {
+        match self {
+            Self::Directory(dir) => dir.id().await,
+            Self::File(file) => file.id().await,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, F> Meta for Node<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_meta<M>(&self) -> Option<M>
+    where
+        M: Clone + Send + Sync + 'static,
+    {
+        match self {
+            Self::Directory(dir) => dir.get_meta().await,
+            Self::File(file) => file.get_meta().await,
+        }
+    }
+
+    async fn remove_meta<M>(&self) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        match self {
+            Self::Directory(dir) => dir.remove_meta().await,
+            Self::File(file) => file.remove_meta().await,
+        }
+    }
+
+    async fn set_meta<M>(&self, value: M) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        match self {
+            Self::Directory(dir) => dir.set_meta(value).await,
+            Self::File(file) => file.set_meta(value).await,
+        }
+    }
+}
+
 #[async_trait]
 impl<D, F> Named for Node<D, F>
 where
     D: ValueType,
     F: ValueType,
 {
-    async fn name(&self) -> Option<String> {
+    async fn name(&self) -> Option<OsString> {
         match self {
             Self::Directory(dir) => dir.name().await,
             Self::File(file) => file.name().await,
         }
     }
 }
+
+#[async_trait]
+impl<D, F> Permissions for Node<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn readonly(&self) -> bool {
+        match self {
+            Self::Directory(dir) => dir.readonly().await,
+            Self::File(file) => file.readonly().await,
+        }
+    }
+
+    async fn set_readonly(&self, readonly: bool) {
+        match self {
+            Self::Directory(dir) => dir.set_readonly(readonly).await,
+            Self::File(file) => file.set_readonly(readonly).await,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, F> Timestamps for Node<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn accessed(&self) -> SystemTime {
+        match self {
+            Self::Directory(dir) => dir.accessed().await,
+            Self::File(file) => file.accessed().await,
+        }
+    }
+
+    async fn created(&self) -> SystemTime {
+        match self {
+            Self::Directory(dir) => dir.created().await,
+            Self::File(file) => file.created().await,
+        }
+    }
+
+    async fn modified(&self) -> SystemTime {
+        match self {
+            Self::Directory(dir) => dir.modified().await,
+            Self::File(file) => file.modified().await,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, F> Digest for Node<D, F>
+where
+    D: ValueType,
+    F: ValueType + AsRef<[u8]>,
+{
+    async fn tree_hash(&self) -> u64 {
+        match self {
+            Self::Directory(dir) => dir.tree_hash().await,
+            Self::File(file) => file.tree_hash().await,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, F> Versioned for Node<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn generation(&self) -> u64 {
+        match self {
+            Self::Directory(dir) => dir.generation().await,
+            Self::File(file) => file.generation().await,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, F> Xattrs for Node<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    async fn get_xattr(&self, name: &str) -> Option<Vec<u8>> {
+        match self {
+            Self::Directory(dir) => dir.get_xattr(name).await,
+            Self::File(file) => file.get_xattr(name).await,
+        }
+    }
+
+    async fn list_xattrs(&self) -> Vec<String> {
+        match self {
+            Self::Directory(dir) => dir.list_xattrs().await,
+            Self::File(file) => file.list_xattrs().await,
+        }
+    }
+
+    async fn remove_xattr(&self, name: &str) -> Result<Option<Vec<u8>>, WriteError> {
+        self.replace_xattr(name, None, None).await
+    }
+
+    async fn set_xattr(&self, name: &str, value: Vec<u8>) -> Result<Option<Vec<u8>>, WriteError> {
+        self.replace_xattr(name, Some(value), None).await
+    }
+}
+
+// Node - Methods
+
+impl<D, F> Node<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    #[must_use]
+    pub const fn as_dir(&self) -> Option<&Directory<D, F>> {
+        match self {
+            Self::Directory(dir) => Some(dir),
+            Self::File(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_file(&self) -> Option<&File<D, F>> {
+        match self {
+            Self::Directory(_) => None,
+            Self::File(file) => Some(file),
+        }
+    }
+
+    pub fn into_dir(self) -> Result<Directory<D, F>, NodeError> {
+        match self {
+            Self::Directory(dir) => Ok(dir),
+            Self::File(_) => Err(NodeError::UnexpectedFile),
+        }
+    }
+
+    pub fn into_file(self) -> Result<File<D, F>, NodeError> {
+        match self {
+            Self::Directory(_) => Err(NodeError::UnexpectedDirectory),
+            Self::File(file) => Ok(file),
+        }
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> NodeKind {
+        match self {
+            Self::Directory(_) => NodeKind::Directory,
+            Self::File(_) => NodeKind::File,
+        }
+    }
+
+    #[must_use]
+    pub fn same_node(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Directory(a), Self::Directory(b)) => a.ptr_eq(b),
+            (Self::File(a), Self::File(b)) => a.ptr_eq(b),
+            _ => false,
+        }
+    }
+
+    pub(crate) async fn remove_meta_as<M>(&self, context: Option<&str>) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        match self {
+            Self::Directory(dir) => dir.remove_meta_as(context).await,
+            Self::File(file) => file.remove_meta_as(context).await,
+        }
+    }
+
+    pub(crate) async fn set_meta_as<M>(&self, value: M, context: Option<&str>) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        match self {
+            Self::Directory(dir) => dir.set_meta_as(value, context).await,
+            Self::File(file) => file.set_meta_as(value, context).await,
+        }
+    }
+
+    pub(crate) async fn set_readonly_as(&self, readonly: bool, context: Option<&str>) {
+        match self {
+            Self::Directory(dir) => dir.set_readonly_as(readonly, context).await,
+            Self::File(file) => file.set_readonly_as(readonly, context).await,
+        }
+    }
+
+    pub(crate) async fn replace_xattr(
+        &self,
+        name: &str,
+        value: Option<Vec<u8>>,
+        context: Option<&str>,
+    ) -> Result<Option<Vec<u8>>, WriteError> {
+        let (previous, hooks) = self.swap_xattr(name, value.clone(), context).await?;
+
+        hooks.record(|| Operation::Xattr {
+            node: self.clone(),
+            name: String::from(name),
+            previous: previous.clone(),
+            value,
+        });
+
+        Ok(previous)
+    }
+
+    pub(crate) async fn swap_xattr(
+        &self,
+        name: &str,
+        value: Option<Vec<u8>>,
+        context: Option<&str>,
+    ) -> Result<(Option<Vec<u8>>, Arc<Hooks<D, F>>), WriteError> {
+        if self.readonly().await {
+            return Err(WriteError::PermissionDenied);
+        }
+
+        let permitted = match self {
+            Self::Directory(dir) => dir.permit(Action::Write).await,
+            Self::File(file) => file.permit(Action::Write).await,
+        };
+
+        if !permitted {
+            return Err(WriteError::AccessDenied);
+        }
+
+        let (previous, hooks) = match self {
+            Self::Directory(dir) => dir.swap_xattr(name, value.clone()).await,
+            Self::File(file) => file.swap_xattr(name, value.clone()).await,
+        };
+
+        hooks
+            .journal(self.path(), |_, path| Record::SetXattr {
+                path,
+                name: String::from(name),
+                value,
+            })
+            .await;
+        hooks
+            .notify_as(Event::Metadata, self.path(), self, None, context)
+            .await;
+
+        Ok((previous, hooks))
+    }
+}
+
+// NodeKind
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NodeKind {
+    Directory,
+    File,
+}
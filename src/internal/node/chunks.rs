@@ -0,0 +1,49 @@
+use bytes::Bytes;
+use futures::{
+    stream::{
+        self,
+        BoxStream,
+    },
+    StreamExt,
+};
+
+use super::{
+    data::ValueType,
+    data_ext::DataExt,
+};
+
+// ReadChunks
+
+pub trait ReadChunks<V>
+where
+    V: AsRef<[u8]> + ValueType,
+{
+    fn read_chunks(&self, chunk_size: usize) -> BoxStream<'_, Bytes>;
+}
+
+// ReadChunks - Blanket Implementation
+
+impl<D, V> ReadChunks<V> for D
+where
+    D: DataExt<V> + Sync,
+    V: AsRef<[u8]> + ValueType,
+{
+    fn read_chunks(&self, chunk_size: usize) -> BoxStream<'_, Bytes> {
+        let chunk_size = chunk_size.max(1);
+
+        stream::unfold(0, move |offset| {
+            self.read(move |value| {
+                let data = value.as_ref();
+
+                data.get(offset..)
+                    .filter(|rest| !rest.is_empty())
+                    .map(|rest| {
+                        let chunk = &rest[..chunk_size.min(rest.len())];
+
+                        (Bytes::copy_from_slice(chunk), offset + chunk.len())
+                    })
+            })
+        })
+        .boxed()
+    }
+}
@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+
+use super::{
+    data::ValueType,
+    data_ext::{
+        DataExt,
+        WriteError,
+    },
+};
+
+// Content
+
+pub trait Content {
+    type Slice: ?Sized;
+
+    fn append(&mut self, data: &Self::Slice);
+
+    fn truncate(&mut self, len: usize);
+}
+
+// Content - Implementations
+
+impl Content for String {
+    type Slice = str;
+
+    fn append(&mut self, data: &str) {
+        self.push_str(data);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if let Some(len) = (0..=len.min(self.len()))
+            .rev()
+            .find(|len| self.is_char_boundary(*len))
+        {
+            Self::truncate(self, len);
+        }
+    }
+}
+
+impl Content for Vec<u8> {
+    type Slice = [u8];
+
+    fn append(&mut self, data: &[u8]) {
+        self.extend_from_slice(data);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        Self::truncate(self, len);
+    }
+}
+
+// ContentExt
+
+#[async_trait]
+pub trait ContentExt<V>
+where
+    V: Content + ValueType,
+{
+    async fn append<A>(&self, data: A) -> Result<(), WriteError>
+    where
+        A: AsRef<V::Slice> + Send;
+
+    async fn clear(&self) -> Result<(), WriteError>;
+
+    async fn truncate(&self, len: usize) -> Result<(), WriteError>;
+}
+
+// ContentExt - Blanket Implementation
+
+#[async_trait]
+impl<D, V> ContentExt<V> for D
+where
+    D: DataExt<V> + Sync,
+    V: Content + ValueType,
+{
+    async fn append<A>(&self, data: A) -> Result<(), WriteError>
+    where
+        A: AsRef<V::Slice> + Send,
+    {
+        self.write(move |mut current| current.append(data.as_ref()))
+            .await
+    }
+
+    async fn clear(&self) -> Result<(), WriteError> {
+        self.truncate(0).await
+    }
+
+    async fn truncate(&self, len: usize) -> Result<(), WriteError> {
+        self.write(|mut current| Content::truncate(&mut *current, len))
+            .await
+    }
+}
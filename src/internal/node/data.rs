@@ -14,6 +14,10 @@ where
     V: ValueType,
 {
     async fn data(&self) -> Value<V>;
+
+    async fn data_mut(&self) -> Value<V> {
+        self.data().await
+    }
 }
 
 // Value
@@ -50,13 +54,28 @@ where
 impl<V> Value<V>
 where
     V: ValueType,
+{
+    #[must_use]
+    pub fn new(data: V) -> Self {
+        Self(Arc::new(RwLock::new(data)))
+    }
+
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<V> Value<V>
+where
+    V: ValueType + Default,
 {
     #[must_use]
     pub fn from_option(data: Option<V>) -> Self {
-        Self(Arc::new(RwLock::new(data.unwrap_or_default())))
+        Self::new(data.unwrap_or_default())
     }
 }
 
 // ValueType
 
-pub trait ValueType = Default + Send + Sync;
+pub trait ValueType = Send + Sync;
@@ -1,13 +1,35 @@
+use std::{
+    mem,
+    sync::Arc,
+};
+
 use async_lock::{
+    RwLock,
     RwLockReadGuard,
     RwLockWriteGuard,
 };
 use async_trait::async_trait;
 use futures::FutureExt;
+use thiserror::Error;
 
-use super::data::{
-    Data,
-    ValueType,
+use super::{
+    super::{
+        file::history::Versions,
+        file_system::{
+            access::Action,
+            context::Context,
+            journal::Append,
+        },
+    },
+    data::{
+        Data,
+        ValueType,
+    },
+    observe::{
+        Access,
+        Observe,
+    },
+    permissions::Permissions,
 };
 
 // DataExt
@@ -22,9 +44,49 @@ where
     where
         R: FnOnce(RwLockReadGuard<'_, V>) -> T + Send;
 
-    async fn write<T, W>(&self, f: W) -> T
+    async fn write<T, W>(&self, f: W) -> Result<T, WriteError>
+    where
+        T: Send,
+        W: FnOnce(RwLockWriteGuard<'_, V>) -> T + Send;
+
+    async fn try_read<T, R>(&self, f: R) -> Option<T>
+    where
+        R: FnOnce(RwLockReadGuard<'_, V>) -> T + Send;
+
+    async fn try_write<T, W>(&self, f: W) -> Result<Option<T>, WriteError>
+    where
+        T: Send,
+        W: FnOnce(RwLockWriteGuard<'_, V>) -> T + Send;
+
+    async fn compare_and_swap(&self, expected: &V, value: V) -> Result<bool, WriteError>
+    where
+        V: PartialEq;
+
+    async fn replace(&self, value: V) -> Result<V, WriteError>
+    where
+        V: 'async_trait;
+
+    async fn take(&self) -> Result<V, WriteError>
     where
-        W: FnMut(RwLockWriteGuard<'_, V>) -> T + Send;
+        V: Default;
+}
+
+#[derive(Clone, Copy, Debug, Error)]
+pub enum WriteError {
+    #[error("access to the node was denied by the file system interceptor")]
+    AccessDenied,
+    #[error("node data is already borrowed by an enclosing read or write")]
+    Borrowed,
+    #[error("entry changed while waiting for the file system interceptor")]
+    Conflict,
+    #[error("name is not a single normal path component")]
+    InvalidName,
+    #[error("children of the directory could not be loaded")]
+    LoadFailed,
+    #[error("node is read-only, so its data cannot be written")]
+    PermissionDenied,
+    #[error("write would exceed the file system quota")]
+    QuotaExceeded,
 }
 
 // DataExt - Blanket Implementation
@@ -32,7 +94,7 @@ where
 #[async_trait]
 impl<D, V> DataExt<V> for D
 where
-    D: Data<V> + Sync,
+    D: Data<V> + Observe<V> + Permissions + Sync,
     V: ValueType,
 {
     async fn read<T, R>(&self, f: R) -> T
@@ -40,16 +102,176 @@ where
         R: FnOnce(RwLockReadGuard<'_, V>) -> T + Send,
     {
         self.data()
-            .then(|value| async move { value.read().map(f).await })
+            .then(|value| async move {
+                let guard = value.read().await;
+
+                self.observe(Access::Read).map(|()| f(guard)).await
+            })
             .await
     }
 
-    async fn write<T, W>(&self, f: W) -> T
+    async fn write<T, W>(&self, f: W) -> Result<T, WriteError>
     where
+        T: Send,
         W: FnOnce(RwLockWriteGuard<'_, V>) -> T + Send,
     {
-        self.data()
-            .then(|value| async move { value.write().map(f).await })
+        write(self, f, None).await
+    }
+
+    async fn try_read<T, R>(&self, f: R) -> Option<T>
+    where
+        R: FnOnce(RwLockReadGuard<'_, V>) -> T + Send,
+    {
+        let value = self.data().await;
+        let guard = value.try_read()?;
+
+        self.observe(Access::Read).map(|()| Some(f(guard))).await
+    }
+
+    async fn try_write<T, W>(&self, f: W) -> Result<Option<T>, WriteError>
+    where
+        T: Send,
+        W: FnOnce(RwLockWriteGuard<'_, V>) -> T + Send,
+    {
+        if self.readonly().await {
+            return Err(WriteError::PermissionDenied);
+        }
+
+        if !self.permit(Action::Write).await {
+            return Err(WriteError::AccessDenied);
+        }
+
+        let journal = self.journal().await;
+        let quota = self.quota().await;
+        let versions = self.versions().await;
+        let value = self.data_mut().await;
+
+        let Some(guard) = value.try_write() else {
+            return Ok(None);
+        };
+
+        self.observe(Access::Write).await;
+
+        let result = commit(guard, f, journal, quota, versions).await?;
+
+        self.written(None).await;
+
+        Ok(Some(result))
+    }
+
+    #[allow(clippy::match_bool)]
+    async fn compare_and_swap(&self, expected: &V, value: V) -> Result<bool, WriteError>
+    where
+        V: PartialEq,
+    {
+        self.write(|mut current| match *current == *expected {
+            true => {
+                *current = value;
+                true
+            }
+            _ => false,
+        })
+        .await
+    }
+
+    async fn replace(&self, value: V) -> Result<V, WriteError>
+    where
+        V: 'async_trait,
+    {
+        self.write(|mut current| mem::replace(&mut *current, value))
             .await
     }
+
+    async fn take(&self) -> Result<V, WriteError>
+    where
+        V: Default,
+    {
+        self.write(|mut current| mem::take(&mut *current)).await
+    }
+}
+
+// Functions
+
+pub async fn write<N, V, T, W>(
+    node: &N,
+    f: W,
+    context: Option<&str>,
+) -> Result<T, WriteError>
+where
+    N: Data<V> + Observe<V> + Permissions + Sync,
+    V: ValueType,
+    T: Send,
+    W: FnOnce(RwLockWriteGuard<'_, V>) -> T + Send,
+{
+    if node.readonly().await {
+        return Err(WriteError::PermissionDenied);
+    }
+
+    if !node.permit(Action::Write).await {
+        return Err(WriteError::AccessDenied);
+    }
+
+    let journal = node.journal().await;
+    let quota = node.quota().await;
+    let versions = node.versions().await;
+
+    let result = node
+        .data_mut()
+        .then(|value| async move {
+            let guard = value.write().await;
+
+            node.observe(Access::Write).await;
+
+            commit(guard, f, journal, quota, versions).await
+        })
+        .await?;
+
+    node.written(context).await;
+
+    Ok(result)
+}
+
+async fn commit<V, T, W>(
+    mut guard: RwLockWriteGuard<'_, V>,
+    f: W,
+    journal: Option<Append<V>>,
+    quota: Option<Arc<Context<V>>>,
+    versions: Option<Versions<V>>,
+) -> Result<T, WriteError>
+where
+    V: ValueType,
+    W: FnOnce(RwLockWriteGuard<'_, V>) -> T,
+{
+    if quota.as_ref().is_some_and(|quota| quota.quota.exceeded()) {
+        return Err(WriteError::QuotaExceeded);
+    }
+
+    if let Some(versions) = versions {
+        versions.record(&guard);
+    }
+
+    let before = quota.as_ref().map(|quota| quota.len(&guard));
+    let scratch = journal
+        .as_ref()
+        .map(|journal| journal.snapshot(&guard))
+        .or_else(|| quota.as_ref().and_then(|quota| quota.take(&mut guard)));
+
+    let Some(next) = scratch else {
+        return Ok(f(guard));
+    };
+
+    let next = RwLock::new(next);
+    let result = f(next.write().await);
+
+    *guard = next.into_inner();
+
+    if let Some(journal) = journal {
+        journal.append(&guard);
+    }
+
+    if let Some((quota, before)) = quota.zip(before) {
+        quota.resize(before, &guard);
+    }
+
+    Ok(result)
 }
@@ -0,0 +1,26 @@
+// DataLen
+
+pub trait DataLen {
+    fn data_len(&self) -> usize;
+}
+
+// DataLen - Implementations
+
+impl DataLen for String {
+    fn data_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl DataLen for Vec<u8> {
+    fn data_len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl DataLen for bytes::Bytes {
+    fn data_len(&self) -> usize {
+        self.len()
+    }
+}
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+
+use super::{
+    child::Child,
+    data::ValueType,
+};
+
+// Depth
+
+#[async_trait]
+pub trait Depth<D, F> {
+    async fn depth(&self) -> usize
+    where
+        D: ValueType,
+        F: ValueType;
+}
+
+// Depth - Blanket Implementation
+
+#[async_trait]
+impl<N, D, F> Depth<D, F> for N
+where
+    N: Child<D, F> + Sync,
+    D: ValueType,
+    F: ValueType,
+{
+    async fn depth(&self) -> usize {
+        let mut depth = 0;
+        let mut parent = self.parent().await;
+
+        while let Some(dir) = parent {
+            depth += 1;
+            parent = dir.parent().await;
+        }
+
+        depth
+    }
+}
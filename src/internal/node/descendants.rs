@@ -0,0 +1,43 @@
+use std::io;
+
+use futures::{
+    stream::{
+        self,
+        BoxStream,
+    },
+    StreamExt,
+};
+
+use super::{
+    super::directory::walk::{
+        Walk,
+        Walked,
+    },
+    data::ValueType,
+    Node,
+};
+
+// Descendants
+
+pub trait Descendants<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn descendants(&self) -> BoxStream<'_, io::Result<Walked<D, F>>>;
+}
+
+// Descendants - Implementation
+
+impl<D, F> Descendants<D, F> for Node<D, F>
+where
+    D: ValueType,
+    F: ValueType,
+{
+    fn descendants(&self) -> BoxStream<'_, io::Result<Walked<D, F>>> {
+        match self {
+            Self::Directory(dir) => dir.walk_parallel(1),
+            Self::File(_) => stream::empty().boxed(),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{
+        Hash,
+        Hasher,
+    },
+    sync::Mutex,
+};
+
+use async_trait::async_trait;
+
+// Digest
+
+#[async_trait]
+pub trait Digest {
+    async fn etag(&self) -> String {
+        format!("\"{:016x}\"", self.tree_hash().await)
+    }
+
+    async fn tree_hash(&self) -> u64;
+}
+
+// Digests
+
+#[derive(Debug, Default)]
+pub struct Digests(Mutex<Option<(u64, u64)>>);
+
+// Digests - Methods
+
+impl Digests {
+    pub fn get(&self, generation: u64) -> Option<u64> {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|cached| *cached)
+            .and_then(|(cached, hash)| (cached == generation).then_some(hash))
+    }
+
+    pub fn set(&self, generation: u64, hash: u64) {
+        if let Ok(mut cached) = self.0.lock() {
+            *cached = Some((generation, hash));
+        }
+    }
+}
+
+// Functions
+
+pub fn hash<H>(value: &H) -> u64
+where
+    H: Hash + ?Sized,
+{
+    let mut hasher = DefaultHasher::new();
+
+    value.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+// Identified
+
+#[async_trait]
+pub trait Identified {
+    async fn id(&self) -> NodeId;
+}
+
+// NodeId
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NodeId(pub(crate) u64);
+
+// NodeId - Standard Traits
+
+impl From<NodeId> for u64 {
+    fn from(id: NodeId) -> Self {
+        id.0
+    }
+}
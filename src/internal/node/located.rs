@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 
 use async_trait::async_trait;
+#[cfg(feature = "camino")]
+use camino::Utf8PathBuf;
 use futures::FutureExt;
 
 use super::{
@@ -18,6 +20,11 @@ where
     F: ValueType,
 {
     async fn path(&self) -> PathBuf;
+
+    #[cfg(feature = "camino")]
+    async fn utf8_path(&self) -> Option<Utf8PathBuf> {
+        Utf8PathBuf::from_path_buf(self.path().await).ok()
+    }
 }
 
 // Located - Blanket Implementation
@@ -0,0 +1,70 @@
+use std::{
+    any::{
+        Any,
+        TypeId,
+    },
+    collections::HashMap,
+};
+
+use async_trait::async_trait;
+
+// Meta
+
+#[async_trait]
+pub trait Meta {
+    async fn get_meta<M>(&self) -> Option<M>
+    where
+        M: Clone + Send + Sync + 'static;
+
+    async fn remove_meta<M>(&self) -> Option<M>
+    where
+        M: Send + Sync + 'static;
+
+    async fn set_meta<M>(&self, value: M) -> Option<M>
+    where
+        M: Send + Sync + 'static;
+}
+
+// Metas
+
+#[derive(Debug, Default)]
+pub struct Metas(HashMap<TypeId, Box<dyn Any + Send + Sync>>);
+
+// Metas - Methods
+
+impl Metas {
+    pub(crate) fn get<M>(&self) -> Option<M>
+    where
+        M: Clone + Send + Sync + 'static,
+    {
+        self.0
+            .get(&TypeId::of::<M>())
+            .and_then(|value| value.downcast_ref::<M>())
+            .cloned()
+    }
+
+    pub(crate) fn remove<M>(&mut self) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        downcast(self.0.remove(&TypeId::of::<M>()))
+    }
+
+    pub(crate) fn insert<M>(&mut self, value: M) -> Option<M>
+    where
+        M: Send + Sync + 'static,
+    {
+        downcast(self.0.insert(TypeId::of::<M>(), Box::new(value)))
+    }
+}
+
+// Functions
+
+fn downcast<M>(value: Option<Box<dyn Any + Send + Sync>>) -> Option<M>
+where
+    M: Send + Sync + 'static,
+{
+    value
+        .and_then(|value| value.downcast::<M>().ok())
+        .map(|value| *value)
+}
@@ -1,8 +1,10 @@
+use std::ffi::OsString;
+
 use async_trait::async_trait;
 
 // Name
 
 #[async_trait]
 pub trait Named {
-    async fn name(&self) -> Option<String>;
+    async fn name(&self) -> Option<OsString>;
 }
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::super::{
+    file::history::Versions,
+    file_system::{
+        access::Action,
+        context::Context,
+        journal::Append,
+    },
+};
+
+// Observe
+
+#[async_trait]
+pub trait Observe<V> {
+    async fn journal(&self) -> Option<Append<V>>;
+
+    async fn observe(&self, access: Access);
+
+    async fn permit(&self, action: Action) -> bool;
+
+    async fn quota(&self) -> Option<Arc<Context<V>>>;
+
+    async fn versions(&self) -> Option<Versions<V>>;
+
+    async fn written(&self, context: Option<&str>);
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Access {
+    Read,
+    Write,
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use super::{
+    super::directory::list::Listed,
+    data::ValueType,
+};
+
+// Parent
+
+#[async_trait]
+pub trait Parent<D, F> {
+    async fn children(&self) -> Vec<Listed<D, F>>
+    where
+        D: ValueType,
+        F: ValueType;
+}
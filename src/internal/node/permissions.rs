@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+// Permissions
+
+#[async_trait]
+pub trait Permissions {
+    async fn readonly(&self) -> bool;
+
+    async fn set_readonly(&self, readonly: bool);
+}
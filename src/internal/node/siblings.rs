@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+
+use super::{
+    super::directory::list::{
+        List,
+        Listed,
+    },
+    child::Child,
+    data::ValueType,
+    named::Named,
+};
+
+// Siblings
+
+#[async_trait]
+pub trait Siblings<D, F> {
+    async fn siblings(&self) -> Vec<Listed<D, F>>
+    where
+        D: ValueType,
+        F: ValueType;
+}
+
+// Siblings - Blanket Implementation
+
+#[async_trait]
+impl<N, D, F> Siblings<D, F> for N
+where
+    N: Child<D, F> + Named + Sync,
+    D: ValueType,
+    F: ValueType,
+{
+    async fn siblings(&self) -> Vec<Listed<D, F>> {
+        let (Some(parent), Some(name)) = (self.parent().await, self.name().await) else {
+            return Vec::new();
+        };
+
+        parent
+            .list()
+            .await
+            .into_iter()
+            .filter(|(sibling, _)| *sibling != name)
+            .collect()
+    }
+}
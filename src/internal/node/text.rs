@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use futures::{
+    stream::{
+        self,
+        BoxStream,
+    },
+    FutureExt,
+    StreamExt,
+};
+
+use super::{
+    data::ValueType,
+    data_ext::{
+        DataExt,
+        WriteError,
+    },
+};
+
+// TextFileExt
+
+#[async_trait]
+pub trait TextFileExt<V>
+where
+    V: AsRef<str> + From<String> + ValueType,
+{
+    fn lines(&self) -> BoxStream<'_, String>;
+
+    async fn read_to_string(&self) -> String;
+
+    async fn write_str(&self, text: &str) -> Result<(), WriteError>;
+}
+
+// TextFileExt - Blanket Implementation
+
+#[async_trait]
+impl<D, V> TextFileExt<V> for D
+where
+    D: DataExt<V> + Sync,
+    V: AsRef<str> + From<String> + ValueType,
+{
+    fn lines(&self) -> BoxStream<'_, String> {
+        self.read(|value| value.as_ref().lines().map(String::from).collect::<Vec<_>>())
+            .map(stream::iter)
+            .flatten_stream()
+            .boxed()
+    }
+
+    async fn read_to_string(&self) -> String {
+        self.read(|value| String::from(value.as_ref())).await
+    }
+
+    async fn write_str(&self, text: &str) -> Result<(), WriteError> {
+        self.write(|mut value| *value = V::from(String::from(text)))
+            .await
+    }
+}
@@ -0,0 +1,45 @@
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+
+use super::observe::Access;
+
+// Timestamps
+
+#[async_trait]
+pub trait Timestamps {
+    async fn accessed(&self) -> SystemTime;
+
+    async fn created(&self) -> SystemTime;
+
+    async fn modified(&self) -> SystemTime;
+}
+
+// Times
+
+#[derive(Clone, Copy, Debug)]
+pub struct Times {
+    pub(crate) accessed: SystemTime,
+    pub(crate) created: SystemTime,
+    pub(crate) modified: SystemTime,
+}
+
+// Times - Methods
+
+impl Times {
+    pub(crate) const fn new(now: SystemTime) -> Self {
+        Self {
+            accessed: now,
+            created: now,
+            modified: now,
+        }
+    }
+
+    pub(crate) const fn observe(&mut self, access: Access, now: SystemTime) {
+        self.accessed = now;
+
+        if matches!(access, Access::Write) {
+            self.modified = now;
+        }
+    }
+}
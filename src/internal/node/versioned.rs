@@ -0,0 +1,38 @@
+use std::sync::{
+    atomic::{
+        AtomicU64,
+        Ordering,
+    },
+    Arc,
+};
+
+use async_trait::async_trait;
+
+// Versioned
+
+#[async_trait]
+pub trait Versioned {
+    async fn generation(&self) -> u64;
+
+    async fn changed_since(&self, generation: u64) -> bool {
+        self.generation().await > generation
+    }
+}
+
+// Functions
+
+pub fn bump(generations: &[Arc<AtomicU64>]) {
+    let generation = next_generation();
+
+    for current in generations {
+        current.fetch_max(generation, Ordering::SeqCst);
+    }
+}
+
+pub fn next_generation() -> u64 {
+    GENERATION.fetch_add(1, Ordering::SeqCst)
+}
+
+// Generation
+
+static GENERATION: AtomicU64 = AtomicU64::new(1);
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use super::data_ext::WriteError;
+
+// Xattrs
+
+#[async_trait]
+pub trait Xattrs {
+    async fn get_xattr(&self, name: &str) -> Option<Vec<u8>>;
+
+    async fn list_xattrs(&self) -> Vec<String>;
+
+    async fn remove_xattr(&self, name: &str) -> Result<Option<Vec<u8>>, WriteError>;
+
+    async fn set_xattr(&self, name: &str, value: Vec<u8>) -> Result<Option<Vec<u8>>, WriteError>;
+}
@@ -0,0 +1,245 @@
+use std::{
+    fmt,
+    path::Path,
+};
+
+#[cfg(feature = "camino")]
+use camino::{
+    Utf8Path,
+    Utf8PathBuf,
+};
+use thiserror::Error;
+
+// MemPath
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MemPath<'a>(&'a str);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum MemPathError {
+    #[error("path segment contains a NUL character: {0:?}")]
+    InvalidSegment(String),
+    #[error("path is not normalized, expected {expected:?} but found {found:?}")]
+    NotNormalized { expected: String, found: String },
+    #[error("path is not valid UTF-8: {0:?}")]
+    NotUtf8(String),
+    #[error("path contains a prefix, which is not supported: {0:?}")]
+    UnexpectedPrefix(String),
+}
+
+// MemPath - Standard Traits
+
+impl AsRef<Path> for MemPath<'_> {
+    fn as_ref(&self) -> &Path {
+        Path::new(self.0)
+    }
+}
+
+#[cfg(feature = "camino")]
+impl AsRef<Utf8Path> for MemPath<'_> {
+    fn as_ref(&self) -> &Utf8Path {
+        Utf8Path::new(self.0)
+    }
+}
+
+impl AsRef<str> for MemPath<'_> {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl fmt::Display for MemPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for MemPath<'a> {
+    type Error = MemPathError;
+
+    fn try_from(path: &'a str) -> Result<Self, Self::Error> {
+        Self::new(path)
+    }
+}
+
+// MemPath - Methods
+
+impl<'a> MemPath<'a> {
+    #[allow(clippy::match_bool)]
+    pub fn new(path: &'a str) -> Result<Self, MemPathError> {
+        let expected = normalize(path)?;
+
+        match expected == path {
+            true => Ok(Self(path)),
+            _ => Err(MemPathError::NotNormalized {
+                expected,
+                found: String::from(path),
+            }),
+        }
+    }
+
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    #[must_use]
+    pub fn is_absolute(&self) -> bool {
+        self.0.starts_with('/')
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item = &'a str> {
+        self.0
+            .split('/')
+            .filter(|segment| !segment.is_empty() && *segment != ".")
+    }
+
+    #[must_use]
+    pub fn to_mem_path_buf(&self) -> MemPathBuf {
+        MemPathBuf(String::from(self.0))
+    }
+}
+
+// MemPathBuf
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MemPathBuf(String);
+
+// MemPathBuf - Standard Traits
+
+impl AsRef<Path> for MemPathBuf {
+    fn as_ref(&self) -> &Path {
+        Path::new(&self.0)
+    }
+}
+
+#[cfg(feature = "camino")]
+impl AsRef<Utf8Path> for MemPathBuf {
+    fn as_ref(&self) -> &Utf8Path {
+        Utf8Path::new(&self.0)
+    }
+}
+
+impl AsRef<str> for MemPathBuf {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for MemPathBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<MemPath<'_>> for MemPathBuf {
+    fn from(path: MemPath<'_>) -> Self {
+        path.to_mem_path_buf()
+    }
+}
+
+#[cfg(feature = "camino")]
+impl From<MemPathBuf> for Utf8PathBuf {
+    fn from(path: MemPathBuf) -> Self {
+        Self::from(path.0)
+    }
+}
+
+impl TryFrom<&Path> for MemPathBuf {
+    type Error = MemPathError;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        path.to_str()
+            .ok_or_else(|| MemPathError::NotUtf8(path.to_string_lossy().into_owned()))
+            .and_then(Self::new)
+    }
+}
+
+#[cfg(feature = "camino")]
+impl TryFrom<&Utf8Path> for MemPathBuf {
+    type Error = MemPathError;
+
+    fn try_from(path: &Utf8Path) -> Result<Self, Self::Error> {
+        Self::new(path.as_str())
+    }
+}
+
+impl TryFrom<&str> for MemPathBuf {
+    type Error = MemPathError;
+
+    fn try_from(path: &str) -> Result<Self, Self::Error> {
+        Self::new(path)
+    }
+}
+
+// MemPathBuf - Methods
+
+impl MemPathBuf {
+    pub fn new<P>(path: P) -> Result<Self, MemPathError>
+    where
+        P: AsRef<str>,
+    {
+        normalize(path.as_ref()).map(Self)
+    }
+
+    #[must_use]
+    pub fn as_mem_path(&self) -> MemPath<'_> {
+        MemPath(&self.0)
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn join<P>(&self, path: P) -> Result<Self, MemPathError>
+    where
+        P: AsRef<str>,
+    {
+        Self::new(format!("{}/{}", self.0, path.as_ref()))
+    }
+}
+
+// Functions
+
+fn normalize(path: &str) -> Result<String, MemPathError> {
+    let absolute = path.starts_with(['/', '\\']);
+    let mut segments = Vec::new();
+
+    for segment in path.split(['/', '\\']) {
+        match segment {
+            "" | "." => {}
+            ".." => match segments.last() {
+                Some(&last) if last != ".." => {
+                    segments.pop();
+                }
+                _ if absolute => {}
+                _ => segments.push(segment),
+            },
+            _ if segment.contains('\0') => {
+                return Err(MemPathError::InvalidSegment(String::from(segment)));
+            }
+            _ if segments.is_empty() && !absolute && is_prefix(segment) => {
+                return Err(MemPathError::UnexpectedPrefix(String::from(segment)));
+            }
+            _ => segments.push(segment),
+        }
+    }
+
+    let joined = segments.join("/");
+
+    Ok(match (absolute, joined.is_empty()) {
+        (true, _) => format!("/{joined}"),
+        (_, true) => String::from("."),
+        _ => joined,
+    })
+}
+
+fn is_prefix(segment: &str) -> bool {
+    let mut chars = segment.chars();
+
+    matches!(
+        (chars.next(), chars.next(), chars.next()),
+        (Some(drive), Some(':'), None) if drive.is_ascii_alphabetic()
+    )
+}
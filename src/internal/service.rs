@@ -0,0 +1,177 @@
+use std::{
+    convert::Infallible,
+    path::PathBuf,
+    task::{
+        Context,
+        Poll,
+    },
+};
+
+use bytes::Bytes;
+use futures::{
+    future::BoxFuture,
+    FutureExt,
+};
+use http::{
+    header,
+    HeaderValue,
+    Method,
+    Request,
+    Response,
+    StatusCode,
+};
+use http_body_util::Full;
+use tower_service::Service;
+
+use super::{
+    directory::{
+        get_ext::GetExt,
+        Directory,
+    },
+    node::{
+        data::ValueType,
+        data_ext::DataExt,
+    },
+};
+
+// ServeDir
+
+#[derive(Debug)]
+pub struct ServeDir<D>
+where
+    D: ValueType,
+{
+    dir: Directory<D, Bytes>,
+}
+
+// ServeDir - Standard Traits
+
+impl<D> Clone for ServeDir<D>
+where
+    D: ValueType,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dir: self.dir.clone(),
+        }
+    }
+}
+
+// ServeDir - Library Traits
+
+impl<D, B> Service<Request<B>> for ServeDir<D>
+where
+    D: ValueType + 'static,
+{
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+    type Response = Response<Full<Bytes>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let dir = self.dir.clone();
+        let method = request.method().clone();
+        let path = request.uri().path().to_owned();
+
+        async move {
+            match method {
+                Method::GET | Method::HEAD => Ok(Self::serve(&dir, &path, &method).await),
+                _ => Ok(Self::status(StatusCode::METHOD_NOT_ALLOWED)),
+            }
+        }
+        .boxed()
+    }
+}
+
+// ServeDir - Methods
+
+impl<D> ServeDir<D>
+where
+    D: ValueType,
+{
+    #[must_use]
+    pub fn new(dir: &Directory<D, Bytes>) -> Self {
+        Self { dir: dir.clone() }
+    }
+
+    async fn serve(
+        dir: &Directory<D, Bytes>,
+        path: &str,
+        method: &Method,
+    ) -> Response<Full<Bytes>> {
+        let Some(path) = relative(path) else {
+            return Self::status(StatusCode::BAD_REQUEST);
+        };
+
+        let Ok(Some(file)) = dir.get_file(&path).await else {
+            return Self::status(StatusCode::NOT_FOUND);
+        };
+
+        let bytes = file.read(|value| value.clone()).await;
+        let len = HeaderValue::from(bytes.len());
+        let mime = mime_guess::from_path(&path).first_or_octet_stream();
+        let body = match *method {
+            Method::HEAD => Full::default(),
+            _ => Full::new(bytes),
+        };
+
+        let mut response = Response::new(body);
+        let headers = response.headers_mut();
+
+        headers.insert(header::CONTENT_LENGTH, len);
+
+        if let Ok(content_type) = HeaderValue::from_str(mime.as_ref()) {
+            headers.insert(header::CONTENT_TYPE, content_type);
+        }
+
+        response
+    }
+
+    fn status(status: StatusCode) -> Response<Full<Bytes>> {
+        let mut response = Response::new(Full::default());
+
+        *response.status_mut() = status;
+        response
+    }
+}
+
+// Functions
+
+fn relative(path: &str) -> Option<PathBuf> {
+    let mut relative = PathBuf::new();
+
+    for segment in path.split('/') {
+        let segment = decode(segment)?;
+
+        match segment.as_str() {
+            "" | "." => {}
+            ".." => return None,
+            _ if segment.contains(['/', '\\', '\0']) => return None,
+            _ => relative.push(segment),
+        }
+    }
+
+    Some(relative)
+}
+
+fn decode(segment: &str) -> Option<String> {
+    let mut bytes = segment.bytes();
+    let mut decoded = Vec::with_capacity(segment.len());
+
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'%' => {
+                let high = char::from(bytes.next()?).to_digit(16)?;
+                let low = char::from(bytes.next()?).to_digit(16)?;
+
+                decoded.push(u8::try_from(high * 16 + low).ok()?);
+            }
+            _ => decoded.push(byte),
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
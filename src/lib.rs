@@ -1,8 +1,4 @@
-#![feature(
-    derive_default_enum,
-    map_try_insert,
-    trait_alias
-)]
+#![feature(trait_alias)]
 #![deny(
     future_incompatible,
     missing_copy_implementations,
@@ -30,46 +26,336 @@
 )]
 #![allow( // TODO
     clippy::missing_errors_doc,
+    clippy::multiple_crate_versions,
     missing_docs,
     rustdoc::all
 )]
 
 mod internal;
 
+#[cfg(feature = "embed")]
+pub use memfs_macros::embed_dir;
+
 pub use internal::{
+    diff::diff,
     directory::Directory,
+    error::Error,
     file::File,
     file_system::FileSystem,
     node::Node,
 };
 
+#[cfg(feature = "proptest")]
+pub mod arbitrary {
+    pub use super::internal::arbitrary::{
+        file_system,
+        invalid_path,
+        tree,
+        valid_path,
+        Tree,
+    };
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    pub use super::internal::blocking::{
+        Directory,
+        File,
+        FileSystem,
+    };
+}
+
+#[cfg(feature = "tokio")]
+pub mod compat {
+    pub mod tokio_fs {
+        pub use super::super::internal::compat::tokio_fs::{
+            DirEntry,
+            Handle,
+            ReadDir,
+        };
+    }
+}
+
+pub mod diff {
+    pub use super::internal::diff::{
+        Change,
+        Diff,
+    };
+}
+
 pub mod directory {
+    #[cfg(feature = "zip")]
+    pub use super::internal::directory::zip::{
+        ExportZip,
+        ZipError,
+    };
+    #[cfg(feature = "im")]
+    pub use super::internal::directory::freeze::{
+        Freeze,
+        Frozen,
+    };
+    #[cfg(feature = "json")]
+    pub use super::internal::directory::json::{
+        JsonError,
+        ToJson,
+    };
+    #[cfg(feature = "snapshot")]
+    pub use super::internal::directory::snapshot::{
+        SaveSnapshot,
+        SnapshotError,
+    };
     pub use super::internal::directory::{
+        attach::{
+            Attach,
+            AttachError,
+        },
         count::Count,
+        create::{
+            Create,
+            CreatedDirs,
+        },
+        create_all::{
+            CreateAll,
+            CreateError,
+        },
+        entry::{
+            Entry,
+            OccupiedEntry,
+            VacantEntry,
+        },
+        filter::Filter,
+        find::Find,
+        fold::Fold,
         get::{
             Get,
             GetError,
+            GetErrorKind,
             GetType,
         },
+        get_clamped::GetClamped,
         get_ext::{
             GetDirectoryError,
             GetExt,
             GetFileError,
         },
+        get_many::GetMany,
+        list::List,
+        memory::{
+            MemoryUsage,
+            Usage,
+        },
+        merge::{
+            Conflict,
+            Merge,
+            MergeError,
+            Resolution,
+        },
+        probe::Probe,
+        rename::{
+            Rename,
+            RenameError,
+        },
+        render::Render,
+        retain::Retain,
+        scope::{
+            Scope,
+            Scoped,
+        },
+        search::Search,
+        segments::GetSegments,
+        size::Size,
+        stats::{
+            Statistics,
+            Stats,
+        },
+        transform::Transform,
+        visit::{
+            Flow,
+            Visit,
+        },
+        walk::Walk,
+    };
+}
+
+pub mod error {
+    pub use super::internal::error::{
+        ModifyError,
+        PersistError,
+        ResolveError,
+    };
+}
+
+pub mod file {
+    pub use super::internal::file::{
+        history::{
+            History,
+            RevertError,
+        },
+        len::Len,
+        lock::{
+            ExclusiveLock,
+            Lock,
+            SharedLock,
+        },
+        open::{
+            Open,
+            Reader,
+            Writer,
+        },
+        subscribe::{
+            Subscribe,
+            Subscription,
+        },
+    };
+}
+
+pub mod file_system {
+    #[cfg(feature = "tokio")]
+    pub use super::internal::file_system::disk::{
+        DiskError,
+        WriteMode,
+    };
+    #[cfg(any(feature = "toml", feature = "yaml"))]
+    pub use super::internal::file_system::fixture::FixtureError;
+    pub use super::internal::file_system::{
+        access::{
+            Action,
+            Decision,
+        },
+        audit::{
+            audited,
+            AuditEntry,
+            Audited,
+            Mutation,
+        },
+        backend::{
+            Backend,
+            BackendError,
+            Stored,
+        },
+        branch::{
+            Branch,
+            Diverged,
+            Version,
+        },
+        builder::{
+            BuildError,
+            Builder,
+        },
+        clock::{
+            Clock,
+            SystemClock,
+        },
+        hooks::Event,
+        iter::IntoIter,
+        journal::{
+            Record,
+            ReplayError,
+            Sink,
+        },
+        options::{
+            Options,
+            Order,
+        },
+        prefix::Listing,
+        undo::UndoError,
+        watch::{
+            Change,
+            Debounce,
+        },
+    };
+}
+
+pub mod fs {
+    pub use super::internal::fs::{
+        create_dir_all,
+        metadata,
+        read,
+        read_dir,
+        write,
+        DirEntry,
+        Metadata,
+    };
+}
+
+#[cfg(all(feature = "fuse", target_os = "linux"))]
+pub mod fuse {
+    pub use super::internal::fuse::{
+        mount,
+        Mount,
+    };
+}
+
+#[cfg(feature = "inodes")]
+pub mod inodes {
+    pub use super::internal::inodes::{
+        Attr,
+        DirEntry,
+        Inodes,
+    };
+}
+
+#[cfg(feature = "local")]
+pub mod local {
+    pub use super::internal::local::{
+        Directory,
+        File,
+        FileSystem,
+        Node,
     };
 }
 
 pub mod node {
+    #[cfg(feature = "bytes")]
+    pub use super::internal::node::chunks::ReadChunks;
     pub use super::internal::node::{
         child::Child,
+        content::{
+            Content,
+            ContentExt,
+        },
         data::{
             Data,
             Value,
             ValueType,
         },
-        data_ext::DataExt,
+        data_ext::{
+            DataExt,
+            WriteError,
+        },
+        data_len::DataLen,
+        depth::Depth,
+        descendants::Descendants,
+        digest::Digest,
+        identified::{
+            Identified,
+            NodeId,
+        },
         located::Located,
+        meta::Meta,
         named::Named,
+        parent::Parent,
+        permissions::Permissions,
         root::Root,
+        siblings::Siblings,
+        text::TextFileExt,
+        timestamps::Timestamps,
+        versioned::Versioned,
+        xattrs::Xattrs,
+        NodeError,
+        NodeKind,
+    };
+}
+
+pub mod path {
+    pub use super::internal::path::{
+        MemPath,
+        MemPathBuf,
+        MemPathError,
     };
 }
+
+#[cfg(feature = "tower")]
+pub mod service {
+    pub use super::internal::service::ServeDir;
+}
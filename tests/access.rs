@@ -0,0 +1,127 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Attach,
+        AttachError,
+        Entry,
+        GetErrorKind,
+        GetExt,
+        GetFileError,
+        Probe,
+        Rename,
+        RenameError,
+    },
+    file_system::{
+        Action,
+        Decision,
+    },
+    node::{
+        DataExt,
+        WriteError,
+    },
+    Directory,
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn intercept() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+
+    fs.get_file_default("/public/a").await?;
+    fs.get_file_default("/private/b").await?;
+    fs.intercept(|action, path| async move {
+        match (action, path.starts_with("/private")) {
+            (Action::Write, _) if path.ends_with("a") => Decision::Deny,
+            (_, true) => Decision::Deny,
+            _ => Decision::Allow,
+        }
+    });
+
+    assert!(fs.get_file("/public/a").await?.is_some());
+    assert!(matches!(
+        fs.get_file("/private/b").await,
+        Err(GetFileError::Get(err)) if err.kind() == GetErrorKind::AccessDenied
+    ));
+    assert!(matches!(
+        fs.get_file_default("/private/c").await,
+        Err(GetFileError::Get(err)) if err.kind() == GetErrorKind::AccessDenied
+    ));
+    assert!(matches!(
+        fs.get_file_default("/public/a")
+            .await?
+            .write(|mut value| *value = 1)
+            .await,
+        Err(WriteError::AccessDenied)
+    ));
+    assert!(matches!(
+        fs.detach("private").await,
+        Err(WriteError::AccessDenied)
+    ));
+    assert!(fs.detach("public").await?.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn intercept_structural() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+
+    fs.get_file_default("/locked/secret").await?;
+    fs.get_dir_default("/open").await?;
+    fs.intercept(|action, path| async move {
+        match (action, path.starts_with("/locked")) {
+            (Action::Remove, true) => Decision::Deny,
+            _ => Decision::Allow,
+        }
+    });
+
+    assert!(matches!(
+        fs.rename("/locked/secret", "/open/stolen").await,
+        Err(RenameError::Write(WriteError::AccessDenied))
+    ));
+
+    let locked = fs.get_dir("/locked").await?.expect("directory");
+    let open = fs.get_dir("/open").await?.expect("directory");
+    let secret = fs.get_file("/locked/secret").await?.expect("file");
+
+    assert!(matches!(
+        open.attach("stolen", Node::File(secret)).await,
+        Err(AttachError::Write(WriteError::AccessDenied))
+    ));
+
+    if let Entry::Occupied(entry) = locked.entry("secret").await {
+        assert!(matches!(entry.remove().await, Err(WriteError::AccessDenied)));
+    }
+
+    assert!(fs.exists("/locked/secret").await);
+    assert!(!fs.exists("/open/stolen").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn intercept_reentrant() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let root = Directory::clone(&fs);
+    let dir = fs.get_dir_default("/a").await?;
+
+    fs.get_file_default("/a/b").await?;
+    fs.intercept(move |action, _| {
+        let root = root.clone();
+
+        async move {
+            match action {
+                Action::Create if root.exists("/a/b").await => Decision::Allow,
+                Action::Create => Decision::Deny,
+                _ => Decision::Allow,
+            }
+        }
+    });
+
+    dir.entry("c").await.or_insert_file(1).await?;
+
+    assert!(fs.exists("/a/c").await);
+
+    Ok(())
+}
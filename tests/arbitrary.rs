@@ -0,0 +1,41 @@
+#![cfg(feature = "proptest")]
+
+use std::path::PathBuf;
+
+use memfs::{
+    arbitrary::{
+        self,
+        Tree,
+    },
+    directory::Probe,
+    FileSystem,
+};
+use proptest::prelude::*;
+
+fn tree_with_paths() -> impl Strategy<Value = (Tree<u8>, PathBuf, PathBuf)> {
+    arbitrary::tree(any::<u8>(), 3, 4).prop_flat_map(|tree| {
+        let valid = arbitrary::valid_path(&tree);
+        let invalid = arbitrary::invalid_path(&tree);
+
+        (Just(tree), valid, invalid)
+    })
+}
+
+proptest! {
+    #[test]
+    fn valid_paths_exist((tree, valid, _) in tree_with_paths()) {
+        let fs: FileSystem<(), u8> = tree.build();
+        prop_assert!(futures::executor::block_on(fs.exists(&valid)));
+    }
+
+    #[test]
+    fn invalid_paths_do_not_exist((tree, _, invalid) in tree_with_paths()) {
+        let fs: FileSystem<(), u8> = tree.build();
+        prop_assert!(!futures::executor::block_on(fs.exists(&invalid)));
+    }
+
+    #[test]
+    fn file_systems_are_generated(fs in arbitrary::file_system::<(), u8, _>(any::<u8>(), 2, 3)) {
+        prop_assert!(futures::executor::block_on(fs.exists("/")));
+    }
+}
@@ -0,0 +1,124 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Attach,
+        AttachError,
+        Count,
+        GetExt,
+        Probe,
+    },
+    node::{
+        DataExt,
+        Located,
+    },
+    File,
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn attach_detached() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+
+    fs.get_file_default("/a/b")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+
+    let node = fs.get_dir_default("/a").await?.detach("b").await?;
+
+    assert!(!fs.exists("/a/b").await);
+
+    if let Some(node) = node {
+        assert!(fs
+            .get_dir_default("/c")
+            .await?
+            .attach("d", node)
+            .await?
+            .is_none());
+    }
+
+    let file = fs.get_file_default("/c/d").await?;
+
+    assert_eq!(file.read(|value| *value).await, 1);
+    assert_eq!(file.path().await.to_str(), Some("/c/d"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attach_moves_attached() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+    let dir = fs.get_dir_default("/a/b").await?;
+
+    fs.get_file_default("/a/b/c").await?;
+    fs.get_dir_default("/d")
+        .await?
+        .attach("e", Node::Directory(dir.clone()))
+        .await?;
+
+    assert!(!fs.exists("/a/b").await);
+    assert!(fs.exists("/d/e/c").await);
+    assert_eq!(dir.path().await.to_str(), Some("/d/e"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attach_replaces() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+    let file = fs.get_file_default("/a").await?;
+
+    fs.get_dir_default("/b").await?;
+
+    let replaced = fs.attach("b", Node::File(file)).await?;
+
+    assert!(matches!(replaced, Some(Node::Directory(_))));
+    assert!(fs.get_file("/b").await?.is_some());
+    assert!(!fs.exists("/a").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attach_rejects_cycle() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+    let a = fs.get_dir_default("/a").await?;
+    let b = fs.get_dir_default("/a/b").await?;
+
+    assert!(matches!(
+        b.attach("c", Node::Directory(a.clone())).await,
+        Err(AttachError::Cycle)
+    ));
+    assert!(matches!(
+        a.attach("c", Node::Directory(a.clone())).await,
+        Err(AttachError::Cycle)
+    ));
+    assert!(fs.exists("/a/b").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attach_invalid_name() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+    let dir = fs.get_dir_default("/a").await?;
+
+    for name in ["", ".", "..", "a/b", "/b", "b/"] {
+        let file = fs.get_file_default("/f").await?;
+
+        assert!(matches!(
+            dir.attach(name, Node::File(file)).await,
+            Err(AttachError::InvalidName)
+        ));
+        assert!(matches!(
+            dir.insert(name, Node::File(File::new(0))).await,
+            Err(AttachError::InvalidName)
+        ));
+    }
+
+    assert!(fs.exists("/f").await);
+    assert_eq!(dir.count().await?, 0);
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use anyhow::Result;
+use futures::AsyncWriteExt;
+use memfs::{
+    directory::{
+        GetExt,
+        Rename,
+        Resolution,
+    },
+    file_system::{
+        audited,
+        Mutation,
+        Options,
+        Stored,
+    },
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn audit_log() -> Result<()> {
+    let fs = FileSystem::<(), u32>::with_options(Options::default().audit(3));
+
+    let file = fs.get_file_default("/a/b").await?;
+    let alice = audited("alice");
+
+    alice.write(&file, |mut value| *value = 1).await?;
+    alice.detach(&fs.get_dir_default("/a").await?, "b").await?;
+
+    let log = fs
+        .audit_log()
+        .into_iter()
+        .map(|entry| (entry.context, entry.mutation, entry.path))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        log,
+        [
+            (None, Mutation::Create, PathBuf::from("/a/b")),
+            (Some("alice".into()), Mutation::Write, PathBuf::from("/a/b")),
+            (Some("alice".into()), Mutation::Remove, PathBuf::from("/a/b")),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn audit_create() -> Result<()> {
+    let fs = FileSystem::<(), ()>::with_options(Options::default().audit(16));
+    let alice = audited("alice");
+
+    alice.create_dir(&fs, "/a", ()).await?;
+    alice.create_file(&fs, "/a/b", ()).await?;
+    alice.create_dir_all(&fs, "/c/d").await?;
+    alice
+        .create_all(&fs, [(PathBuf::from("/e"), Stored::File(()))], || ())
+        .await?;
+    alice.or_insert_file(fs.entry("f").await, ()).await?;
+
+    let log = fs
+        .audit_log()
+        .into_iter()
+        .map(|entry| (entry.context, entry.mutation, entry.path))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        log,
+        ["/a", "/a/b", "/c", "/c/d", "/e", "/f"].map(|path| (
+            Some("alice".into()),
+            Mutation::Create,
+            PathBuf::from(path)
+        ))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn audit_metadata() -> Result<()> {
+    let fs = FileSystem::<(), ()>::with_options(Options::default().audit(16));
+    let node = Node::File(fs.get_file_default("/a").await?);
+    let bob = audited("bob");
+
+    bob.set_xattr(&node, "user.a", b"a".to_vec()).await?;
+    bob.remove_xattr(&node, "user.a").await?;
+    bob.set_meta(&node, 1_u32).await;
+    bob.remove_meta::<_, _, u32>(&node).await;
+    bob.set_readonly(&node, true).await;
+
+    let log = fs
+        .audit_log()
+        .into_iter()
+        .skip(1)
+        .map(|entry| (entry.context, entry.mutation))
+        .collect::<Vec<_>>();
+
+    assert_eq!(log.len(), 5);
+    assert!(
+        log.iter()
+            .all(|entry| *entry == (Some("bob".into()), Mutation::Metadata))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn audit_merge_retain_writer() -> Result<()> {
+    let fs = FileSystem::<(), Vec<u8>>::with_options(Options::default().audit(16));
+    let other = FileSystem::<(), Vec<u8>>::new();
+    let carol = audited("carol");
+
+    fs.get_file_default("/a").await?;
+    other.get_file_default("/b").await?;
+
+    carol
+        .merge_from(&fs, &other, |_, _| Resolution::Theirs)
+        .await?;
+    carol.retain(&fs, |path, _| path != Path::new("/a")).await;
+
+    let mut writer = carol.open_writer(&fs.get_file_default("/b").await?).await?;
+
+    writer.write_all(b"b").await?;
+    writer.close().await?;
+
+    let log = fs
+        .audit_log()
+        .into_iter()
+        .skip(1)
+        .map(|entry| (entry.context, entry.mutation, entry.path))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        log,
+        [
+            (Some("carol".into()), Mutation::Create, PathBuf::from("/b")),
+            (Some("carol".into()), Mutation::Remove, PathBuf::from("/a")),
+            (Some("carol".into()), Mutation::Write, PathBuf::from("/b")),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn audit_rename() -> Result<()> {
+    let fs = FileSystem::<(), ()>::with_options(Options::default().audit(8));
+
+    fs.get_file_default("/a/b").await?;
+    fs.get_dir_default("/c").await?;
+    fs.rename("/a/b", "/a/d").await?;
+    audited("bob").rename(&fs, "/a/d", "/c/d").await?;
+
+    let log = fs
+        .audit_log()
+        .into_iter()
+        .filter(|entry| entry.mutation == Mutation::Rename)
+        .map(|entry| (entry.context, entry.from, entry.path))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        log,
+        [
+            (None, Some(PathBuf::from("/a/b")), PathBuf::from("/a/d")),
+            (Some("bob".into()), Some(PathBuf::from("/a/d")), PathBuf::from("/c/d")),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn audit_spawn() -> Result<()> {
+    let fs = FileSystem::<(), u32>::with_options(Options::default().audit(8));
+    let file = fs.get_file_default("/a").await?;
+    let alice = audited("alice");
+
+    tokio::spawn(async move { alice.write(&file, |mut value| *value = 1).await }).await??;
+
+    let last = fs.audit_log().pop().unwrap();
+
+    assert_eq!(last.context.as_deref(), Some("alice"));
+    assert_eq!(last.mutation, Mutation::Write);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn audit_sink() -> Result<()> {
+    let entries = Arc::new(Mutex::new(Vec::new()));
+    let sink = entries.clone();
+    let fs = FileSystem::<(), ()>::with_options(
+        Options::default().on_audit(move |entry| sink.lock().unwrap().push(entry.clone())),
+    );
+
+    fs.get_dir_default("/a").await?;
+
+    assert!(fs.audit_log().is_empty());
+    assert_eq!(entries.lock().unwrap().len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Entry,
+        GetExt,
+        Probe,
+        Rename,
+    },
+    file_system::{
+        Backend,
+        Options,
+        Stored,
+    },
+    node::{
+        DataExt,
+        Permissions,
+    },
+    FileSystem,
+};
+
+type Memory = Arc<Mutex<BTreeMap<PathBuf, (Stored<u32, u32>, bool)>>>;
+
+struct Failing;
+
+impl Backend<u32, u32> for Failing {
+    fn list(&self, _: &Path) -> io::Result<Vec<OsString>> {
+        Ok(Vec::new())
+    }
+
+    fn load(&self, _: &Path) -> io::Result<Option<Stored<u32, u32>>> {
+        Ok(None)
+    }
+
+    fn readonly(&self, _: &Path) -> io::Result<bool> {
+        Ok(false)
+    }
+
+    fn remove(&self, _: &Path) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn set_readonly(&self, _: &Path, _: bool) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn store(&self, _: &Path, _: Stored<u32, u32>) -> io::Result<()> {
+        Err(io::ErrorKind::StorageFull.into())
+    }
+}
+
+#[tokio::test]
+async fn backend_store() -> Result<()> {
+    let backend = Memory::default();
+    let fs = FileSystem::with_backend(Options::default(), backend.clone()).await?;
+
+    fs.get_file_default("/a/b")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+    fs.get_file_default("/a/c/d").await?;
+    fs.rename("/a/c", "/e").await?;
+
+    if let Entry::Occupied(entry) = fs.entry("e").await {
+        entry.remove().await?;
+    }
+
+    assert_eq!(backend.load("/a".as_ref())?, Some(Stored::Directory(0)));
+    assert_eq!(backend.load("/a/b".as_ref())?, Some(Stored::File(1)));
+    assert_eq!(backend.load("/a/c/d".as_ref())?, None);
+    assert_eq!(backend.load("/e/d".as_ref())?, None);
+    assert_eq!(backend.list("/".as_ref())?, vec!["a"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn backend_load() -> Result<()> {
+    let backend = Memory::default();
+
+    backend.store("/".as_ref(), Stored::Directory(1))?;
+    backend.store("/a".as_ref(), Stored::Directory(2))?;
+    backend.store("/a/b".as_ref(), Stored::File(3))?;
+
+    let fs = FileSystem::with_backend(Options::default(), backend.clone()).await?;
+
+    assert_eq!(fs.read(|value| *value).await, 1);
+    assert_eq!(
+        fs.get_dir_default("/a").await?.read(|value| *value).await,
+        2
+    );
+    assert_eq!(
+        fs.get_file_default("/a/b")
+            .await?
+            .read(|value| *value)
+            .await,
+        3
+    );
+    assert!(!fs.exists("/c").await);
+    assert_eq!(backend.list("/a".as_ref())?, vec!["b"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn backend_readonly() -> Result<()> {
+    let backend = Memory::default();
+    let fs = FileSystem::with_backend(Options::default(), backend.clone()).await?;
+
+    fs.get_file_default("/a/b").await?;
+    fs.get_dir_default("/a").await?.set_readonly(true).await;
+    fs.rename("/a", "/c").await?;
+
+    assert!(backend.readonly("/c".as_ref())?);
+    assert!(!backend.readonly("/c/b".as_ref())?);
+
+    let fs = FileSystem::with_backend(Options::default(), backend.clone()).await?;
+    let c = fs.get_dir_default("/c").await?;
+
+    assert!(c.readonly().await);
+    assert!(fs.exists("/c/b").await);
+    assert!(!fs.get_file_default("/c/b").await?.readonly().await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn backend_flush() -> Result<()> {
+    let fs = FileSystem::with_backend(Options::default(), Failing).await?;
+
+    fs.flush()?;
+    fs.get_file_default("/a").await?;
+
+    assert_eq!(
+        fs.flush().map_err(|err| err.kind()),
+        Err(io::ErrorKind::StorageFull)
+    );
+
+    fs.flush()?;
+
+    Ok(())
+}
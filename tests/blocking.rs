@@ -0,0 +1,22 @@
+#![cfg(feature = "blocking")]
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use memfs::blocking::FileSystem;
+
+#[test]
+fn blocking() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+    let file = fs.get_file_default("/a/b")?;
+
+    file.write(|mut value| *value = 1)?;
+
+    assert!(fs.is_root());
+    assert_eq!(fs.count()?, 1);
+    assert_eq!(file.read(|value| *value), 1);
+    assert_eq!(file.path(), PathBuf::from("/a/b"));
+    assert!(fs.get_dir("/a")?.is_some());
+
+    Ok(())
+}
@@ -0,0 +1,278 @@
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Attach,
+        GetErrorKind,
+        GetExt,
+        GetFileError,
+        MergeError,
+        Resolution,
+    },
+    file_system::{
+        Options,
+        Version,
+    },
+    node::{
+        DataExt,
+        Permissions,
+    },
+    FileSystem,
+};
+
+async fn read(fs: &FileSystem<(), String>, path: &str) -> Result<Option<String>> {
+    Ok(match fs.get_file(path).await? {
+        Some(file) => Some(file.read(|value| value.clone()).await),
+        _ => None,
+    })
+}
+
+#[tokio::test]
+async fn branch() -> Result<()> {
+    let fs = FileSystem::<(), String>::new();
+
+    for path in ["/a", "/b", "/c", "/d/e"] {
+        fs.get_file_default(path)
+            .await?
+            .write(|mut value| *value = String::from("base"))
+            .await?;
+    }
+
+    let branch = fs.branch("experiment").await?;
+
+    assert_eq!(branch.name(), "experiment");
+
+    fs.get_file_default("/a")
+        .await?
+        .write(|mut value| *value = String::from("ours"))
+        .await?;
+    fs.get_file_default("/c")
+        .await?
+        .write(|mut value| *value = String::from("ours"))
+        .await?;
+
+    branch
+        .get_file_default("/b")
+        .await?
+        .write(|mut value| *value = String::from("theirs"))
+        .await?;
+    branch
+        .get_file_default("/c")
+        .await?
+        .write(|mut value| *value = String::from("theirs"))
+        .await?;
+    branch
+        .get_file_default("/f/g")
+        .await?
+        .write(|mut value| *value = String::from("theirs"))
+        .await?;
+    branch.detach("d").await?;
+
+    assert_eq!(read(&fs, "/b").await?.as_deref(), Some("base"));
+    assert_eq!(read(&branch, "/a").await?.as_deref(), Some("base"));
+
+    let conflicts = Mutex::new(Vec::new());
+
+    fs.merge_branch(&branch, |path, diverged| {
+        assert!(matches!(diverged.base, Version::File(base) if base == "base"));
+
+        match (diverged.ours, diverged.theirs) {
+            (Version::File(ours), Version::File(theirs)) => {
+                conflicts.lock().unwrap().push(path.to_path_buf());
+
+                Resolution::Combined(format!("{ours}+{theirs}"))
+            }
+            _ => Resolution::Ours,
+        }
+    })
+    .await?;
+
+    assert_eq!(*conflicts.lock().unwrap(), [Path::new("/c")]);
+    assert_eq!(read(&fs, "/a").await?.as_deref(), Some("ours"));
+    assert_eq!(read(&fs, "/b").await?.as_deref(), Some("theirs"));
+    assert_eq!(read(&fs, "/c").await?.as_deref(), Some("ours+theirs"));
+    assert_eq!(read(&fs, "/f/g").await?.as_deref(), Some("theirs"));
+    assert!(fs.get_dir("/d").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn branch_removed_subtree() -> Result<()> {
+    for (resolution, expected) in [(Resolution::Ours, Some("ours")), (Resolution::Theirs, None)] {
+        let fs = FileSystem::<(), String>::new();
+
+        for path in ["/d/e", "/d/f"] {
+            fs.get_file_or_create_with(path, || String::from("base"))
+                .await?;
+        }
+
+        let branch = fs.branch("experiment").await?;
+
+        fs.get_file_default("/d/e")
+            .await?
+            .write(|mut value| *value = String::from("ours"))
+            .await?;
+        branch.detach("d").await?;
+
+        let conflicts = Mutex::new(Vec::new());
+        let resolution = Mutex::new(Some(resolution));
+
+        fs.merge_branch(&branch, |path, diverged| {
+            assert!(matches!(diverged.ours, Version::Directory(())));
+            assert!(matches!(diverged.theirs, Version::Missing));
+
+            conflicts.lock().unwrap().push(path.to_path_buf());
+            resolution.lock().unwrap().take().unwrap()
+        })
+        .await?;
+
+        assert_eq!(*conflicts.lock().unwrap(), [Path::new("/d")]);
+        assert_eq!(read(&fs, "/d/e").await?.as_deref(), expected);
+        assert_eq!(
+            read(&fs, "/d/f").await?.as_deref(),
+            expected.map(|_| "base")
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn branch_merged_twice() -> Result<()> {
+    let fs = FileSystem::<(), String>::new();
+
+    fs.get_file_default("/a").await?;
+
+    let branch = fs.branch("experiment").await?;
+
+    branch
+        .get_file_default("/a")
+        .await?
+        .write(|mut value| *value = String::from("theirs"))
+        .await?;
+
+    fs.merge_branch(&branch, |path, _| panic!("unexpected conflict at {path:?}"))
+        .await?;
+    fs.get_file_default("/a")
+        .await?
+        .write(|mut value| *value = String::from("ours"))
+        .await?;
+    fs.merge_branch(&branch, |path, _| panic!("unexpected conflict at {path:?}"))
+        .await?;
+
+    assert_eq!(read(&fs, "/a").await?.as_deref(), Some("ours"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn branch_directory_values() -> Result<()> {
+    let fs = FileSystem::<String, String>::new();
+
+    fs.get_dir_default("/d").await?;
+
+    let branch = fs.branch("experiment").await?;
+
+    branch
+        .write(|mut value| *value = String::from("root"))
+        .await?;
+    branch
+        .get_dir_default("/d")
+        .await?
+        .write(|mut value| *value = String::from("theirs"))
+        .await?;
+
+    fs.merge_branch(&branch, |_, _| Resolution::Ours).await?;
+
+    assert_eq!(fs.read(|value| value.clone()).await, "root");
+    assert_eq!(
+        fs.get_dir_default("/d")
+            .await?
+            .read(|value| value.clone())
+            .await,
+        "theirs"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn branch_combined_directory() -> Result<()> {
+    let fs = FileSystem::<(), String>::new();
+
+    fs.get_file_default("/a").await?;
+
+    let branch = fs.branch("experiment").await?;
+
+    fs.get_file_default("/a")
+        .await?
+        .write(|mut value| *value = String::from("ours"))
+        .await?;
+    branch.detach("a").await?;
+    branch.get_file_default("/a/b").await?;
+
+    let result = fs
+        .merge_branch(&branch, |_, _| Resolution::Combined(String::from("both")))
+        .await;
+
+    assert!(matches!(result, Err(MergeError::Combined(path)) if path == Path::new("/a")));
+    assert_eq!(read(&fs, "/a").await?.as_deref(), Some("ours"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn branch_options() -> Result<()> {
+    let fs = FileSystem::<(), String>::with_options(Options::default().max_nodes(2));
+
+    fs.get_file_default("/a").await?.set_readonly(true).await;
+
+    let branch = fs.branch("experiment").await?;
+    let file = branch.get_file_default("/a").await?;
+
+    assert!(file.readonly().await);
+    assert!(matches!(
+        branch.get_file_default("/b/c").await,
+        Err(GetFileError::Get(err)) if err.kind() == GetErrorKind::QuotaExceeded
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn branch_audit() -> Result<()> {
+    let entries = Arc::new(Mutex::new(Vec::new()));
+    let sink = entries.clone();
+    let fs = FileSystem::<(), String>::with_options(
+        Options::default()
+            .audit(8)
+            .on_audit(move |entry| sink.lock().unwrap().push(entry.path.clone())),
+    );
+
+    fs.get_file_default("/a/b").await?;
+
+    let branch = fs.branch("experiment").await?;
+
+    assert!(branch.audit_log().is_empty());
+
+    branch.get_file_default("/c").await?;
+
+    assert_eq!(
+        *entries.lock().unwrap(),
+        ["/a", "/a/b", "/c"].map(PathBuf::from)
+    );
+    assert_eq!(branch.audit_log().len(), 1);
+
+    Ok(())
+}
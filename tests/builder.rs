@@ -0,0 +1,57 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetDirectoryError,
+        GetExt,
+    },
+    file_system::{
+        BuildError,
+        Options,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn builder() -> Result<()> {
+    let fs: FileSystem<u32, u32> = FileSystem::builder()
+        .with_root_value(1)
+        .dir("/a/b")
+        .file("/a/c", 2)
+        .file("/d", 3)
+        .build()
+        .await?;
+
+    assert_eq!(fs.read(|value| *value).await, 1);
+    assert_eq!(fs.count().await?, 2);
+    assert!(fs.get_dir("/a/b").await?.is_some());
+    assert_eq!(
+        fs.get_file_default("/a/c")
+            .await?
+            .read(|value| *value)
+            .await,
+        2
+    );
+    assert_eq!(
+        fs.get_file_default("/d").await?.read(|value| *value).await,
+        3
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn builder_conflict() {
+    let result: Result<FileSystem<(), u32>, _> = FileSystem::builder()
+        .options(Options::default().max_depth(4))
+        .file("/a", 1)
+        .dir("/a")
+        .build()
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(BuildError::GetDirectory(GetDirectoryError::UnexpectedFile))
+    ));
+}
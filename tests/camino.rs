@@ -0,0 +1,48 @@
+#![cfg(feature = "camino")]
+
+use anyhow::Result;
+use camino::{
+    Utf8Path,
+    Utf8PathBuf,
+};
+use memfs::{
+    directory::GetExt,
+    node::Located,
+    path::MemPathBuf,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn utf8_path() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let file = fs.get_file_default(Utf8Path::new("/a/b")).await?;
+
+    assert_eq!(file.utf8_path().await, Some(Utf8PathBuf::from("/a/b")));
+    assert_eq!(fs.utf8_path().await, Some(Utf8PathBuf::from("/")));
+
+    let path = MemPathBuf::try_from(Utf8Path::new("/a/./c/../b"))?;
+
+    assert_eq!(Utf8PathBuf::from(path.clone()), "/a/b");
+    assert!(fs.get_file(AsRef::<Utf8Path>::as_ref(&path)).await?.is_some());
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn utf8_path_invalid() -> Result<()> {
+    use std::{
+        ffi::OsStr,
+        os::unix::ffi::OsStrExt,
+        path::Path,
+    };
+
+    let fs = FileSystem::<(), u32>::new();
+    let file = fs
+        .get_file_default(Path::new(OsStr::from_bytes(b"/\xff")))
+        .await?;
+
+    assert_eq!(file.utf8_path().await, None);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use std::ffi::OsString;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+    },
+    file_system::Options,
+    node::Named,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn case_insensitive() -> Result<()> {
+    let fs: FileSystem<(), ()> =
+        FileSystem::with_options(Options::default().case_insensitive(true));
+    let file = fs.get_file_default("/Dir/README.md").await?;
+
+    assert!(fs
+        .get_file("/dir/readme.MD")
+        .await?
+        .is_some_and(|found| found == file));
+    assert!(fs.get_file_default("/DIR/Readme.md").await? == file);
+    assert_eq!(fs.count().await?, 1);
+    assert_eq!(file.name().await, Some(OsString::from("README.md")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn case_sensitive() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::new();
+
+    fs.get_file_default("/README.md").await?;
+
+    assert!(fs.get_file("/readme.md").await?.is_none());
+
+    Ok(())
+}
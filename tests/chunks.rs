@@ -0,0 +1,42 @@
+#![cfg(feature = "bytes")]
+
+use anyhow::Result;
+use bytes::Bytes;
+use futures::StreamExt;
+use memfs::{
+    directory::GetExt,
+    node::{
+        DataExt,
+        ReadChunks,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn read_chunks() -> Result<()> {
+    let fs = FileSystem::<u32, Vec<u8>>::default();
+    let file = fs.get_file_default("/a").await?;
+
+    file.write(|mut value| *value = b"abcdefg".to_vec()).await?;
+
+    assert_eq!(
+        file.read_chunks(3).collect::<Vec<_>>().await,
+        vec![
+            Bytes::from_static(b"abc"),
+            Bytes::from_static(b"def"),
+            Bytes::from_static(b"g"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_chunks_empty() -> Result<()> {
+    let fs = FileSystem::<u32, Bytes>::default();
+    let file = fs.get_file_default("/a").await?;
+
+    assert!(file.read_chunks(0).collect::<Vec<_>>().await.is_empty());
+
+    Ok(())
+}
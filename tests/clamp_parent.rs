@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Get,
+        GetClamped,
+        GetErrorKind,
+        GetExt,
+        GetSegments,
+        GetType,
+    },
+    file_system::Options,
+    node::Located,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn clamp_parent() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::with_options(Options::default().clamp_parent(true));
+
+    fs.get_file_default("/a/b").await?;
+
+    assert!(fs.get_file("/../a/b").await?.is_some());
+    assert!(fs.get_file("/a/../../../a/b").await?.is_some());
+    assert_eq!(fs.get_dir("..").await?.expect("directory").path().await, Path::new("/"));
+    assert!(fs.get_segments(["..", "a", "b"]).await?.is_some());
+
+    assert!(matches!(
+        fs.get_clamped("/../a", false).await,
+        Err(err) if err.kind() == GetErrorKind::UnexpectedOrphan
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn clamp_parent_per_call() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::new();
+
+    fs.get_file_default("/a/b").await?;
+
+    assert!(matches!(
+        fs.get("/../a", GetType::Directory).await,
+        Err(err) if err.kind() == GetErrorKind::UnexpectedOrphan
+    ));
+    assert!(fs.get_clamped("/../../a/b", true).await?.is_some());
+    assert!(fs.get_clamped("/a/c", true).await?.is_none());
+
+    let a = fs.get_dir("/a").await?.expect("directory");
+
+    assert!(a.get_clamped("../../../a/b", true).await?.is_some());
+    assert!(a.get_clamped("../../a/b", false).await.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn compare_and_swap() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+    let file = fs.get_file_default("/a").await?;
+
+    assert!(file.compare_and_swap(&0, 1).await?);
+    assert_eq!(file.read(|value| *value).await, 1);
+
+    assert!(!file.compare_and_swap(&0, 2).await?);
+    assert_eq!(file.read(|value| *value).await, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn compare_and_swap_concurrent() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+    let file = fs.get_file_default("/a").await?;
+
+    let (a, b) = tokio::join!(file.compare_and_swap(&0, 1), file.compare_and_swap(&0, 2));
+
+    assert!(a? ^ b?);
+
+    Ok(())
+}
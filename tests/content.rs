@@ -0,0 +1,47 @@
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    node::{
+        ContentExt,
+        DataExt,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn content_bytes() -> Result<()> {
+    let fs = FileSystem::<u32, Vec<u8>>::default();
+    let file = fs.get_file_default("/a").await?;
+
+    file.append(b"hello").await?;
+    file.append(vec![b'!']).await?;
+
+    assert_eq!(file.read(|value| value.clone()).await, b"hello!");
+
+    file.truncate(4).await?;
+
+    assert_eq!(file.read(|value| value.clone()).await, b"hell");
+
+    file.clear().await?;
+
+    assert!(file.read(|value| value.is_empty()).await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn content_string() -> Result<()> {
+    let fs = FileSystem::<u32, String>::default();
+    let file = fs.get_file_default("/a").await?;
+
+    file.append("héllo").await?;
+    file.truncate(2).await?;
+
+    assert_eq!(file.read(|value| value.clone()).await, "h");
+
+    file.truncate(10).await?;
+
+    assert_eq!(file.read(|value| value.clone()).await, "h");
+
+    Ok(())
+}
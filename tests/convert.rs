@@ -0,0 +1,50 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn convert_from_iterator() -> Result<()> {
+    let fs = [
+        (PathBuf::from("/a/b/one"), 1),
+        (PathBuf::from("/a/two"), 2),
+        (PathBuf::from("/a/two"), 3),
+        (PathBuf::from("/a/two/invalid"), 4),
+    ]
+    .into_iter()
+    .collect::<FileSystem<(), u32>>();
+
+    let one = fs.get_file_default("/a/b/one").await?;
+    let two = fs.get_file_default("/a/two").await?;
+
+    assert_eq!(fs.count().await?, 1);
+    assert_eq!(one.read(|value| *value).await, 1);
+    assert_eq!(two.read(|value| *value).await, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn convert_map_round_trip() -> Result<()> {
+    let files = HashMap::from([
+        (PathBuf::from("/config/app"), String::from("debug = true")),
+        (PathBuf::from("/readme"), String::from("hello")),
+    ]);
+
+    let fs = FileSystem::<(), String>::from(files.clone());
+
+    assert!(fs.get_dir("/config").await?.is_some());
+    assert_eq!(fs.to_map().await, files);
+
+    Ok(())
+}
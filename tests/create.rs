@@ -0,0 +1,137 @@
+use std::path::Path;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Create,
+        CreateError,
+        GetErrorKind,
+        GetExt,
+    },
+    file_system::{
+        Action,
+        Decision,
+        Options,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn create() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    let dir = fs.create_dir("/a", 1).await?;
+    let file = fs.create_file("/a/b", 2).await?;
+
+    assert_eq!(dir.read(|value| *value).await, 1);
+    assert_eq!(file.read(|value| *value).await, 2);
+    assert!(fs
+        .get_file("/a/b")
+        .await?
+        .is_some_and(|found| found == file));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_already_exists() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.get_file_default("/a/b").await?;
+
+    assert!(matches!(
+        fs.create_dir("/a", 1).await,
+        Err(CreateError::AlreadyExists(_))
+    ));
+    assert!(matches!(
+        fs.create_file("/a/b", 1).await,
+        Err(CreateError::AlreadyExists(_))
+    ));
+    assert_eq!(
+        fs.get_file_default("/a/b")
+            .await?
+            .read(|value| *value)
+            .await,
+        0
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_intermediate_not_found() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    assert!(matches!(
+        fs.create_file("/a/b", 1).await,
+        Err(CreateError::IntermediateNotFound(_))
+    ));
+    assert!(fs.get_dir("/a").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_dir_all() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.get_dir_default("/a").await?;
+
+    let dirs = fs.create_dir_all("/a/b/c").await?;
+
+    assert_eq!(dirs.created, [Path::new("/a/b"), Path::new("/a/b/c")]);
+    assert_eq!(dirs.existing, [Path::new("/a")]);
+    assert!(fs
+        .get_dir("/a/b/c")
+        .await?
+        .is_some_and(|dir| dir == dirs.dir));
+
+    fs.get_file_default("/a/d").await?;
+
+    assert!(matches!(
+        fs.create_dir_all("/a/d/e").await,
+        Err(CreateError::UnexpectedFile(path)) if path == Path::new("/a/d")
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_dir_all_limits() -> Result<()> {
+    let check = |result: Result<_, CreateError>, kind| {
+        matches!(result, Err(CreateError::Get(err)) if err.kind() == kind)
+    };
+
+    let fs = FileSystem::<u32, u32>::with_options(Options::default().max_components(3));
+
+    assert!(check(
+        fs.create_dir_all("/a/b/c").await,
+        GetErrorKind::ComponentLimitExceeded
+    ));
+    assert!(fs.get_dir("/a").await?.is_none());
+
+    let fs = FileSystem::<u32, u32>::with_options(Options::default().max_traversal(2));
+
+    assert!(check(
+        fs.create_dir_all("/a/b/c").await,
+        GetErrorKind::TraversalLimitExceeded
+    ));
+    assert!(fs.get_dir("/a/b/c").await.is_err());
+
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.intercept(|action, path| async move {
+        match (action, path.ends_with("hidden")) {
+            (Action::Get, true) => Decision::Deny,
+            _ => Decision::Allow,
+        }
+    });
+
+    assert!(check(
+        fs.create_dir_all("/a/hidden").await,
+        GetErrorKind::AccessDenied
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,286 @@
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        CreateAll,
+        CreateError,
+        GetExt,
+    },
+    file_system::{
+        Action,
+        Decision,
+        Options,
+        Stored,
+    },
+    node::{
+        DataExt,
+        Permissions,
+        WriteError,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn create_all() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.create_all(
+        [
+            (PathBuf::from("/a/b/c"), Stored::File(1)),
+            (PathBuf::from("/a/b"), Stored::Directory(2)),
+            (PathBuf::from("/d"), Stored::Directory(3)),
+        ],
+        u32::default,
+    )
+    .await?;
+
+    assert_eq!(fs.get_file_default("/a/b/c").await?.read(|v| *v).await, 1);
+    assert_eq!(fs.get_dir_default("/a/b").await?.read(|v| *v).await, 2);
+    assert_eq!(fs.get_dir_default("/d").await?.read(|v| *v).await, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_all_existing() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::with_options(Options::default().shards(4));
+
+    fs.get_file_default("/a/existing").await?;
+
+    assert!(matches!(
+        fs.create_all(
+            [
+                (PathBuf::from("/a/b"), Stored::File(1)),
+                (PathBuf::from("/a/existing"), Stored::File(2)),
+                (PathBuf::from("/c"), Stored::File(3)),
+            ],
+            u32::default,
+        )
+        .await,
+        Err(CreateError::AlreadyExists(path)) if path == Path::new("/a/existing")
+    ));
+    assert!(matches!(
+        fs.create_all(
+            [
+                (PathBuf::from("/d"), Stored::File(1)),
+                (PathBuf::from("/d"), Stored::File(2)),
+            ],
+            u32::default,
+        )
+        .await,
+        Err(CreateError::AlreadyExists(path)) if path == Path::new("/d")
+    ));
+    assert_eq!(
+        fs.get_file_default("/a/existing").await?.read(|v| *v).await,
+        0
+    );
+    assert!(fs.get_file("/a/b").await?.is_none());
+    assert!(fs.get_file("/c").await?.is_none());
+    assert!(fs.get_file("/d").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_all_conflict() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.get_file_default("/a").await?;
+
+    assert!(matches!(
+        fs.create_all([(PathBuf::from("/a"), Stored::Directory(1))], u32::default)
+            .await,
+        Err(CreateError::UnexpectedFile(path)) if path == Path::new("/a")
+    ));
+    assert!(matches!(
+        fs.create_all([(PathBuf::from("/"), Stored::Directory(1))], u32::default)
+            .await,
+        Err(CreateError::InvalidPath(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_all_sharded() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::with_options(Options::default().shards(4));
+    let created = Arc::new(AtomicUsize::new(0));
+    let counter = created.clone();
+
+    fs.on_create(move |_, _| {
+        counter.fetch_add(1, Ordering::SeqCst);
+
+        async {}
+    });
+    fs.create_all(
+        (0..16).map(|i| (PathBuf::from(format!("/{i:02}")), Stored::File(i))),
+        u32::default,
+    )
+    .await?;
+
+    assert_eq!(created.load(Ordering::SeqCst), 16);
+
+    for i in 0..16 {
+        let file = fs.get_file_default(format!("/{i:02}")).await?;
+
+        assert_eq!(file.read(|v| *v).await, i);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_all_denied() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::with_options(Options::default().shards(4));
+
+    fs.intercept(|action, path| async move {
+        match (action, path.ends_with("denied")) {
+            (Action::Create, true) => Decision::Deny,
+            _ => Decision::Allow,
+        }
+    });
+
+    assert!(matches!(
+        fs.create_all(
+            [
+                (PathBuf::from("/allowed"), Stored::File(1)),
+                (PathBuf::from("/denied"), Stored::File(2)),
+            ],
+            u32::default,
+        )
+        .await,
+        Err(CreateError::Write(WriteError::AccessDenied))
+    ));
+    assert!(fs.get_file("/allowed").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_all_rollback() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::with_options(Options::default().shards(4).max_children(2));
+
+    assert!(matches!(
+        fs.create_all(
+            (0..3).map(|i| (PathBuf::from(format!("/{i}")), Stored::File(i))),
+            u32::default,
+        )
+        .await,
+        Err(CreateError::Write(WriteError::QuotaExceeded))
+    ));
+
+    for i in 0..3 {
+        assert!(fs.get_file(format!("/{i}")).await?.is_none());
+    }
+
+    fs.create_all(
+        (0..2).map(|i| (PathBuf::from(format!("/{i}")), Stored::File(i))),
+        u32::default,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_all_normalized() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::with_options(Options::default().case_insensitive(true));
+
+    fs.create_all(
+        [
+            (PathBuf::from("/a/x"), Stored::File(1)),
+            (PathBuf::from("a/./y"), Stored::File(2)),
+            (PathBuf::from("/a/b/../z"), Stored::File(3)),
+        ],
+        u32::default,
+    )
+    .await?;
+
+    assert_eq!(fs.get_file_default("/a/y").await?.read(|v| *v).await, 2);
+    assert_eq!(fs.get_file_default("/a/z").await?.read(|v| *v).await, 3);
+    assert!(fs.get_dir("/a/b").await?.is_none());
+    assert!(matches!(
+        fs.create_all(
+            [
+                (PathBuf::from("/b/x"), Stored::File(1)),
+                (PathBuf::from("B/X"), Stored::File(2)),
+            ],
+            u32::default,
+        )
+        .await,
+        Err(CreateError::AlreadyExists(path)) if path == Path::new("B/X")
+    ));
+    assert!(fs.get_dir("/b").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_all_pending_conflict() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    assert!(matches!(
+        fs.create_all(
+            [
+                (PathBuf::from("/a"), Stored::File(1)),
+                (PathBuf::from("/a/b/c"), Stored::File(2)),
+            ],
+            u32::default,
+        )
+        .await,
+        Err(CreateError::UnexpectedFile(path)) if path == Path::new("/a")
+    ));
+    assert!(fs.get_file("/a").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_all_checked() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::with_options(Options::default().max_nodes(3));
+
+    fs.get_dir_default("/readonly")
+        .await?
+        .set_readonly(true)
+        .await;
+
+    assert!(matches!(
+        fs.create_all(
+            [
+                (PathBuf::from("/allowed"), Stored::File(1)),
+                (PathBuf::from("/readonly/a/b"), Stored::File(2)),
+            ],
+            u32::default,
+        )
+        .await,
+        Err(CreateError::Write(WriteError::PermissionDenied))
+    ));
+    assert!(matches!(
+        fs.create_all(
+            [
+                (PathBuf::from("/allowed"), Stored::File(1)),
+                (PathBuf::from("/a/b"), Stored::File(2)),
+            ],
+            u32::default,
+        )
+        .await,
+        Err(CreateError::Write(WriteError::QuotaExceeded))
+    ));
+    assert!(fs.get_file("/allowed").await?.is_none());
+    assert!(fs.get_dir("/a").await?.is_none());
+
+    Ok(())
+}
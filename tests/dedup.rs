@@ -0,0 +1,78 @@
+use anyhow::Result;
+use futures::AsyncWriteExt;
+use memfs::{
+    directory::{
+        Attach,
+        GetExt,
+    },
+    file::Open,
+    file_system::Options,
+    node::{
+        Data,
+        DataExt,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn dedup() -> Result<()> {
+    let fs = FileSystem::<(), Vec<u8>>::with_options(Options::default().dedup());
+    let a = fs.get_file_or_create_with("/a", || b"shared".to_vec()).await?;
+    let b = fs.get_file_or_create_with("/b", || b"shared".to_vec()).await?;
+    let c = fs.get_file_or_create_with("/c", || b"other".to_vec()).await?;
+
+    assert!(a.data().await.ptr_eq(&b.data().await));
+    assert!(!a.data().await.ptr_eq(&c.data().await));
+
+    a.write(|mut value| value.extend_from_slice(b"!")).await?;
+
+    assert!(!a.data().await.ptr_eq(&b.data().await));
+    assert_eq!(a.read(|value| value.clone()).await, b"shared!");
+    assert_eq!(b.read(|value| value.clone()).await, b"shared");
+
+    let d = fs.get_file_or_create_with("/d", || b"shared".to_vec()).await?;
+
+    assert!(b.data().await.ptr_eq(&d.data().await));
+
+    let mut writer = d.open_writer().await?;
+
+    writer.write_all(b"written").await?;
+    writer.close().await?;
+
+    assert_eq!(b.read(|value| value.clone()).await, b"shared");
+    assert_eq!(d.read(|value| value.clone()).await, b"written");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dedup_moved_across_file_systems() -> Result<()> {
+    let fs = FileSystem::<(), Vec<u8>>::with_options(Options::default().dedup());
+    let other = FileSystem::<(), Vec<u8>>::new();
+    let a = fs.get_file_or_create_with("/a", || b"shared".to_vec()).await?;
+    let b = fs.get_file_or_create_with("/b", || b"shared".to_vec()).await?;
+
+    if let Some(node) = fs.detach("a").await? {
+        other.attach("a", node).await?;
+    }
+
+    assert!(!a.data().await.ptr_eq(&b.data().await));
+
+    a.write(|mut value| value.extend_from_slice(b"!")).await?;
+
+    assert_eq!(a.read(|value| value.clone()).await, b"shared!");
+    assert_eq!(b.read(|value| value.clone()).await, b"shared");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dedup_disabled() -> Result<()> {
+    let fs = FileSystem::<(), Vec<u8>>::new();
+    let a = fs.get_file_or_create_with("/a", || b"shared".to_vec()).await?;
+    let b = fs.get_file_or_create_with("/b", || b"shared".to_vec()).await?;
+
+    assert!(!a.data().await.ptr_eq(&b.data().await));
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetExt,
+        Rename,
+    },
+    node::Depth,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn depth() -> Result<()> {
+    let fs = FileSystem::<(), ()>::default();
+    let file = fs.get_file_default("/a/b/c").await?;
+
+    assert_eq!(fs.depth().await, 0);
+    assert_eq!(fs.get_dir_default("/a").await?.depth().await, 1);
+    assert_eq!(file.depth().await, 3);
+
+    fs.rename("/a/b/c", "/c").await?;
+
+    assert_eq!(file.depth().await, 1);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use futures::{
+    StreamExt,
+    TryStreamExt,
+};
+use memfs::{
+    directory::{
+        Get,
+        GetType,
+    },
+    node::Descendants,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn descendants() -> Result<()> {
+    let fs = FileSystem::<(), ()>::default();
+
+    fs.get_default("/a/b/c", GetType::File).await?;
+    fs.get_default("/a/d", GetType::File).await?;
+
+    let node = fs.get_default("/a", GetType::Directory).await?;
+    let mut paths = node
+        .descendants()
+        .map_ok(|(path, _)| path)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    paths.sort();
+
+    assert_eq!(
+        paths,
+        [
+            PathBuf::from("/b"),
+            PathBuf::from("/b/c"),
+            PathBuf::from("/d")
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn descendants_file() -> Result<()> {
+    let fs = FileSystem::<(), ()>::default();
+    let node = fs.get_default("/a", GetType::File).await?;
+
+    assert_eq!(node.descendants().count().await, 0);
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use std::path::Path;
+
+use anyhow::Result;
+use memfs::{
+    diff::Change,
+    directory::GetExt,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn diff() -> Result<()> {
+    let a: FileSystem<(), u32> = FileSystem::new();
+    let b: FileSystem<(), u32> = FileSystem::new();
+
+    a.get_file_default("/same").await?;
+    b.get_file_default("/same").await?;
+    a.get_file_default("/removed").await?;
+    b.get_dir_default("/added/child").await?;
+    a.get_file_default("/type").await?;
+    b.get_dir_default("/type").await?;
+    a.get_file_default("/dir/data").await?;
+    b.get_file_default("/dir/data")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+
+    let diff = memfs::diff(&a, &b, PartialEq::eq).await?;
+
+    assert_eq!(diff.len(), 4);
+    assert_eq!(diff.get(Path::new("/added")), Some(&Change::Added));
+    assert_eq!(diff.get(Path::new("/removed")), Some(&Change::Removed));
+    assert_eq!(diff.get(Path::new("/type")), Some(&Change::TypeChanged));
+    assert_eq!(diff.get(Path::new("/dir/data")), Some(&Change::DataChanged));
+    assert!(memfs::diff(&a, &a, PartialEq::eq).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn diff_directory_values() -> Result<()> {
+    let a: FileSystem<u32, u32> = FileSystem::new();
+    let b: FileSystem<u32, u32> = FileSystem::new();
+
+    a.get_dir_default("/same").await?;
+    b.get_dir_default("/same").await?;
+    a.get_dir_default("/dir/changed").await?;
+    b.get_dir_default("/dir/changed")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+
+    let diff = memfs::diff(&a, &b, PartialEq::eq).await?;
+
+    assert_eq!(diff.len(), 1);
+    assert_eq!(
+        diff.get(Path::new("/dir/changed")),
+        Some(&Change::DataChanged)
+    );
+    assert_eq!(diff.to_string(), "~ /dir/changed\n");
+    assert_eq!(
+        diff.render_with(&a, &b, |a, b| format!("-{a}\n+{b}")).await?,
+        "~ /dir/changed\n"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn render() -> Result<()> {
+    let a: FileSystem<(), u32> = FileSystem::new();
+    let b: FileSystem<(), u32> = FileSystem::new();
+
+    a.get_file_default("/removed").await?;
+    a.get_file_default("/type").await?;
+    b.get_dir_default("/type").await?;
+    b.get_file_default("/added").await?;
+    a.get_file_default("/data").await?;
+    b.get_file_default("/data")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+
+    let diff = memfs::diff(&a, &b, PartialEq::eq).await?;
+
+    assert_eq!(diff.to_string(), "+ /added\n~ /data\n- /removed\n! /type\n");
+    assert_eq!(
+        diff.render_with(&a, &b, |a, b| format!("-{a}\n+{b}")).await?,
+        "+ /added\n~ /data\n    -0\n    +1\n- /removed\n! /type\n"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn render_nested() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+
+    fs.get_file_default("/a/data").await?;
+    fs.get_file_default("/b/data")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+
+    let a = fs.get_dir_default("/a").await?;
+    let b = fs.get_dir_default("/b").await?;
+    let diff = memfs::diff(&a, &b, PartialEq::eq).await?;
+
+    assert_eq!(
+        diff.render_with(&a, &b, |a, b| format!("-{a}\n+{b}")).await?,
+        "~ /data\n    -0\n    +1\n"
+    );
+
+    Ok(())
+}
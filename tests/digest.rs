@@ -0,0 +1,54 @@
+use anyhow::Result;
+use futures::AsyncWriteExt;
+use memfs::{
+    directory::GetExt,
+    file::Open,
+    node::{
+        DataExt,
+        Digest,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn digest() -> Result<()> {
+    let a = FileSystem::<(), Vec<u8>>::new();
+    let b = FileSystem::<(), Vec<u8>>::new();
+
+    for fs in [&a, &b] {
+        fs.get_file_default("/config/a")
+            .await?
+            .write(|mut value| *value = b"one".to_vec())
+            .await?;
+        fs.get_file_default("/other").await?;
+    }
+
+    let file = a.get_file_default("/config/a").await?;
+    let etag = file.etag().await;
+
+    assert_eq!(etag, file.etag().await);
+    assert_eq!(a.tree_hash().await, b.tree_hash().await);
+
+    let hash = a.tree_hash().await;
+
+    file.write(|mut value| *value = b"two".to_vec()).await?;
+
+    assert_ne!(etag, file.etag().await);
+    assert_ne!(hash, a.tree_hash().await);
+
+    let hash = a.tree_hash().await;
+    let mut writer = file.open_writer().await?;
+
+    writer.write_all(b"three").await?;
+    writer.close().await?;
+
+    assert_ne!(hash, a.tree_hash().await);
+
+    let hash = b.tree_hash().await;
+
+    b.get_dir_default("/empty").await?;
+
+    assert_ne!(hash, b.tree_hash().await);
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+#![cfg(feature = "tokio")]
+
+use std::{
+    ffi::OsString,
+    path::PathBuf,
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+    },
+    file_system::{
+        DiskError,
+        Options,
+        Stored,
+        WriteMode,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn from_disk() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    tokio::fs::create_dir_all(dir.path().join("a/b")).await?;
+    tokio::fs::create_dir_all(dir.path().join("empty")).await?;
+    tokio::fs::write(dir.path().join("a/b/file"), "content").await?;
+
+    let fs: FileSystem<(), String> = FileSystem::from_disk(dir.path(), |bytes| {
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+    .await?;
+
+    assert_eq!(fs.count().await?, 2);
+    assert!(fs.get_dir("/empty").await?.is_some());
+
+    let value = match fs.get_file("/a/b/file").await? {
+        Some(file) => file.read(|value| value.clone()).await,
+        _ => String::new(),
+    };
+
+    assert_eq!(value, "content");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn from_disk_non_default() -> Result<()> {
+    #[derive(Debug)]
+    struct Text(String);
+
+    let dir = tempfile::tempdir()?;
+
+    tokio::fs::write(dir.path().join("file"), "content").await?;
+
+    let fs: FileSystem<(), Text> = FileSystem::from_disk(dir.path(), |bytes| {
+        Text(String::from_utf8_lossy(&bytes).into_owned())
+    })
+    .await?;
+
+    let value = match fs.get_file("/file").await? {
+        Some(file) => file.read(|value| value.0.clone()).await,
+        _ => String::new(),
+    };
+
+    assert_eq!(value, "content");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn from_disk_with() -> Result<()> {
+    #[derive(Debug)]
+    struct Handle(u32);
+
+    let dir = tempfile::tempdir()?;
+
+    tokio::fs::create_dir_all(dir.path().join("a/b")).await?;
+    tokio::fs::write(dir.path().join("a/b/file"), "content").await?;
+
+    let fs: FileSystem<Handle, String> = FileSystem::from_disk_with(
+        dir.path(),
+        || Handle(1),
+        |bytes| String::from_utf8_lossy(&bytes).into_owned(),
+    )
+    .await?;
+
+    let value = match fs.get_dir("/a/b").await? {
+        Some(dir) => dir.read(|value| value.0).await,
+        _ => panic!("expected imported directory"),
+    };
+
+    assert_eq!(fs.read(|value| value.0).await, 1);
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn from_disk_symlink() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    tokio::fs::write(dir.path().join("file"), "content").await?;
+    tokio::fs::symlink(dir.path().join("file"), dir.path().join("link")).await?;
+
+    let result = FileSystem::<(), String>::from_disk(dir.path(), |bytes| {
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+    .await;
+
+    assert!(matches!(result, Err(DiskError::Unsupported(path)) if path.ends_with("link")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_to_disk() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let target = dir.path().join("target");
+    let fs: FileSystem<(), String> = FileSystem::new();
+
+    fs.get_dir_default("/empty").await?;
+    fs.get_file_default("/a/b/file")
+        .await?
+        .write(|mut value| *value = String::from("content"))
+        .await?;
+
+    fs.write_to_disk(&target, WriteMode::Create, |value| {
+        value.clone().into_bytes()
+    })
+    .await?;
+
+    assert!(target.join("empty").is_dir());
+    assert_eq!(
+        tokio::fs::read_to_string(target.join("a/b/file")).await?,
+        "content"
+    );
+
+    let result = fs
+        .write_to_disk(&target, WriteMode::Create, |value| {
+            value.clone().into_bytes()
+        })
+        .await;
+
+    assert!(matches!(result, Err(DiskError::AlreadyExists(_))));
+
+    tokio::fs::write(target.join("extra"), "extra").await?;
+    fs.write_to_disk(&target, WriteMode::Merge, |value| {
+        value.clone().into_bytes()
+    })
+    .await?;
+
+    assert!(target.join("extra").is_file());
+
+    fs.write_to_disk(&target, WriteMode::Overwrite, |value| {
+        value.clone().into_bytes()
+    })
+    .await?;
+
+    assert!(!target.join("extra").exists());
+    assert!(target.join("a/b/file").is_file());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_to_disk_over_file() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let target = dir.path().join("target");
+    let fs: FileSystem<(), String> = FileSystem::new();
+
+    fs.get_file_default("/file")
+        .await?
+        .write(|mut value| *value = String::from("content"))
+        .await?;
+    tokio::fs::write(&target, "existing").await?;
+
+    fs.write_to_disk(&target, WriteMode::Overwrite, |value| {
+        value.clone().into_bytes()
+    })
+    .await?;
+
+    assert!(target.is_dir());
+    assert_eq!(
+        tokio::fs::read_to_string(target.join("file")).await?,
+        "content"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_to_disk_invalid_name() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let target = dir.path().join("target");
+    let fs: FileSystem<(), String> =
+        FileSystem::with_loader(Options::default(), |path: PathBuf| async move {
+            Ok(match path.to_str() {
+                Some("/") => vec![(OsString::from(".."), Stored::File(String::from("escape")))],
+                _ => Vec::new(),
+            })
+        });
+
+    tokio::fs::create_dir_all(&target).await?;
+    tokio::fs::write(target.join("existing"), "existing").await?;
+
+    let result = fs
+        .write_to_disk(&target, WriteMode::Overwrite, |value| {
+            value.clone().into_bytes()
+        })
+        .await;
+
+    assert!(matches!(result, Err(DiskError::InvalidName(_))));
+    assert!(target.join("existing").is_file());
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "embed")]
+// Wide directories must embed well within a low expansion depth.
+#![recursion_limit = "64"]
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+    },
+    embed_dir,
+    node::DataExt,
+};
+
+#[tokio::test]
+async fn embed_dir() -> Result<()> {
+    let fs = embed_dir!("tests/fixtures/embed").await?;
+
+    assert_eq!(fs.count().await?, 2);
+    assert_eq!(
+        fs.get_file_default("/hello.txt")
+            .await?
+            .read(|value| *value)
+            .await,
+        b"hello\n"
+    );
+    assert_eq!(
+        fs.get_file_default("/nested/file.txt")
+            .await?
+            .read(|value| *value)
+            .await,
+        b"nested"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn embed_dir_wide() -> Result<()> {
+    let fs = embed_dir!("tests").await?;
+    let entries = std::fs::read_dir("tests")?.count();
+
+    assert_eq!(fs.count().await?, entries);
+
+    Ok(())
+}
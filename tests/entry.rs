@@ -0,0 +1,83 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        Entry,
+        GetExt,
+    },
+    node::{
+        DataExt,
+        Permissions,
+        WriteError,
+    },
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn entry() -> Result<()> {
+    let fs: FileSystem<u32, u32> = FileSystem::new();
+
+    let dir = fs.entry("dir").await.or_insert_dir(1).await?;
+    let file = fs.entry("file").await.or_insert_file(2).await?;
+
+    assert!(matches!(&dir, Node::Directory(dir) if dir.read(|value| *value).await == 1));
+    assert!(matches!(
+        fs.entry("dir").await.or_insert_file(3).await?,
+        Node::Directory(_)
+    ));
+    assert!(fs.entry("file").await.or_insert_dir(4).await? == file);
+
+    let mut modified = false;
+    let entry = fs.entry("file").await.and_modify(|_| modified = true);
+
+    assert!(modified);
+    assert!(matches!(entry, Entry::Occupied(_)));
+
+    drop(entry);
+
+    match fs.entry("file").await {
+        Entry::Occupied(entry) => assert!(entry.remove().await? == file),
+        Entry::Vacant(_) => panic!("expected occupied entry"),
+    }
+
+    assert_eq!(fs.count().await?, 1);
+    assert!(fs.get_file("file").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn entry_readonly() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::new();
+    let dir = fs.get_dir_default("/dir").await?;
+
+    dir.set_readonly(true).await;
+
+    assert!(matches!(
+        dir.entry("file").await.or_insert_file(()).await,
+        Err(WriteError::PermissionDenied)
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn entry_invalid_name() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::new();
+
+    for name in ["..", ".", "a/b", "/a", ""] {
+        assert!(matches!(
+            fs.entry(name).await.or_insert_file(()).await,
+            Err(WriteError::InvalidName)
+        ));
+        assert!(matches!(
+            fs.entry(name).await.or_insert_dir(()).await,
+            Err(WriteError::InvalidName)
+        ));
+    }
+
+    assert_eq!(fs.count().await?, 0);
+
+    Ok(())
+}
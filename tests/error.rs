@@ -0,0 +1,137 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        Get,
+        GetExt,
+        GetType,
+        Merge,
+        Rename,
+        Resolution,
+    },
+    error::{
+        ModifyError,
+        ResolveError,
+    },
+    node::{
+        DataExt,
+        Permissions,
+    },
+    path::MemPath,
+    Error,
+    FileSystem,
+};
+
+async fn update(fs: &FileSystem<u32, u32>) -> Result<(), Error> {
+    fs.get_file_default("/a/b")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+    fs.rename("/a/b", "/c").await?;
+
+    Ok(())
+}
+
+async fn open(fs: &FileSystem<u32, u32>, path: &str) -> Result<usize, Error> {
+    let node = fs.get_default(path, GetType::Directory).await?;
+
+    Ok(node.into_dir()?.count_file().await?)
+}
+
+async fn merge(fs: &FileSystem<u32, u32>, from: &str, to: &str) -> Result<(), Error> {
+    let from = fs.get(MemPath::new(from)?, GetType::Directory).await?;
+    let to = fs.get(MemPath::new(to)?, GetType::Directory).await?;
+    let (Some(from), Some(to)) = (from, to) else {
+        return Ok(());
+    };
+
+    to.into_dir()?
+        .merge_from(&from.into_dir()?, |_, _| Resolution::Theirs)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+async fn import(json: &str) -> Result<FileSystem<(), String>, Error> {
+    Ok(FileSystem::from_json(json).await?)
+}
+
+#[tokio::test]
+async fn error_unified() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    update(&fs).await?;
+
+    fs.get_file_default("/a/b").await?.set_readonly(true).await;
+
+    assert!(matches!(
+        update(&fs).await,
+        Err(Error::Modify(ModifyError::Write(_)))
+    ));
+
+    fs.get_file_default("/d").await?;
+
+    assert!(matches!(
+        fs.get_dir_default("/d/e").await.map_err(Error::from),
+        Err(Error::Resolve(ResolveError::GetDirectory(_)))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn error_node() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.get_file_default("/a/b").await?;
+
+    assert_eq!(open(&fs, "/a").await?, 1);
+    assert!(matches!(
+        open(&fs, "/a/b").await,
+        Err(Error::Resolve(ResolveError::Node(_)))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn error_conversions() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+    let a = fs.get_dir_default("/a").await?;
+
+    fs.get_file_default("/b").await?;
+    a.get_file_default("c").await?;
+    fs.get_dir_default("/d").await?;
+
+    merge(&fs, "/a", "/d").await?;
+
+    assert!(fs.get_file("/d/c").await?.is_some());
+    assert!(matches!(
+        merge(&fs, "/a/", "/d").await,
+        Err(Error::Resolve(ResolveError::Path(_)))
+    ));
+    assert!(matches!(
+        merge(&fs, "/b", "/d").await,
+        Err(Error::Resolve(ResolveError::Node(_)))
+    ));
+    assert!(matches!(
+        merge(&fs, "/", "/a").await,
+        Err(Error::Modify(ModifyError::Merge(_)))
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[tokio::test]
+async fn error_json() -> Result<()> {
+    import(r#"{ "type": "dir" }"#).await?;
+
+    assert!(matches!(
+        import(r#"{ "type": "file" }"#).await,
+        Err(Error::Persist(memfs::error::PersistError::Json(_)))
+    ));
+
+    Ok(())
+}
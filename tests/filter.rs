@@ -0,0 +1,96 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Filter,
+        Fold,
+        GetErrorKind,
+        GetExt,
+        GetFileError,
+    },
+    file_system::Options,
+    node::{
+        DataExt,
+        Permissions,
+    },
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn filter() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::new();
+
+    fs.get_dir_or_create_with("/a", || 1).await?;
+    fs.get_file_or_create_with("/a/b/c", || 2).await?;
+    fs.get_file_or_create_with("/a/d", || 3).await?;
+    fs.get_file_or_create_with("/e/f", || 4).await?;
+    fs.get_dir_default("/g").await?;
+
+    let filtered = fs
+        .filter(|path, node| match node {
+            Node::Directory(_) => path.ends_with("g"),
+            Node::File(_) => path.ends_with("c"),
+        })
+        .await?;
+    let mut paths = filtered
+        .fold(Vec::new(), |mut paths, path, _| {
+            paths.push(path.to_path_buf());
+            paths
+        })
+        .await;
+
+    paths.sort();
+
+    assert_eq!(
+        paths,
+        ["/a", "/a/b", "/a/b/c", "/g"]
+            .map(PathBuf::from)
+            .to_vec()
+    );
+    assert_eq!(
+        filtered
+            .get_dir_default("/a")
+            .await?
+            .read(|value| *value)
+            .await,
+        1
+    );
+
+    filtered
+        .get_file_default("/a/b/c")
+        .await?
+        .write(|mut value| *value = 5)
+        .await?;
+
+    assert_eq!(
+        fs.get_file_default("/a/b/c")
+            .await?
+            .read(|value| *value)
+            .await,
+        2
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn filter_isolated() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::with_options(Options::default().max_nodes(2));
+    let file = fs.get_file_or_create_with("/a", || 1).await?;
+
+    file.set_readonly(true).await;
+
+    let filtered = fs.filter(|_, _| true).await?;
+    let copy = filtered.get_file_default("/a").await?;
+
+    assert!(copy.write(|mut value| *value = 2).await.is_ok());
+    assert_eq!(file.read(|value| *value).await, 1);
+    assert!(matches!(
+        filtered.get_file_default("/b/c").await,
+        Err(GetFileError::Get(err)) if err.kind() == GetErrorKind::QuotaExceeded
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Find,
+        GetExt,
+    },
+    file_system::{
+        Options,
+        Order,
+    },
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn find_by_name() -> Result<()> {
+    let fs = FileSystem::<(), u32>::with_options(Options::default().order(Order::Sorted));
+
+    fs.get_file_default("/a/Cargo.toml").await?;
+    fs.get_file_default("/b/c/Cargo.toml").await?;
+    fs.get_file_default("/b/c/lib.rs").await?;
+    fs.get_dir_default("/b/src").await?;
+
+    let (path, node) = fs.find_by_name("Cargo.toml").await.unwrap();
+
+    assert_eq!(path, Path::new("/a/Cargo.toml"));
+    assert!(matches!(node, Node::File(_)));
+    assert!(fs.find_by_name("missing").await.is_none());
+
+    let paths = |found: Vec<(PathBuf, Node<(), u32>)>| {
+        found.into_iter().map(|(path, _)| path).collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        paths(fs.find_by_name_all("Cargo.toml").await),
+        [Path::new("/a/Cargo.toml"), Path::new("/b/c/Cargo.toml")]
+    );
+    let sources = fs
+        .find_by_name_matching(|name| name.to_string_lossy().ends_with(".rs") || name == "src")
+        .await;
+
+    assert_eq!(
+        paths(sources),
+        [Path::new("/b/c/lib.rs"), Path::new("/b/src")]
+    );
+
+    let b = fs.get_dir_default("/b").await?;
+
+    assert_eq!(
+        paths(b.find_by_name_all("Cargo.toml").await),
+        [Path::new("/c/Cargo.toml")]
+    );
+
+    Ok(())
+}
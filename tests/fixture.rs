@@ -0,0 +1,59 @@
+#![cfg(all(feature = "toml", feature = "yaml"))]
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+    },
+    file_system::FixtureError,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn fixture_toml() -> Result<()> {
+    let fs = FileSystem::from_toml(
+        r#"
+        "/config/app.toml" = "debug = true"
+        "/empty/" = ""
+        "/readme" = "hello"
+        "#,
+    )
+    .await?;
+
+    let app = fs.get_file_default("/config/app.toml").await?;
+
+    assert_eq!(fs.count().await?, 3);
+    assert_eq!(app.read(|value| value.clone()).await, "debug = true");
+    assert!(fs.get_dir("/empty").await?.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fixture_yaml() -> Result<()> {
+    let fs = FileSystem::from_yaml(
+        "
+        /config/app.yaml: |
+          debug: true
+        /readme: hello
+        ",
+    )
+    .await?;
+
+    let app = fs.get_file_default("/config/app.yaml").await?;
+    let readme = fs.get_file_default("/readme").await?;
+
+    assert_eq!(app.read(|value| value.clone()).await, "debug: true\n");
+    assert_eq!(readme.read(|value| value.clone()).await, "hello");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fixture_invalid() {
+    let fs = FileSystem::from_toml("/readme = ").await;
+
+    assert!(matches!(fs, Err(FixtureError::Toml(_))));
+}
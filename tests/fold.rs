@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Fold,
+        GetExt,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn fold() -> Result<()> {
+    let fs = FileSystem::<(), usize>::new();
+
+    fs.get_file_or_create_with("/a/b/c", || 1).await?;
+    fs.get_file_or_create_with("/a/d", || 2).await?;
+    fs.get_file_or_create_with("/e", || 3).await?;
+
+    let mut paths = fs
+        .fold(Vec::new(), |mut paths, path, _| {
+            paths.push(path.to_path_buf());
+            paths
+        })
+        .await;
+
+    paths.sort();
+
+    assert_eq!(
+        paths,
+        ["/a", "/a/b", "/a/b/c", "/a/d", "/e"]
+            .map(PathBuf::from)
+            .to_vec()
+    );
+
+    let files = fs
+        .fold(Vec::new(), |mut files, _, node| {
+            files.extend(node.as_file().cloned());
+            files
+        })
+        .await;
+    let mut total = 0;
+
+    for file in files {
+        total += file.read(|value| *value).await;
+    }
+
+    assert_eq!(total, 6);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "im")]
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Attach,
+        Count,
+        Freeze,
+        GetExt,
+    },
+    file_system::{
+        Options,
+        Order,
+    },
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn freeze_persistent() -> Result<()> {
+    let fs: FileSystem<(), ()> =
+        FileSystem::with_options(Options::default().order(Order::Persistent).shards(4));
+
+    for i in 0..16 {
+        fs.get_file_default(format!("/file-{i:02}")).await?;
+    }
+
+    let frozen = fs.freeze().await?;
+    let cloned = frozen.clone();
+
+    fs.detach("file-00").await?;
+    fs.get_file_default("/file-16").await?;
+
+    assert_eq!(fs.count_file().await?, 16);
+    assert_eq!(frozen.len(), 16);
+    assert!(frozen.contains("file-00"));
+    assert!(!frozen.contains("file-16"));
+    assert_eq!(cloned.iter().count(), 16);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn freeze_hashed() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::new();
+
+    fs.get_file_default("/a").await?;
+
+    let frozen = fs.freeze().await?;
+
+    fs.detach("a").await?;
+
+    assert!(frozen.get("a").is_some_and(|node| matches!(node, Node::File(_))));
+    assert!(!fs.freeze().await?.contains("a"));
+    assert!(fs.freeze().await?.is_empty());
+
+    Ok(())
+}
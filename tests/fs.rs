@@ -0,0 +1,36 @@
+use std::io::ErrorKind;
+
+use anyhow::Result;
+use memfs::{
+    fs,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn std_fs_facade() -> Result<()> {
+    let memfs: FileSystem<(), String> = FileSystem::new();
+
+    fs::create_dir_all(&memfs, "/a/b").await?;
+    fs::write(&memfs, "/a/b/file", String::from("content")).await?;
+
+    assert_eq!(fs::read(&memfs, "/a/b/file").await?, "content");
+    assert!(fs::metadata(&memfs, "/a/b").await?.is_dir());
+    assert!(fs::metadata(&memfs, "/a/b/file").await?.is_file());
+
+    let entries = fs::read_dir(&memfs, "/a/b").await?;
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].file_name(), "file");
+    assert_eq!(entries[0].path().to_str(), Some("/a/b/file"));
+
+    let missing = fs::write(&memfs, "/missing/file", String::new()).await;
+    let directory = fs::read(&memfs, "/a/b").await;
+
+    assert_eq!(missing.map_err(|err| err.kind()), Err(ErrorKind::NotFound));
+    assert_eq!(
+        directory.map_err(|err| err.kind()),
+        Err(ErrorKind::IsADirectory)
+    );
+
+    Ok(())
+}
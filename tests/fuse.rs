@@ -0,0 +1,76 @@
+#![cfg(all(feature = "fuse", target_os = "linux"))]
+
+use std::{
+    fs::{
+        read,
+        read_dir,
+        remove_file,
+        write,
+    },
+    io,
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    fuse,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+#[ignore = "mounting needs /dev/fuse and CAP_SYS_ADMIN"]
+async fn mount() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+
+    fs.get_dir_default("/a").await?;
+    fs.get_file_default("/b")
+        .await?
+        .write(|mut value| *value = b"hello".to_vec())
+        .await?;
+
+    let dir = tempfile::tempdir()?;
+    let mount = fuse::mount(&fs, dir.path()).await?;
+    let path = mount.path().to_path_buf();
+
+    let (names, content) = tokio::task::spawn_blocking(move || -> io::Result<_> {
+        let mut names = read_dir(&path)?
+            .map(|entry| entry.map(|entry| entry.file_name()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        names.sort();
+
+        let content = read(path.join("b"))?;
+
+        write(path.join("b"), b"hi")?;
+
+        assert_eq!(read(path.join("b"))?, b"hi");
+
+        write(path.join("c"), b"world")?;
+        remove_file(path.join("b"))?;
+
+        assert!(path.join("a").is_dir());
+        assert_eq!(
+            remove_file(path.join("a")).map_err(|err| err.kind()),
+            Err(io::ErrorKind::IsADirectory)
+        );
+
+        Ok((names, content))
+    })
+    .await??;
+
+    mount.unmount()?;
+
+    assert_eq!(names, ["a", "b"]);
+    assert_eq!(content, b"hello");
+    assert!(fs.get_file("/b").await?.is_none());
+    assert_eq!(
+        fs.get_file_default("/c")
+            .await?
+            .read(|value| value.clone())
+            .await,
+        b"world"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetDirectoryError,
+        GetErrorKind,
+        GetExt,
+    },
+    FileSystem,
+};
+use miette::Diagnostic;
+
+#[tokio::test]
+async fn get_error_path() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.get_file_default("/a").await?;
+
+    let Err(GetDirectoryError::Get(err)) = fs.get_dir_default("/a/b/c").await else {
+        anyhow::bail!("expected a get error");
+    };
+
+    assert_eq!(err.kind(), GetErrorKind::UnexpectedFile);
+    assert_eq!(err.path().to_str(), Some("/a/b/c"));
+    assert_eq!(err.index(), 2);
+    assert!(err.to_string().contains("component 2 of /a/b/c"));
+
+    let spans = err
+        .labels()
+        .map(|labels| {
+            labels
+                .map(|label| (label.offset(), label.len()))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    assert_eq!(spans, vec![(3, 1)]);
+
+    Ok(())
+}
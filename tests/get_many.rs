@@ -0,0 +1,70 @@
+use std::ffi::OsString;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Get,
+        GetExt,
+        GetMany,
+        GetType,
+    },
+    node::{
+        Identified,
+        Named,
+    },
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn get_many() -> Result<()> {
+    let fs = FileSystem::<(), ()>::new();
+
+    fs.get_file_default("/a/b").await?;
+    fs.get_file_default("/c").await?;
+
+    let results = fs.get_many(["/c", "/missing", "/a/b", "/c/d", "/a"]).await;
+
+    assert_eq!(results.len(), 5);
+    assert!(matches!(&results[0], Ok(Some(Node::File(_)))));
+    assert!(matches!(&results[1], Ok(None)));
+    assert!(matches!(&results[2], Ok(Some(Node::File(_)))));
+    assert!(results[3].is_err());
+    assert!(matches!(&results[4], Ok(Some(Node::Directory(_)))));
+
+    if let Ok(Some(node)) = &results[2] {
+        assert_eq!(node.name().await, Some(OsString::from("b")));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_many_shared_parents() -> Result<()> {
+    let fs = FileSystem::<(), ()>::new();
+
+    for path in ["/a/b/c", "/a/b/d", "/a/e", "/f"] {
+        fs.get_file_default(path).await?;
+    }
+
+    let paths = [
+        "/a/e", "/a/b/d", "/f/g", "/a/b/c", "/a/b", "/a/x/y", "/a/../f", "./a/e", "/a/b/d",
+    ];
+    let results = fs.get_many(paths).await;
+
+    assert_eq!(results.len(), paths.len());
+
+    for (path, result) in paths.iter().zip(results) {
+        match (result, Get::get(&*fs, path, GetType::default()).await) {
+            (Ok(Some(a)), Ok(Some(b))) => assert_eq!(a.id().await, b.id().await),
+            (Ok(None), Ok(None)) => {}
+            (Err(a), Err(b)) => {
+                assert_eq!(a.kind(), b.kind());
+                assert_eq!(a.index(), b.index());
+            }
+            (a, b) => panic!("{path}: {a:?} != {b:?}"),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetDirectoryError,
+        GetExt,
+        GetFileError,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn get_file_or_create_with() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    let file = fs.get_file_or_create_with("/a/b", || 7).await?;
+
+    assert_eq!(file.read(|value| *value).await, 7);
+    assert_eq!(
+        fs.get_dir_default("/a").await?.read(|value| *value).await,
+        0
+    );
+
+    let file = fs.get_file_or_create_with("/a/b", || 9).await?;
+
+    assert_eq!(file.read(|value| *value).await, 7);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_dir_or_create_with() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    let dir = fs.get_dir_or_create_with("/a/b", || 3).await?;
+
+    assert_eq!(dir.read(|value| *value).await, 3);
+    assert_eq!(
+        fs.get_dir_default("/a").await?.read(|value| *value).await,
+        3
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_or_create_with_unexpected() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.get_file_default("/a").await?;
+    fs.get_dir_default("/b").await?;
+
+    assert!(matches!(
+        fs.get_dir_or_create_with("/a", || 1).await,
+        Err(GetDirectoryError::UnexpectedFile)
+    ));
+    assert!(matches!(
+        fs.get_file_or_create_with("/b", || 1).await,
+        Err(GetFileError::UnexpectedDirectory)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anyhow::Result;
+use futures::AsyncWriteExt;
+use memfs::{
+    directory::GetExt,
+    file::{
+        History,
+        Open,
+        RevertError,
+    },
+    file_system::Options,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn history_disabled() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+    let file = fs.get_file_default("/a").await?;
+
+    file.write(|mut value| *value = 1).await?;
+
+    assert!(file.history().await.is_empty());
+    assert!(matches!(
+        file.revert(0).await,
+        Err(RevertError::UnknownVersion(0))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn history_bounded() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().history(2));
+    let file = fs.get_file_default("/a").await?;
+
+    for i in 1..=3 {
+        file.write(|mut value| *value = i).await?;
+    }
+
+    assert_eq!(file.history().await, vec![2, 1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn history_revert() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().history(4));
+    let file = fs.get_file_default("/a").await?;
+
+    for i in 1..=3 {
+        file.write(|mut value| *value = i).await?;
+    }
+
+    file.revert(1).await?;
+
+    assert_eq!(file.read(|value| *value).await, 1);
+    assert_eq!(file.history().await, vec![3, 2, 1, 0]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn history_writer() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::with_options(Options::default().history(2));
+    let file = fs.get_file_or_create_with("/a", || b"first".to_vec()).await?;
+
+    let mut writer = file.open_writer().await?;
+
+    writer.write_all(b"second").await?;
+    writer.close().await?;
+
+    assert_eq!(file.history().await, vec![b"first".to_vec()]);
+
+    file.revert(0).await?;
+
+    assert_eq!(file.read(|value| value.clone()).await, b"first");
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use std::{
+    future::{
+        self,
+        Ready,
+    },
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use anyhow::Result;
+use futures::AsyncWriteExt;
+use memfs::{
+    directory::{
+        Attach,
+        GetExt,
+    },
+    file::Open,
+    node::DataExt,
+    FileSystem,
+    Node,
+};
+
+type Events = Arc<Mutex<Vec<(&'static str, PathBuf)>>>;
+
+fn record(
+    events: &Events,
+    kind: &'static str,
+) -> impl Fn(PathBuf, Node<(), u32>) -> Ready<()> {
+    let events = events.clone();
+
+    move |path, _| {
+        events.lock().unwrap().push((kind, path));
+        future::ready(())
+    }
+}
+
+#[tokio::test]
+async fn hooks() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let events = Events::default();
+
+    fs.on_create(record(&events, "create"));
+    fs.on_remove(record(&events, "remove"));
+    fs.on_write(record(&events, "write"));
+
+    fs.get_file_default("/a/b").await?;
+    fs.get_file_default("/a/b").await?;
+    fs.get_file_default("/a/b")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+    fs.get_dir_default("/a").await?.detach("b").await?;
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            ("create", "/a"),
+            ("create", "/a/b"),
+            ("write", "/a/b"),
+            ("remove", "/a/b"),
+        ]
+        .map(|(kind, path)| (kind, PathBuf::from(path)))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hooks_writer() -> Result<()> {
+    let fs = FileSystem::<(), Vec<u8>>::new();
+    let events = Arc::new(Mutex::new(Vec::new()));
+
+    fs.on_write({
+        let events = events.clone();
+
+        move |path, _| {
+            events.lock().unwrap().push(path);
+            future::ready(())
+        }
+    });
+
+    let file = fs.get_file_default("/a").await?;
+    let mut writer = file.open_writer().await?;
+
+    writer.write_all(b"closed").await?;
+    writer.close().await?;
+
+    let mut writer = file.open_writer().await?;
+
+    writer.write_all(b"dropped").await?;
+    drop(writer);
+
+    assert_eq!(*events.lock().unwrap(), [PathBuf::from("/a")]);
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    node::Identified,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn ids() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::new();
+    let dir = fs.get_dir_default("/dir").await?;
+    let file = fs.get_file_default("/dir/file").await?;
+
+    assert_eq!(u64::from(fs.id().await), 1);
+    assert!(fs.id().await < dir.id().await);
+    assert!(dir.id().await < file.id().await);
+    assert_eq!(
+        fs.get_file_default("/dir/file").await?.id().await,
+        file.id().await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Get,
+        GetExt,
+        GetType,
+    },
+    node::Child,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn identity() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::new();
+    let a = fs.get_file_default("/dir/a").await?;
+    let b = fs.get_file_default("/dir/b").await?;
+    let dir = fs.get_dir("/dir").await?;
+
+    assert!(a.ptr_eq(&fs.get_file_default("/dir/a").await?));
+    assert!(!a.ptr_eq(&b));
+    assert_eq!(a.parent().await, dir);
+    assert_ne!(a, b);
+
+    let node = fs.get("/dir/a", GetType::File).await?;
+    let other = fs.get("/dir", GetType::Directory).await?;
+
+    assert!(matches!((&node, &other), (Some(node), Some(other)) if !node.same_node(other)));
+    assert_eq!(node, fs.get("/dir/a", GetType::File).await?);
+
+    Ok(())
+}
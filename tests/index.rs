@@ -0,0 +1,114 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Attach,
+        GetErrorKind,
+        GetExt,
+        Probe,
+        Rename,
+    },
+    file_system::{
+        Action,
+        Decision,
+        Options,
+    },
+    FileSystem,
+    Node,
+};
+
+fn paths(nodes: Vec<(PathBuf, Node<(), u32>)>) -> Vec<PathBuf> {
+    nodes.into_iter().map(|(path, _)| path).collect()
+}
+
+#[tokio::test]
+async fn index() -> Result<()> {
+    let fs = FileSystem::<(), u32>::with_options(Options::default().index(true));
+    let file = fs.get_file_default("/a/b/c").await?;
+
+    fs.get_file_default("/a/d").await?;
+    fs.get_file_default("/e").await?;
+
+    assert!(fs
+        .lookup("/a/b/c")
+        .await?
+        .is_some_and(|node| node.same_node(&Node::File(file.clone()))));
+    assert_eq!(
+        paths(fs.lookup_prefix("/a").await),
+        [Path::new("/a/b"), Path::new("/a/b/c"), Path::new("/a/d")]
+    );
+
+    fs.rename("/a/b", "/f").await?;
+
+    assert!(fs.lookup("/a/b/c").await?.is_none());
+    assert!(fs
+        .lookup("/f/c")
+        .await?
+        .is_some_and(|node| node.same_node(&Node::File(file.clone()))));
+    assert_eq!(paths(fs.lookup_prefix("/a").await), [Path::new("/a/d")]);
+
+    fs.detach("f").await?;
+
+    assert!(fs.lookup("/f/c").await?.is_none());
+    assert!(fs.lookup_prefix("/f").await.is_empty());
+    assert_eq!(
+        paths(fs.lookup_prefix("/").await),
+        [Path::new("/a"), Path::new("/a/d"), Path::new("/e")]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn index_disabled() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+
+    fs.get_file_default("/a/b").await?;
+
+    assert!(fs.lookup("/a/b").await?.is_some());
+    assert!(fs.lookup("/a/./b").await?.is_some());
+    assert_eq!(paths(fs.lookup_prefix("/a").await), [Path::new("/a/b")]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn index_intercept() -> Result<()> {
+    let fs = FileSystem::<(), u32>::with_options(Options::default().index(true));
+
+    fs.get_file_default("/private/a").await?;
+    fs.intercept(|action, path| async move {
+        match (action, path.starts_with("/private")) {
+            (Action::Get, true) => Decision::Deny,
+            _ => Decision::Allow,
+        }
+    });
+
+    assert!(matches!(
+        fs.lookup("/private/a").await,
+        Err(err) if err.kind() == GetErrorKind::AccessDenied
+    ));
+    assert!(fs.lookup_prefix("/private").await.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn index_lru() -> Result<()> {
+    let fs = FileSystem::<(), u32>::with_options(Options::default().index(true).capacity(2));
+
+    fs.get_file_default("/a").await?;
+    fs.get_file_default("/b").await?;
+    fs.lookup("/a").await?;
+    fs.get_file_default("/c").await?;
+
+    assert!(fs.exists("/a").await);
+    assert!(!fs.exists("/b").await);
+    assert!(fs.lookup("/b").await?.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "inodes")]
+
+use std::{
+    ffi::OsStr,
+    io::ErrorKind,
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    file_system::Options,
+    inodes::Inodes,
+    node::{
+        DataExt,
+        NodeKind,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn lookup_and_readdir() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+
+    fs.get_dir_default("/a").await?;
+    fs.get_file_default("/b")
+        .await?
+        .write(|mut value| *value = b"hello".to_vec())
+        .await?;
+
+    let inodes = Inodes::new(&fs).await;
+    let mut entries = inodes.readdir(1).await?;
+
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].name, "a");
+    assert_eq!(entries[0].kind, NodeKind::Directory);
+    assert_eq!(entries[1].name, "b");
+    assert_eq!(entries[1].kind, NodeKind::File);
+
+    let attr = inodes.lookup(1, OsStr::new("b")).await?;
+
+    assert_eq!(attr.kind, NodeKind::File);
+    assert_eq!(attr.size, 5);
+    assert_eq!(inodes.getattr(attr.ino).await?, attr);
+    assert_eq!(
+        inodes
+            .lookup(1, OsStr::new("c"))
+            .await
+            .map_err(|err| err.kind()),
+        Err(ErrorKind::NotFound)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_and_write() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+
+    fs.get_file_default("/a")
+        .await?
+        .write(|mut value| *value = b"hello".to_vec())
+        .await?;
+
+    let inodes = Inodes::new(&fs).await;
+    let ino = inodes.lookup(1, OsStr::new("a")).await?.ino;
+
+    assert_eq!(inodes.read(ino, 1, 3).await?, b"ell");
+    assert_eq!(inodes.read(ino, 10, 3).await?, b"");
+    assert_eq!(inodes.write(ino, 3, b"p me").await?, 4);
+    assert_eq!(inodes.read(ino, 0, 100).await?, b"help me");
+    assert_eq!(
+        inodes.read(1, 0, 1).await.map_err(|err| err.kind()),
+        Err(ErrorKind::IsADirectory)
+    );
+
+    inodes.forget(ino).await;
+
+    assert_eq!(
+        inodes.getattr(ino).await.map_err(|err| err.kind()),
+        Err(ErrorKind::NotFound)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_bounds() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::with_options(Options::default().max_size(8));
+
+    fs.get_file_default("/a").await?;
+
+    let inodes = Inodes::new(&fs).await;
+    let ino = inodes.lookup(1, OsStr::new("a")).await?.ino;
+
+    assert_eq!(
+        inodes
+            .write(ino, u64::MAX, b"a")
+            .await
+            .map_err(|err| err.kind()),
+        Err(ErrorKind::InvalidInput)
+    );
+    assert_eq!(
+        inodes
+            .write(ino, u64::MAX - 1, b"a")
+            .await
+            .map_err(|err| err.kind()),
+        Err(ErrorKind::FileTooLarge)
+    );
+    assert_eq!(
+        inodes.write(ino, 6, b"abc").await.map_err(|err| err.kind()),
+        Err(ErrorKind::FileTooLarge)
+    );
+    assert_eq!(inodes.write(ino, 6, b"ab").await?, 2);
+    assert_eq!(inodes.getattr(ino).await?.size, 8);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_default_limit() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+
+    fs.get_file_default("/a").await?;
+
+    let inodes = Inodes::new(&fs).await;
+    let ino = inodes.lookup(1, OsStr::new("a")).await?.ino;
+
+    assert_eq!(
+        inodes
+            .write(ino, 1 << 40, b"a")
+            .await
+            .map_err(|err| err.kind()),
+        Err(ErrorKind::FileTooLarge)
+    );
+    assert_eq!(inodes.getattr(ino).await?.size, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_truncate_and_unlink() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+
+    fs.get_dir_default("/a").await?;
+
+    let inodes = Inodes::new(&fs).await;
+    let ino = inodes.create(1, OsStr::new("b")).await?.ino;
+
+    assert_eq!(
+        inodes
+            .create(1, OsStr::new("b"))
+            .await
+            .map_err(|err| err.kind()),
+        Err(ErrorKind::AlreadyExists)
+    );
+    assert_eq!(inodes.write(ino, 0, b"hello").await?, 5);
+    assert_eq!(inodes.truncate(ino, 2).await?.size, 2);
+    assert_eq!(inodes.read(ino, 0, 100).await?, b"he");
+    assert_eq!(
+        inodes
+            .unlink(1, OsStr::new("a"))
+            .await
+            .map_err(|err| err.kind()),
+        Err(ErrorKind::IsADirectory)
+    );
+
+    inodes.unlink(1, OsStr::new("b")).await?;
+
+    assert!(fs.get_file("/b").await?.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Attach,
+        AttachError,
+        GetDirectoryError,
+        GetErrorKind,
+        GetExt,
+    },
+    file_system::Options,
+    node::{
+        DataExt,
+        Depth,
+        Identified,
+    },
+    Directory,
+    File,
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn insert() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+    let dir = Directory::new(1);
+    let file = File::new(2);
+
+    dir.insert("b", Node::File(file.clone())).await?;
+    fs.insert("a", Node::Directory(dir.clone())).await?;
+
+    assert!(fs.get_dir("/a").await?.is_some_and(|found| found == dir));
+    assert!(fs
+        .get_file("/a/b")
+        .await?
+        .is_some_and(|found| found == file));
+    assert_eq!(file.read(|value| *value).await, 2);
+    assert_eq!(file.depth().await, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn insert_attached() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+    let file = fs.get_file_default("/a").await?;
+
+    assert!(matches!(
+        fs.insert("b", Node::File(file)).await,
+        Err(AttachError::Attached)
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn insert_joins_file_system() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::with_options(Options::default().max_nodes(2));
+    let dir = Directory::new(1);
+
+    fs.insert("a", Node::Directory(dir.clone())).await?;
+    dir.get_dir_default("b").await?;
+
+    assert!(matches!(
+        dir.get_dir_default("c").await,
+        Err(GetDirectoryError::Get(err)) if err.kind() == GetErrorKind::QuotaExceeded
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn insert_rebinds_hooks_and_ids() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+    let writes = Arc::new(AtomicUsize::new(0));
+    let dir = Directory::new(1);
+    let file = File::new(2);
+
+    fs.on_write({
+        let writes = writes.clone();
+
+        move |_, _| {
+            writes.fetch_add(1, Ordering::SeqCst);
+            async {}
+        }
+    });
+
+    let existing = fs.get_file_default("/x").await?;
+
+    dir.insert("b", Node::File(file.clone())).await?;
+    fs.insert("a", Node::Directory(dir.clone())).await?;
+    file.write(|mut value| *value = 3).await?;
+
+    assert_eq!(writes.load(Ordering::SeqCst), 1);
+
+    let ids = [fs.id().await, existing.id().await, dir.id().await, file.id().await];
+
+    assert_eq!(ids.into_iter().collect::<HashSet<_>>().len(), ids.len());
+
+    Ok(())
+}
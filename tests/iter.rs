@@ -0,0 +1,77 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use anyhow::Result;
+use futures::{
+    future,
+    StreamExt,
+};
+use memfs::{
+    directory::GetExt,
+    node::DataExt,
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn iter_extend() -> Result<()> {
+    let mut fs = FileSystem::<(), u32>::new();
+
+    fs.extend([(PathBuf::from("/a/one"), 1)]);
+    fs.extend([
+        (PathBuf::from("/a/one"), 2),
+        (PathBuf::from("/b/two"), 3),
+    ]);
+
+    let one = fs.get_file_default("/a/one").await?;
+    let two = fs.get_file_default("/b/two").await?;
+
+    assert_eq!(one.read(|value| *value).await, 2);
+    assert_eq!(two.read(|value| *value).await, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn iter_into_iter() {
+    let fs = [
+        (PathBuf::from("/a/b/one"), 1),
+        (PathBuf::from("/c"), 2),
+    ]
+    .into_iter()
+    .collect::<FileSystem<(), u32>>();
+
+    let mut paths = fs.into_iter().map(|(path, _)| path).collect::<Vec<_>>();
+
+    paths.sort();
+
+    assert_eq!(
+        paths,
+        [
+            Path::new("/a"),
+            Path::new("/a/b"),
+            Path::new("/a/b/one"),
+            Path::new("/c")
+        ]
+    );
+}
+
+#[tokio::test]
+async fn iter_into_stream() {
+    let fs = [
+        (PathBuf::from("/a/b/one"), 1),
+        (PathBuf::from("/c"), 2),
+    ]
+    .into_iter()
+    .collect::<FileSystem<(), u32>>();
+
+    let files = fs
+        .into_stream()
+        .filter(|(_, node)| future::ready(matches!(node, Node::File(_))))
+        .count()
+        .await;
+
+    assert_eq!(files, 2);
+}
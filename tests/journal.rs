@@ -0,0 +1,171 @@
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Entry,
+        GetExt,
+        Rename,
+    },
+    file_system::{
+        Options,
+        Record,
+        ReplayError,
+    },
+    node::{
+        DataExt,
+        Permissions,
+    },
+    FileSystem,
+};
+
+type Records = Arc<Mutex<Vec<Record<u32, u32>>>>;
+
+fn journaled() -> (FileSystem<u32, u32>, Records) {
+    let records = Records::default();
+    let fs = FileSystem::with_journal(Options::default(), records.clone());
+
+    (fs, records)
+}
+
+fn records(records: &Records) -> Vec<Record<u32, u32>> {
+    records
+        .lock()
+        .map(|records| records.clone())
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn journal_records() -> Result<()> {
+    let (fs, journal) = journaled();
+
+    fs.get_file_default("/a/b")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+    fs.rename("/a/b", "/c").await?;
+
+    if let Entry::Occupied(entry) = fs.entry("a").await {
+        entry.remove().await?;
+    }
+
+    assert_eq!(
+        records(&journal),
+        vec![
+            Record::CreateDir {
+                path: PathBuf::from("/a"),
+                value: 0,
+            },
+            Record::CreateFile {
+                path: PathBuf::from("/a/b"),
+                value: 0,
+            },
+            Record::WriteFile {
+                path: PathBuf::from("/a/b"),
+                value: 1,
+            },
+            Record::Rename {
+                from: PathBuf::from("/a/b"),
+                to: PathBuf::from("/c"),
+            },
+            Record::Remove {
+                path: PathBuf::from("/a"),
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn journal_replay() -> Result<()> {
+    let (fs, journal) = journaled();
+
+    fs.get_dir_default("/a/b")
+        .await?
+        .write(|mut value| *value = 2)
+        .await?;
+    fs.get_file_default("/a/b/c")
+        .await?
+        .write(|mut value| *value = 3)
+        .await?;
+    fs.get_file_default("/a/d")
+        .await?
+        .write(|mut value| *value = 4)
+        .await?;
+    fs.rename("/a/d", "/e").await?;
+
+    if let Some(file) = fs.get_file("/e").await? {
+        file.set_readonly(true).await;
+    }
+
+    let replayed = FileSystem::replay(records(&journal)).await?;
+
+    assert_eq!(
+        replayed
+            .get_dir_default("/a/b")
+            .await?
+            .read(|value| *value)
+            .await,
+        2
+    );
+    assert_eq!(
+        replayed
+            .get_file_default("/a/b/c")
+            .await?
+            .read(|value| *value)
+            .await,
+        3
+    );
+    assert!(replayed.get_file("/a/d").await?.is_none());
+    assert_eq!(
+        replayed
+            .get_file_default("/e")
+            .await?
+            .read(|value| *value)
+            .await,
+        4
+    );
+    assert!(replayed.get_file_default("/e").await?.readonly().await);
+
+    assert!(memfs::diff(&fs, &replayed, PartialEq::eq).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn journal_replay_missing() -> Result<()> {
+    let result = FileSystem::<u32, u32>::replay(vec![Record::Remove {
+        path: PathBuf::from("/a"),
+    }])
+    .await;
+
+    assert!(matches!(result, Err(ReplayError::NotFound(_))));
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[tokio::test]
+async fn journal_serde() -> Result<()> {
+    let (fs, journal) = journaled();
+
+    fs.get_file_default("/a/b")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+
+    let json = serde_json::to_string(&records(&journal))?;
+    let replayed =
+        FileSystem::replay(serde_json::from_str::<Vec<Record<u32, u32>>>(&json)?).await?;
+
+    assert!(memfs::diff(&fs, &replayed, PartialEq::eq).await?.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "json")]
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+        JsonError,
+        ToJson,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn json_round_trip() -> Result<()> {
+    let fs: FileSystem<(), String> = FileSystem::new();
+
+    fs.get_dir_default("/empty").await?;
+    fs.get_file_default("/a/b/file")
+        .await?
+        .write(|mut value| *value = String::from("content"))
+        .await?;
+
+    let json = fs.to_json().await?;
+    let imported: FileSystem<(), String> = FileSystem::from_json(&json).await?;
+
+    assert_eq!(imported.count().await?, fs.count().await?);
+    assert_eq!(imported.to_json().await?, json);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_fixture() -> Result<()> {
+    let fs: FileSystem<(), String> = FileSystem::from_json(
+        r#"{
+            "type": "dir",
+            "children": {
+                "config": {
+                    "type": "dir",
+                    "children": {
+                        "app.toml": { "type": "file", "value": "debug = true" }
+                    }
+                },
+                "empty": { "type": "file" }
+            }
+        }"#,
+    )
+    .await?;
+
+    let app = fs.get_file_default("/config/app.toml").await?;
+    let empty = fs.get_file_default("/empty").await?;
+
+    assert_eq!(app.read(|value| value.clone()).await, "debug = true");
+    assert_eq!(empty.read(|value| value.clone()).await, "");
+
+    let invalid = FileSystem::<(), String>::from_json(r#"{ "type": "file" }"#).await;
+
+    assert!(matches!(invalid, Err(JsonError::InvalidRoot)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_invalid_names() -> Result<()> {
+    for name in ["..", ".", "", "a/b", "/etc"] {
+        let json = format!(
+            r#"{{ "type": "dir", "children": {{ "{name}": {{ "type": "file" }} }} }}"#
+        );
+        let invalid = FileSystem::<(), String>::from_json(&json).await;
+
+        assert!(matches!(invalid, Err(JsonError::InvalidName(_))), "{name}");
+    }
+
+    Ok(())
+}
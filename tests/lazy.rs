@@ -0,0 +1,233 @@
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
+};
+
+use tokio::sync::oneshot;
+
+use anyhow::Result;
+use futures::StreamExt;
+use memfs::{
+    directory::{
+        Count,
+        GetDirectoryError,
+        GetErrorKind,
+        GetExt,
+        GetFileError,
+        List,
+        Probe,
+        Rename,
+        RenameError,
+        Walk,
+    },
+    file_system::{
+        Backend,
+        Options,
+        Stored,
+    },
+    node::{
+        DataExt,
+        WriteError,
+    },
+    FileSystem,
+};
+
+fn children(path: &Path) -> Vec<(OsString, Stored<(), u32>)> {
+    match path.to_str() {
+        Some("/") => vec![
+            (OsString::from("a"), Stored::Directory(())),
+            (OsString::from("b"), Stored::File(1)),
+        ],
+        Some("/a") => vec![(OsString::from("c"), Stored::File(2))],
+        _ => Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn lazy_loader() -> Result<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let fs = FileSystem::with_loader(Options::default(), move |path: PathBuf| {
+        counter.fetch_add(1, Ordering::SeqCst);
+
+        async move { Ok(children(&path)) }
+    });
+
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        fs.get_file_default("/b").await?.read(|value| *value).await,
+        1
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        fs.get_file_default("/a/c")
+            .await?
+            .read(|value| *value)
+            .await,
+        2
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(fs.exists("/a/c").await);
+    assert!(!fs.exists("/a/d").await);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lazy_loader_retry() -> Result<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let fs = FileSystem::with_loader(Options::default(), move |path: PathBuf| {
+        let call = counter.fetch_add(1, Ordering::SeqCst);
+
+        async move {
+            match call {
+                0 => Err(io::Error::other("unavailable")),
+                _ => Ok(children(&path)),
+            }
+        }
+    });
+
+    assert!(!fs.exists("/b").await);
+    assert!(fs.exists("/b").await);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lazy_backend() -> Result<()> {
+    let backend = Arc::new(Mutex::new(BTreeMap::new()));
+
+    backend.store("/a".as_ref(), Stored::Directory(1))?;
+    backend.store("/a/b".as_ref(), Stored::File(2))?;
+
+    let fs: FileSystem<u32, u32> =
+        FileSystem::with_lazy_backend(Options::default(), backend.clone()).await?;
+
+    assert_eq!(
+        fs.get_file_default("/a/b")
+            .await?
+            .read(|value| *value)
+            .await,
+        2
+    );
+
+    fs.get_file_default("/a/c")
+        .await?
+        .write(|mut value| *value = 3)
+        .await?;
+
+    assert_eq!(backend.load("/a/c".as_ref())?, Some(Stored::File(3)));
+    assert_eq!(backend.list("/a".as_ref())?, vec!["b", "c"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lazy_loader_error() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_loader(Options::default(), |_| async {
+        Err(io::Error::other("unavailable"))
+    });
+
+    let Err(GetFileError::Get(err)) = fs.get_file_default("/b").await else {
+        anyhow::bail!("expected a get error");
+    };
+
+    assert_eq!(err.kind(), GetErrorKind::LoadFailed);
+    assert_eq!(
+        fs.try_list().await.map_err(|err| err.to_string()),
+        Err(String::from("unavailable"))
+    );
+    assert!(fs.list().await.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lazy_loader_error_propagated() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_loader(Options::default(), |_| async {
+        Err(io::Error::other("unavailable"))
+    });
+
+    assert!(fs.count().await.is_err());
+    assert!(memfs::diff(&fs, &fs, PartialEq::eq).await.is_err());
+    assert!(matches!(fs.walk_parallel(1).next().await, Some(Err(_))));
+    assert!(matches!(
+        fs.rename("/a", "/b").await,
+        Err(RenameError::Write(WriteError::LoadFailed))
+    ));
+    assert!(matches!(
+        fs.entry("b").await.or_insert_file(2).await,
+        Err(WriteError::LoadFailed)
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lazy_loader_quota() -> Result<()> {
+    let fs = FileSystem::with_loader(Options::default().max_nodes(1), |path: PathBuf| async move {
+        Ok(children(&path))
+    });
+
+    assert_eq!(
+        fs.try_list().await.map_err(|err| err.kind()).err(),
+        Some(io::ErrorKind::StorageFull)
+    );
+    assert!(fs.list().await.is_empty());
+    assert!(matches!(
+        fs.get_dir_default("/a").await,
+        Err(GetDirectoryError::Get(err)) if err.kind() == GetErrorKind::LoadFailed
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lazy_loader_concurrent() -> Result<()> {
+    let (sender, receiver) = oneshot::channel::<()>();
+    let receiver = Arc::new(Mutex::new(Some(receiver)));
+    let fs = Arc::new(FileSystem::with_loader(Options::default(), move |path: PathBuf| {
+        let receiver = receiver.lock().ok().and_then(|mut receiver| receiver.take());
+
+        async move {
+            if let Some(receiver) = receiver {
+                receiver.await.ok();
+            }
+
+            Ok(children(&path))
+        }
+    }));
+
+    let first = tokio::spawn({
+        let fs = fs.clone();
+
+        async move { fs.list().await.len() }
+    });
+
+    tokio::task::yield_now().await;
+
+    assert_eq!(fs.list().await.len(), 2);
+
+    sender.send(()).ok();
+
+    assert_eq!(first.await?, 2);
+    assert!(fs.exists("/a/c").await);
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use std::ffi::OsString;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetExt,
+        List,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn list_sorted() -> Result<()> {
+    let fs = FileSystem::<(), ()>::default();
+
+    for name in ["/c", "/a/x", "/d/y", "/b"] {
+        fs.get_file_default(name).await?;
+    }
+
+    let names =
+        |listed: Vec<(OsString, _)>| listed.into_iter().map(|(name, _)| name).collect::<Vec<_>>();
+
+    assert_eq!(names(fs.list_sorted(false).await), ["a", "b", "c", "d"]);
+    assert_eq!(names(fs.list_sorted(true).await), ["a", "d", "b", "c"]);
+    assert_eq!(fs.list().await.len(), 4);
+
+    Ok(())
+}
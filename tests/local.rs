@@ -0,0 +1,163 @@
+#![cfg(feature = "local")]
+
+use std::{
+    ffi::OsString,
+    path::Path,
+    rc::Rc,
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetErrorKind,
+        GetExt,
+        GetFileError,
+        List,
+    },
+    local::{
+        FileSystem,
+        Node,
+    },
+    node::{
+        Permissions,
+        WriteError,
+    },
+};
+
+fn outcome<T>(result: Result<Option<T>, GetFileError>) -> Result<bool, Option<GetErrorKind>> {
+    match result {
+        Ok(file) => Ok(file.is_some()),
+        Err(GetFileError::Get(err)) => Err(Some(err.kind())),
+        Err(GetFileError::UnexpectedDirectory) => Err(None),
+    }
+}
+
+#[test]
+fn local() -> Result<()> {
+    let fs = FileSystem::<(), Rc<str>>::new();
+    let file = fs.get_file_default("/a/b/file")?;
+
+    file.write(|mut value| *value = Rc::from("content"))?;
+
+    assert_eq!(file.path(), Path::new("/a/b/file"));
+    assert_eq!(&*fs.get_file("/a/b/file")?.unwrap().read(|value| value.clone())?, "content");
+    assert_eq!(fs.count(), 1);
+    assert_eq!(fs.get_dir("/a")?.unwrap().count_dir(), 1);
+    assert!(fs.get_file("/a/missing")?.is_none());
+
+    file.set_readonly(true);
+
+    assert!(matches!(
+        file.write(|mut value| *value = Rc::from("denied")),
+        Err(WriteError::PermissionDenied)
+    ));
+
+    let b = fs.get_dir_default("/a/b")?;
+
+    assert!(matches!(b.remove("file")?, Some(Node::File(_))));
+    assert!(file.parent().is_some());
+    assert_eq!(b.count(), 0);
+
+    assert!(fs.get("/a/b/../../x/..")?.is_none());
+
+    fs.get_file_default("/a/b/kept")?;
+    b.set_readonly(true);
+
+    let denied = b.get_file_default("new").unwrap_err();
+
+    assert!(matches!(
+        denied,
+        GetFileError::Get(err) if err.kind() == GetErrorKind::PermissionDenied
+    ));
+
+    assert!(matches!(b.remove("kept"), Err(WriteError::PermissionDenied)));
+    assert_eq!(b.count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn local_reentrant() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::new();
+    let file = fs.get_file_default("/a/file")?;
+
+    let created = fs.read(|_| fs.get_dir_default("/a/b").map(|dir| dir.path()))??;
+
+    assert_eq!(created, Path::new("/a/b"));
+    assert_eq!(fs.get_dir("/a")?.unwrap().count(), 2);
+
+    assert!(matches!(
+        file.read(|_| file.write(|mut value| *value = 1))?,
+        Err(WriteError::Borrowed)
+    ));
+    assert!(file.write(|_| file.read(|value| *value).is_err())?);
+    assert!(fs.write(|_| fs.remove("a").map(|node| node.is_some()))??);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn local_parity() -> Result<()> {
+    let local = FileSystem::<(), u32>::new();
+    let shared = memfs::FileSystem::<(), u32>::new();
+
+    for path in ["/c/d", "/a/b/file", "/a/z", "/a/b/../e"] {
+        local.get_file_default(path)?;
+        shared.get_file_default(path).await?;
+    }
+
+    assert_eq!(local.count(), shared.count().await?);
+    assert_eq!(local.count_dir(), shared.count_dir().await?);
+    assert_eq!(local.count_file(), shared.count_file().await?);
+
+    let listed = shared
+        .get_dir("/a")
+        .await?
+        .unwrap()
+        .list_sorted(false)
+        .await
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    let entries = local
+        .get_dir("/a")?
+        .unwrap()
+        .entries()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<OsString>>();
+
+    assert_eq!(entries, listed);
+
+    for path in [
+        "/a/b/file",
+        "/a/b/missing",
+        "/a/b",
+        "/a/b/file/x",
+        "/a/./b/../z",
+        "/..",
+        "a/b/file",
+    ] {
+        assert_eq!(
+            outcome(local.get_file(path)),
+            outcome(shared.get_file(path).await),
+            "{path}"
+        );
+    }
+
+    local.get_dir("/a")?.unwrap().set_readonly(true);
+    shared
+        .get_dir("/a")
+        .await?
+        .unwrap()
+        .set_readonly(true)
+        .await;
+
+    assert_eq!(
+        outcome(local.get_file_default("/a/new").map(Some)),
+        outcome(shared.get_file_default("/a/new").await.map(Some))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use futures::future;
+use memfs::{
+    directory::GetExt,
+    file::Lock,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn lock_shared() -> Result<()> {
+    let fs = FileSystem::<(), u32>::default();
+    let file = fs.get_file_default("/a").await?;
+    let shared = file.lock_shared().await;
+
+    assert!(file.try_lock_shared().await.is_some());
+    assert!(file.try_lock_exclusive().await.is_none());
+
+    drop(shared);
+
+    assert!(file.try_lock_exclusive().await.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lock_exclusive() -> Result<()> {
+    let fs = FileSystem::<(), u32>::default();
+    let file = fs.get_file_default("/a").await?;
+    let exclusive = file.lock_exclusive().await;
+
+    file.write(|mut value| *value = 1).await?;
+
+    assert_eq!(file.read(|value| *value).await, 1);
+    assert!(file.try_lock_shared().await.is_none());
+    assert!(file.lock_shared_timeout(future::ready(())).await.is_none());
+    assert!(file
+        .lock_exclusive_timeout(future::ready(()))
+        .await
+        .is_none());
+
+    drop(exclusive);
+
+    assert!(file.lock_shared_timeout(future::pending()).await.is_some());
+
+    Ok(())
+}
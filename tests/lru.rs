@@ -0,0 +1,95 @@
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Entry,
+        GetExt,
+        Probe,
+    },
+    file_system::Options,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn lru_evicts_least_recent() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().capacity(2));
+
+    fs.get_file_default("/a").await?;
+    fs.get_file_default("/b").await?;
+    fs.get_file_default("/c").await?;
+
+    assert!(!fs.exists("/a").await);
+    assert!(fs.exists("/b").await);
+    assert!(fs.exists("/c").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lru_access_refreshes() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().capacity(2));
+    let a = fs.get_file_default("/a").await?;
+
+    fs.get_file_default("/dir/b").await?;
+    a.read(|value| *value).await;
+    fs.get_file_default("/c").await?;
+
+    assert!(fs.exists("/a").await);
+    assert!(!fs.exists("/dir/b").await);
+    assert!(fs.exists("/dir").await);
+    assert!(fs.exists("/c").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lru_removed_not_counted() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().capacity(2));
+
+    fs.get_file_default("/a").await?;
+    fs.get_file_default("/b").await?;
+
+    if let Entry::Occupied(entry) = fs.entry("b").await {
+        entry.remove().await?;
+    }
+
+    fs.get_file_default("/c").await?;
+
+    assert!(fs.exists("/a").await);
+    assert!(fs.exists("/c").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lru_on_evict() -> Result<()> {
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    let events = evicted.clone();
+    let fs: FileSystem<(), u32> =
+        FileSystem::with_options(Options::default().capacity(1).on_evict(move |path| {
+            if let Ok(mut events) = events.lock() {
+                events.push(path.to_path_buf());
+            }
+        }));
+
+    fs.get_file_default("/a/b").await?;
+    fs.get_file_default("/c").await?;
+
+    assert_eq!(
+        evicted
+            .lock()
+            .map(|events| events.clone())
+            .unwrap_or_default(),
+        vec![PathBuf::from("/a/b")]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+    },
+    error::ResolveError,
+    memfs,
+    node::{
+        DataExt,
+        NodeError,
+    },
+    Error,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn memfs() -> Result<()> {
+    let fs: FileSystem<(), String> = memfs! {
+        "etc" => {
+            "config.toml" => file(String::from("key = 1")),
+            "conf.d" => {},
+        },
+        "empty" => file(),
+    }
+    .await?;
+
+    assert_eq!(fs.count().await?, 2);
+    assert_eq!(fs.get_dir_default("/etc").await?.count().await?, 2);
+    assert!(fs.get_dir("/etc/conf.d").await?.is_some());
+    assert_eq!(
+        fs.get_file_default("/etc/config.toml")
+            .await?
+            .read(|value| value.clone())
+            .await,
+        "key = 1"
+    );
+    assert!(
+        fs.get_file_default("/empty")
+            .await?
+            .read(|value| value.is_empty())
+            .await
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn memfs_empty() -> Result<()> {
+    let fs: FileSystem<(), ()> = memfs! {}.await?;
+
+    assert_eq!(fs.count().await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn memfs_conflict() {
+    let mut calls = 0;
+    let result: Result<FileSystem<(), ()>, Error> = memfs! {
+        {
+            calls += 1;
+            "a"
+        } => file(),
+        "a" => {},
+    }
+    .await;
+
+    assert_eq!(calls, 1);
+    assert!(matches!(
+        result,
+        Err(Error::Resolve(ResolveError::Node(NodeError::UnexpectedFile)))
+    ));
+}
+
+macro_rules! wide {
+    (@entries [] $units:tt -> [$($entries:tt)*]) => {
+        memfs! { $($entries)* }
+    };
+    (@entries [$tens:tt $($rest:tt)*] [$($units:tt)*] -> [$($entries:tt)*]) => {
+        wide!(@entries [$($rest)*] [$($units)*] -> [
+            $($entries)*
+            $(concat!("file-", $tens, $units) => file(),)*
+        ])
+    };
+    ($($tens:tt)*) => {
+        wide!(@entries [$($tens)*] [0 1 2 3 4 5 6 7 8 9] -> [])
+    };
+}
+
+#[tokio::test]
+async fn memfs_wide() -> Result<()> {
+    let fs: FileSystem<(), ()> = wide!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19).await?;
+
+    assert_eq!(fs.count().await?, 200);
+    assert!(fs.get_file("/file-199").await?.is_some());
+
+    Ok(())
+}
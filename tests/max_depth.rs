@@ -0,0 +1,35 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetDirectoryError,
+        GetErrorKind,
+        GetExt,
+        GetFileError,
+    },
+    file_system::Options,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn max_depth() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::with_options(Options::default().max_depth(2));
+
+    fs.get_file_default("/a/b").await?;
+
+    assert!(matches!(
+        fs.get_file_default("/a/b/c").await,
+        Err(GetFileError::Get(err)) if err.kind() == GetErrorKind::UnexpectedFile
+    ));
+    assert!(matches!(
+        fs.get_dir_default("/a/c/d").await,
+        Err(GetDirectoryError::Get(err)) if err.kind() == GetErrorKind::DepthLimitExceeded
+    ));
+    assert!(matches!(
+        fs.get_dir_default("/b/c/d/e").await,
+        Err(GetDirectoryError::Get(err)) if err.kind() == GetErrorKind::DepthLimitExceeded
+    ));
+    assert!(fs.get_dir("/b/c").await?.is_some());
+    assert!(fs.get_dir("/b/c/d").await?.is_none());
+
+    Ok(())
+}
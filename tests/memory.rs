@@ -0,0 +1,41 @@
+use std::ffi::OsStr;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetExt,
+        MemoryUsage,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn memory_usage() -> Result<()> {
+    let fs = FileSystem::<(), String>::new();
+    let empty = fs.memory_usage().await;
+
+    fs.get_file_default("/a/one")
+        .await?
+        .write(|mut value| *value = String::from("content"))
+        .await?;
+    fs.get_file_default("/b/two").await?;
+
+    let usage = fs.memory_usage().await;
+    let with_len = fs.memory_usage_with_len().await;
+
+    assert!(usage.structure > empty.structure);
+    assert_eq!(usage.contents, None);
+    assert_eq!(with_len.contents, Some(7));
+    assert_eq!(with_len.structure, usage.structure);
+    assert_eq!(with_len.total(), usage.structure + 7);
+
+    let a = &with_len.children[OsStr::new("a")];
+    let b = &with_len.children[OsStr::new("b")];
+
+    assert_eq!(a.contents, Some(7));
+    assert_eq!(b.contents, Some(0));
+    assert!(a.structure + b.structure < with_len.structure);
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use std::path::Path;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Conflict,
+        GetExt,
+        Merge,
+        MergeError,
+        Resolution,
+    },
+    file_system::Options,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn merge_from() -> Result<()> {
+    let ours: FileSystem<(), u32> = FileSystem::new();
+    let theirs: FileSystem<(), u32> = FileSystem::new();
+
+    ours.get_file_default("/a/ours").await?;
+    theirs.get_file_default("/a/theirs/file").await?;
+
+    for (path, ours_value, theirs_value) in [("/keep", 1, 2), ("/take", 1, 2), ("/sum", 1, 2)] {
+        ours.get_file_default(path)
+            .await?
+            .write(|mut value| *value = ours_value)
+            .await?;
+        theirs
+            .get_file_default(path)
+            .await?
+            .write(|mut value| *value = theirs_value)
+            .await?;
+    }
+
+    ours.get_file_default("/type").await?;
+    theirs.get_dir_default("/type").await?;
+
+    ours.merge_from(&theirs, |path, conflict| match (path.to_str(), conflict) {
+        (Some("/take"), _) | (_, Conflict::Type) => Resolution::Theirs,
+        (Some("/sum"), Conflict::Data(ours, theirs)) => Resolution::Combined(ours + theirs),
+        _ => Resolution::Ours,
+    })
+    .await?;
+
+    assert!(ours.get_file("/a/ours").await?.is_some());
+    assert!(ours.get_file("/a/theirs/file").await?.is_some());
+    assert!(ours.get_dir("/type").await?.is_some());
+
+    for (path, expected) in [("/keep", 1), ("/take", 2), ("/sum", 3)] {
+        let file = ours.get_file_default(path).await?;
+
+        assert_eq!(file.read(|value| *value).await, expected);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn merge_from_case_insensitive() -> Result<()> {
+    let options = || Options::default().case_insensitive(true);
+    let ours: FileSystem<(), u32> = FileSystem::with_options(options());
+    let theirs: FileSystem<(), u32> = FileSystem::with_options(options());
+
+    ours.get_file_default("/Dir/ours").await?;
+    ours.get_file_default("/File")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+    theirs.get_file_default("/dir/theirs").await?;
+    theirs
+        .get_file_default("/file")
+        .await?
+        .write(|mut value| *value = 2)
+        .await?;
+
+    ours.merge_from(&theirs, |_, conflict| match conflict {
+        Conflict::Data(ours, theirs) => Resolution::Combined(ours + theirs),
+        Conflict::Directory(..) | Conflict::Type => Resolution::Ours,
+    })
+    .await?;
+
+    assert!(ours.get_file("/dir/ours").await?.is_some());
+    assert!(ours.get_file("/DIR/theirs").await?.is_some());
+    assert_eq!(
+        ours.get_file_default("/file")
+            .await?
+            .read(|value| *value)
+            .await,
+        3
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn merge_from_directory_values() -> Result<()> {
+    let ours: FileSystem<u32, u32> = FileSystem::new();
+    let theirs: FileSystem<u32, u32> = FileSystem::new();
+
+    ours.get_dir_default("/keep").await?;
+    ours.get_dir_default("/take").await?;
+
+    for path in ["/keep", "/take"] {
+        theirs
+            .get_dir_default(path)
+            .await?
+            .write(|mut value| *value = 1)
+            .await?;
+    }
+
+    ours.merge_from(&theirs, |path, conflict| match (path.to_str(), conflict) {
+        (Some("/take"), Conflict::Directory(..)) => Resolution::Theirs,
+        _ => Resolution::Ours,
+    })
+    .await?;
+
+    for (path, expected) in [("/keep", 0), ("/take", 1)] {
+        let dir = ours.get_dir_default(path).await?;
+
+        assert_eq!(dir.read(|value| *value).await, expected);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn merge_from_combined_type() -> Result<()> {
+    let ours: FileSystem<(), u32> = FileSystem::new();
+    let theirs: FileSystem<(), u32> = FileSystem::new();
+
+    ours.get_file_default("/a").await?;
+    theirs.get_dir_default("/a").await?;
+
+    let result = ours
+        .merge_from(&theirs, |_, _| Resolution::Combined(1))
+        .await;
+
+    assert!(matches!(result, Err(MergeError::Combined(path)) if path == Path::new("/a")));
+    assert!(ours.get_file("/a").await?.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn merge_from_combined_unapplied() -> Result<()> {
+    let ours: FileSystem<(), u32> = FileSystem::new();
+    let theirs: FileSystem<(), u32> = FileSystem::new();
+
+    ours.get_file_default("/a").await?;
+    ours.get_file_default("/b").await?;
+    theirs.get_dir_default("/a").await?;
+    theirs.get_file_default("/c").await?;
+    theirs
+        .get_file_default("/b")
+        .await?
+        .write(|mut value| *value = 2)
+        .await?;
+
+    let result = ours
+        .merge_from(&theirs, |_, conflict| match conflict {
+            Conflict::Data(..) => Resolution::Theirs,
+            _ => Resolution::Combined(1),
+        })
+        .await;
+
+    assert!(matches!(result, Err(MergeError::Combined(path)) if path == Path::new("/a")));
+    assert!(ours.get_file("/c").await?.is_none());
+    assert_eq!(
+        ours.get_file_default("/b")
+            .await?
+            .read(|value| *value)
+            .await,
+        0
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn merge_from_equal_files() -> Result<()> {
+    let ours: FileSystem<(), u32> = FileSystem::new();
+    let theirs: FileSystem<(), u32> = FileSystem::new();
+
+    for fs in [&ours, &theirs] {
+        fs.get_file_default("/a")
+            .await?
+            .write(|mut value| *value = 1)
+            .await?;
+    }
+
+    ours.merge_from(&theirs, |path, _| panic!("unexpected conflict at {path:?}"))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn merge_from_ancestor() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+    let dir = fs.get_dir_default("/a").await?;
+
+    fs.get_file_default("/b").await?;
+
+    let result = dir.merge_from(&fs, |_, _| Resolution::Theirs).await;
+
+    assert!(matches!(result, Err(MergeError::Ancestor)));
+    assert!(fs.get_file("/a/b").await?.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Get,
+        GetExt,
+        GetType,
+    },
+    node::Meta,
+    FileSystem,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Owner(&'static str);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Tags(Vec<&'static str>);
+
+#[tokio::test]
+async fn meta_typed() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+    let file = fs.get_file_default("/a/b").await?;
+
+    assert_eq!(file.set_meta(Owner("alice")).await, None);
+    assert_eq!(file.set_meta(Tags(vec!["x"])).await, None);
+    assert_eq!(file.set_meta(Owner("bob")).await, Some(Owner("alice")));
+
+    assert_eq!(file.get_meta::<Owner>().await, Some(Owner("bob")));
+    assert_eq!(file.get_meta::<Tags>().await, Some(Tags(vec!["x"])));
+
+    assert_eq!(file.remove_meta::<Tags>().await, Some(Tags(vec!["x"])));
+    assert_eq!(file.get_meta::<Tags>().await, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn meta_nodes() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.get_dir_default("/a")
+        .await?
+        .set_meta(Owner("alice"))
+        .await;
+    fs.get_file_default("/b")
+        .await?
+        .set_meta(Owner("bob"))
+        .await;
+
+    for (path, owner) in [("/a", "alice"), ("/b", "bob")] {
+        let meta = match fs.get(path, GetType::File).await? {
+            Some(node) => node.get_meta::<Owner>().await,
+            None => None,
+        };
+
+        assert_eq!(meta, Some(Owner(owner)));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+#![cfg(unix)]
+
+use std::{
+    ffi::OsStr,
+    os::unix::ffi::OsStrExt,
+    path::Path,
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+    },
+    fs,
+    node::Named,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn non_utf8_names() -> Result<()> {
+    let a = OsStr::from_bytes(b"file-\xff");
+    let b = OsStr::from_bytes(b"file-\xfe");
+    let fs: FileSystem<(), ()> = FileSystem::new();
+    let file = fs.get_file_default(Path::new("/").join(a)).await?;
+
+    fs.get_file_default(Path::new("/").join(b)).await?;
+
+    assert_eq!(fs.count().await?, 2);
+    assert_eq!(file.name().await.as_deref(), Some(a));
+    assert!(fs
+        .get_file(Path::new("/").join(a))
+        .await?
+        .is_some_and(|found| found == file));
+
+    let entries = fs::read_dir(&fs, "/").await?;
+
+    assert!(entries.iter().any(|entry| entry.file_name() == a));
+    assert!(entries.iter().any(|entry| entry.file_name() == b));
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Get,
+        GetType,
+    },
+    node::{
+        NodeError,
+        NodeKind,
+    },
+    Directory,
+    File,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn typed_accessors() -> Result<()> {
+    let fs = FileSystem::<(), ()>::default();
+    let dir = fs.get_default("/a", GetType::Directory).await?;
+    let file = fs.get_default("/a/b", GetType::File).await?;
+
+    assert!(dir.as_dir().is_some());
+    assert!(dir.as_file().is_none());
+    assert!(file.as_file().is_some());
+    assert!(file.as_dir().is_none());
+
+    assert!(matches!(
+        file.clone().into_dir(),
+        Err(NodeError::UnexpectedFile)
+    ));
+    assert!(matches!(
+        dir.clone().into_file(),
+        Err(NodeError::UnexpectedDirectory)
+    ));
+
+    let dir = Directory::try_from(dir)?;
+    let file = File::try_from(file)?;
+
+    assert_eq!(
+        fs.get_default("/a", GetType::Directory).await?.into_dir()?,
+        dir
+    );
+    assert_eq!(
+        fs.get_default("/a/b", GetType::File).await?.into_file()?,
+        file
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn kind() -> Result<()> {
+    let fs = FileSystem::<(), ()>::default();
+    let dir = fs.get_default("/a", GetType::Directory).await?;
+    let file = fs.get_default("/a/b", GetType::File).await?;
+
+    assert_eq!(dir.kind(), NodeKind::Directory);
+    assert_eq!(file.kind(), NodeKind::File);
+
+    Ok(())
+}
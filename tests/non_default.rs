@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        CreateAll,
+        GetExt,
+    },
+    file_system::{
+        Options,
+        Stored,
+    },
+    node::DataExt,
+    FileSystem,
+    Node,
+};
+
+#[derive(Debug, Eq, PartialEq)]
+struct Handle(u32);
+
+#[tokio::test]
+async fn non_default_files() -> Result<()> {
+    let fs = FileSystem::<(), Handle>::new();
+
+    fs.get_file_or_create_with("/a/b", || Handle(1)).await?;
+
+    let file = fs.get_file_or_create_with("/a/b", || Handle(2)).await?;
+
+    assert_eq!(file.read(|value| value.0).await, 1);
+    assert_eq!(file.replace(Handle(3)).await?, Handle(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_default_root() -> Result<()> {
+    let fs = FileSystem::<Handle, Handle>::with_root(Options::default(), Handle(0));
+
+    let dir = match fs.entry("a").await.or_insert_dir(Handle(1)).await? {
+        Node::Directory(dir) => dir,
+        Node::File(_) => anyhow::bail!("expected a directory"),
+    };
+
+    dir.entry("b").await.or_insert_file(Handle(2)).await?;
+
+    assert_eq!(fs.read(|value| value.0).await, 0);
+    assert_eq!(dir.read(|value| value.0).await, 1);
+
+    let file = fs.get_file("/a/b").await?;
+
+    assert_eq!(
+        match file {
+            Some(file) => Some(file.read(|value| value.0).await),
+            None => None,
+        },
+        Some(2)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_default_create_all() -> Result<()> {
+    let fs = FileSystem::<Handle, Handle>::with_root(Options::default(), Handle(0));
+
+    fs.create_all([(PathBuf::from("/a/b/c"), Stored::File(Handle(2)))], || {
+        Handle(1)
+    })
+    .await?;
+
+    let dir = fs.get_dir("/a/b").await?;
+    let file = fs.get_file("/a/b/c").await?;
+
+    assert_eq!(
+        match dir {
+            Some(dir) => Some(dir.read(|value| value.0).await),
+            None => None,
+        },
+        Some(1)
+    );
+    assert_eq!(
+        match file {
+            Some(file) => Some(file.read(|value| value.0).await),
+            None => None,
+        },
+        Some(2)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_default_dirs() -> Result<()> {
+    let fs = FileSystem::<Handle, ()>::with_root(Options::default(), Handle(0));
+
+    let dir = fs.get_dir_or_create_with("/a/b", || Handle(1)).await?;
+    let parent = fs.get_dir("/a").await?;
+
+    assert_eq!(dir.read(|value| value.0).await, 1);
+    assert_eq!(
+        match parent {
+            Some(parent) => Some(parent.read(|value| value.0).await),
+            None => None,
+        },
+        Some(1)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_default_builder() -> Result<()> {
+    let fs: FileSystem<(), Handle> = FileSystem::builder()
+        .file("/a/b", Handle(1))
+        .file("/a/b", Handle(2))
+        .build()
+        .await?;
+
+    let file = fs.get_file("/a/b").await?;
+
+    assert_eq!(
+        match file {
+            Some(file) => Some(file.read(|value| value.0).await),
+            None => None,
+        },
+        Some(2)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "notify")]
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use futures::StreamExt;
+use memfs::{
+    directory::{
+        Attach,
+        GetExt,
+    },
+    node::DataExt,
+    FileSystem,
+};
+use notify_types::event::{
+    CreateKind,
+    DataChange,
+    Event,
+    EventKind,
+    ModifyKind,
+    RemoveKind,
+};
+
+#[tokio::test]
+async fn notify() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let mut events = fs.watch().map(Event::from);
+
+    fs.get_file_default("/a")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+    fs.detach("a").await?;
+
+    let path = PathBuf::from("/a");
+
+    assert_eq!(
+        events.next().await,
+        Some(Event::new(EventKind::Create(CreateKind::Any)).add_path(path.clone()))
+    );
+    assert_eq!(
+        events.next().await,
+        Some(
+            Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Any)))
+                .add_path(path.clone())
+        )
+    );
+    assert_eq!(
+        events.next().await,
+        Some(Event::new(EventKind::Remove(RemoveKind::Any)).add_path(path))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anyhow::Result;
+use futures::{
+    AsyncReadExt,
+    AsyncWriteExt,
+};
+use memfs::{
+    directory::GetExt,
+    file::Open,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn open_reader_and_writer() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+    let file = fs.get_file_default("/file").await?;
+
+    file.write(|mut value| *value = b"previous content".to_vec())
+        .await?;
+
+    let mut writer = file.open_writer().await?;
+
+    writer.write_all(b"hello, ").await?;
+    writer.write_all(b"world").await?;
+    writer.close().await?;
+
+    assert!(writer.write_all(b"!").await.is_err());
+
+    drop(writer);
+
+    let mut content = String::new();
+
+    file.open_reader()
+        .await
+        .read_to_string(&mut content)
+        .await?;
+
+    assert_eq!(content, "hello, world");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn open_writer_dropped() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+    let file = fs.get_file_default("/file").await?;
+
+    fs.on_write(|_, _| tokio::time::sleep(std::time::Duration::from_millis(1)));
+    file.write(|mut value| *value = b"previous".to_vec()).await?;
+
+    let mut writer = file.open_writer().await?;
+
+    writer.write_all(b"discarded").await?;
+    drop(writer);
+
+    assert_eq!(file.read(|value| value.clone()).await, b"previous");
+
+    file.write(|mut value| *value = b"next".to_vec()).await?;
+
+    assert_eq!(file.read(|value| value.clone()).await, b"next");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn open_writer_unlocked() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+    let file = fs.get_file_default("/file").await?;
+    let mut writer = file.open_writer().await?;
+
+    writer.write_all(b"buffered").await?;
+    file.write(|mut value| *value = b"direct".to_vec()).await?;
+
+    assert_eq!(file.read(|value| value.clone()).await, b"direct");
+
+    writer.close().await?;
+
+    assert_eq!(file.read(|value| value.clone()).await, b"buffered");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn open_reader_unlocked() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+    let file = fs.get_file_default("/file").await?;
+
+    file.write(|mut value| *value = b"opened".to_vec()).await?;
+
+    let mut reader = file.open_reader().await;
+
+    file.write(|mut value| *value = b"written".to_vec()).await?;
+
+    let mut content = String::new();
+
+    reader.read_to_string(&mut content).await?;
+
+    assert_eq!(content, "opened");
+    assert_eq!(file.read(|value| value.clone()).await, b"written");
+
+    Ok(())
+}
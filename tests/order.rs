@@ -0,0 +1,51 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Attach,
+        GetExt,
+    },
+    file_system::{
+        Options,
+        Order,
+    },
+    fs,
+    FileSystem,
+};
+
+async fn names(fs: &FileSystem<(), ()>) -> Result<Vec<String>> {
+    Ok(fs::read_dir(fs, "/")
+        .await?
+        .iter()
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect())
+}
+
+#[tokio::test]
+async fn sorted_children() -> Result<()> {
+    let fs: FileSystem<(), ()> =
+        FileSystem::with_options(Options::default().order(Order::Sorted).shards(4));
+
+    for name in ["/c", "/a", "/d", "/b"] {
+        fs.get_file_default(name).await?;
+    }
+
+    assert_eq!(names(&fs).await?, ["a", "b", "c", "d"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn insertion_children() -> Result<()> {
+    let fs: FileSystem<(), ()> =
+        FileSystem::with_options(Options::default().order(Order::Insertion));
+
+    for name in ["/c", "/a", "/d", "/b"] {
+        fs.get_file_default(name).await?;
+    }
+
+    fs.detach("a").await?;
+
+    assert_eq!(names(&fs).await?, ["c", "d", "b"]);
+
+    Ok(())
+}
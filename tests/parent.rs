@@ -0,0 +1,37 @@
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    node::{
+        Child,
+        Parent,
+    },
+    FileSystem,
+    Node,
+};
+
+async fn count<P>(parent: &P) -> usize
+where
+    P: Parent<(), ()> + Sync,
+{
+    parent.children().await.len()
+}
+
+#[tokio::test]
+async fn children() -> Result<()> {
+    let fs = FileSystem::<(), ()>::default();
+
+    fs.get_file_default("/a/b").await?;
+    fs.get_file_default("/a/c").await?;
+
+    let dir = fs.get_dir_default("/a").await?;
+
+    assert_eq!(count(&dir).await, 2);
+    assert_eq!(count(&*fs).await, 1);
+
+    for (_, node) in dir.children().await {
+        assert!(matches!(&node, Node::File(_)));
+        assert!(node.parent().await.is_some_and(|parent| parent == dir));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetExt,
+        GetSegments,
+    },
+    path::{
+        MemPath,
+        MemPathBuf,
+        MemPathError,
+    },
+    FileSystem,
+};
+
+#[test]
+fn path_normalize() -> Result<()> {
+    assert_eq!(MemPathBuf::new("/a//b/./c/")?.as_str(), "/a/b/c");
+    assert_eq!(MemPathBuf::new("a\\b")?.as_str(), "a/b");
+    assert_eq!(MemPathBuf::new("/a/../../b")?.as_str(), "/b");
+    assert_eq!(MemPathBuf::new("../a/../b")?.as_str(), "../b");
+    assert_eq!(MemPathBuf::new("./")?.as_str(), ".");
+    assert_eq!(MemPathBuf::new("/")?.as_str(), "/");
+
+    assert!(matches!(
+        MemPathBuf::new("C:/a"),
+        Err(MemPathError::UnexpectedPrefix(_))
+    ));
+    assert!(matches!(
+        MemPathBuf::new("/a\0b"),
+        Err(MemPathError::InvalidSegment(_))
+    ));
+    assert!(matches!(
+        MemPath::new("/a//b"),
+        Err(MemPathError::NotNormalized { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn path_accepted() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let path = MemPathBuf::new("/a//b/file")?;
+
+    fs.get_file_default(&path).await?;
+
+    let borrowed = path.as_mem_path();
+
+    assert!(fs.get_file(borrowed).await?.is_some());
+    assert!(fs.get_segments(borrowed.segments()).await?.is_some());
+    assert_eq!(borrowed.segments().collect::<Vec<_>>(), ["a", "b", "file"]);
+    assert_eq!(MemPath::new("/a/b/file")?, borrowed);
+
+    Ok(())
+}
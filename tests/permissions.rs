@@ -0,0 +1,55 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Attach,
+        Entry,
+        GetDirectoryError,
+        GetErrorKind,
+        GetExt,
+    },
+    node::{
+        DataExt,
+        Permissions,
+        WriteError,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn readonly() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+    let dir = fs.get_dir_default("/dir").await?;
+    let file = dir.get_file_default("file").await?;
+
+    assert!(!file.readonly().await);
+
+    file.set_readonly(true).await;
+    dir.set_readonly(true).await;
+
+    assert!(file.readonly().await);
+    assert!(matches!(
+        file.write(|mut value| *value = 1).await,
+        Err(WriteError::PermissionDenied)
+    ));
+    assert!(matches!(
+        fs.get_dir_default("/dir/child").await,
+        Err(GetDirectoryError::Get(err)) if err.kind() == GetErrorKind::PermissionDenied
+    ));
+    assert!(matches!(
+        dir.detach("file").await,
+        Err(WriteError::PermissionDenied)
+    ));
+
+    if let Entry::Occupied(entry) = dir.entry("file").await {
+        assert!(matches!(entry.remove().await, Err(WriteError::PermissionDenied)));
+    }
+
+    assert!(dir.get_file("file").await?.is_some());
+
+    file.set_readonly(false).await;
+    file.write(|mut value| *value = 1).await?;
+
+    assert_eq!(file.read(|value| *value).await, 1);
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    file_system::{
+        Listing,
+        Options,
+    },
+    FileSystem,
+};
+
+fn listing(common_prefixes: &[&str], keys: &[&str]) -> Listing {
+    Listing {
+        common_prefixes: common_prefixes.iter().map(ToString::to_string).collect(),
+        keys: keys.iter().map(ToString::to_string).collect(),
+    }
+}
+
+#[tokio::test]
+async fn list_prefix() -> Result<()> {
+    for options in [Options::default(), Options::default().index(true)] {
+        let fs = FileSystem::<(), u32>::with_options(options);
+
+        for path in [
+            "/photos/2023/a.jpg",
+            "/photos/2024/b.jpg",
+            "/photos/2024/c.jpg",
+            "/photos/index.html",
+            "/readme.txt",
+        ] {
+            fs.get_file_default(path).await?;
+        }
+
+        fs.get_dir_default("/empty").await?;
+
+        assert_eq!(
+            fs.list_prefix("", Some("/")).await,
+            listing(&["photos/"], &["readme.txt"])
+        );
+        assert_eq!(
+            fs.list_prefix("photos/", Some("/")).await,
+            listing(&["photos/2023/", "photos/2024/"], &["photos/index.html"])
+        );
+        assert_eq!(
+            fs.list_prefix("photos/2024/", None).await,
+            listing(&[], &["photos/2024/b.jpg", "photos/2024/c.jpg"])
+        );
+        assert_eq!(
+            fs.list_prefix("photos/20", None).await,
+            listing(
+                &[],
+                &[
+                    "photos/2023/a.jpg",
+                    "photos/2024/b.jpg",
+                    "photos/2024/c.jpg"
+                ]
+            )
+        );
+        assert_eq!(fs.list_prefix("missing/", Some("/")).await, Listing::default());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetExt,
+        Probe,
+    },
+    node::NodeKind,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn probe() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::new();
+    let dir = fs.get_dir_default("/a").await?;
+
+    fs.get_file_default("/a/file").await?;
+
+    assert!(fs.exists("/a").await);
+    assert!(dir.exists("file").await);
+    assert!(!fs.exists("/a/missing").await);
+    assert!(!fs.exists("/a/file/child").await);
+    assert_eq!(fs.node_type("/a").await, Some(NodeKind::Directory));
+    assert_eq!(fs.node_type("/a/file").await, Some(NodeKind::File));
+    assert_eq!(fs.node_type("/b/c").await, None);
+    assert!(fs.get_dir("/b").await?.is_none());
+
+    Ok(())
+}
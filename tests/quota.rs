@@ -0,0 +1,110 @@
+use anyhow::Result;
+use futures::AsyncWriteExt;
+use memfs::{
+    directory::{
+        Attach,
+        Count,
+        GetDirectoryError,
+        GetErrorKind,
+        GetExt,
+        GetFileError,
+    },
+    file::Open,
+    file_system::Options,
+    node::{
+        DataExt,
+        WriteError,
+    },
+    Directory,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn max_children() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::with_options(Options::default().max_children(2));
+
+    fs.get_dir_default("/a").await?;
+    fs.get_file_default("/b").await?;
+    fs.get_dir_default("/a/c").await?;
+
+    assert!(matches!(
+        fs.get_dir_default("/d").await,
+        Err(GetDirectoryError::Get(err)) if err.kind() == GetErrorKind::QuotaExceeded
+    ));
+    assert!(fs.get_dir_default("/a").await.is_ok());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn max_children_concurrent() -> Result<()> {
+    let fs: FileSystem<(), ()> =
+        FileSystem::with_options(Options::default().max_children(4).shards(16));
+    let root = Directory::clone(&fs);
+    let tasks = (0..256)
+        .map(|index| {
+            let root = root.clone();
+
+            tokio::spawn(async move { root.get_file_default(format!("{index}")).await })
+        })
+        .collect::<Vec<_>>();
+
+    for task in tasks {
+        let _ = task.await?;
+    }
+
+    assert_eq!(fs.count().await?, 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn max_nodes() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::with_options(Options::default().max_nodes(3));
+
+    fs.get_dir_default("/a/b").await?;
+    fs.get_file_default("/a/c").await?;
+
+    assert!(matches!(
+        fs.get_file_default("/a/d").await,
+        Err(GetFileError::Get(err)) if err.kind() == GetErrorKind::QuotaExceeded
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn max_size() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::with_options(Options::default().max_size(8));
+    let a = fs.get_file_default("/a").await?;
+    let b = fs.get_file_default("/b").await?;
+
+    a.write(|mut value| *value = vec![0; 6]).await?;
+    b.write(|mut value| *value = vec![0; 3]).await?;
+
+    assert!(matches!(
+        b.write(|mut value| value.clear()).await,
+        Err(WriteError::QuotaExceeded)
+    ));
+    assert_eq!(b.read(|value| value.len()).await, 3);
+
+    let mut writer = a.open_writer().await?;
+
+    writer.write_all(&[0; 4]).await?;
+
+    assert!(writer.close().await.is_err());
+    assert_eq!(a.read(|value| value.len()).await, 6);
+
+    fs.detach("b").await?;
+    a.write(|mut value| value.truncate(2)).await?;
+
+    let mut writer = a.open_writer().await?;
+
+    writer.write_all(&[0; 4]).await?;
+    writer.close().await?;
+
+    assert_eq!(a.read(|value| value.len()).await, 4);
+    assert!(a.write(|mut value| value.push(0)).await.is_ok());
+
+    Ok(())
+}
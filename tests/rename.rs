@@ -0,0 +1,162 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+        Probe,
+        Rename,
+        RenameError,
+    },
+    file_system::Options,
+    node::{
+        DataExt,
+        Located,
+        Permissions,
+        WriteError,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn rename_file() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+
+    fs.get_file_default("/a/b")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+    fs.get_dir_default("/c").await?;
+    fs.rename("/a/b", "/c/d").await?;
+
+    assert!(!fs.exists("/a/b").await);
+
+    let file = fs.get_file_default("/c/d").await?;
+
+    assert_eq!(file.path().await, PathBuf::from("/c/d"));
+    assert_eq!(file.read(|value| *value).await, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_dir() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+
+    fs.get_file_default("/a/b/c").await?;
+    fs.rename("/a/b", "/d").await?;
+
+    assert!(!fs.exists("/a/b").await);
+    assert!(fs.exists("/d/c").await);
+    assert_eq!(fs.get_dir_default("/a").await?.count().await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_errors() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+
+    fs.get_dir_default("/a/b").await?;
+
+    assert!(matches!(
+        fs.rename("/a", "/a/b/c").await,
+        Err(RenameError::Cycle)
+    ));
+    assert!(matches!(
+        fs.rename("/x", "/y").await,
+        Err(RenameError::NotFound)
+    ));
+    assert!(matches!(
+        fs.rename("/", "/y").await,
+        Err(RenameError::InvalidPath)
+    ));
+
+    fs.get_dir_default("/c").await?.set_readonly(true).await;
+
+    assert!(matches!(
+        fs.rename("/a/b", "/c/b").await,
+        Err(RenameError::Write(_))
+    ));
+    assert!(fs.exists("/a/b").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_quota() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().max_children(2));
+
+    fs.get_file_default("/a/b").await?;
+    fs.get_file_default("/c/d").await?;
+    fs.get_file_default("/c/f").await?;
+
+    assert!(matches!(
+        fs.rename("/a/b", "/c/e").await,
+        Err(RenameError::Write(WriteError::QuotaExceeded))
+    ));
+    assert!(fs.exists("/a/b").await);
+    assert!(!fs.exists("/c/e").await);
+
+    fs.rename("/a/b", "/c/d").await?;
+
+    assert!(!fs.exists("/a/b").await);
+    assert_eq!(fs.get_dir_default("/c").await?.count().await?, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_replace() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+
+    fs.get_file_default("/a").await?;
+    fs.get_file_default("/b").await?;
+    fs.get_dir_default("/c").await?;
+    fs.get_file_default("/d/e").await?;
+    fs.get_dir_default("/f").await?;
+
+    assert!(matches!(
+        fs.rename("/a", "/c").await,
+        Err(RenameError::UnexpectedDirectory)
+    ));
+    assert!(matches!(
+        fs.rename("/c", "/a").await,
+        Err(RenameError::UnexpectedFile)
+    ));
+    assert!(matches!(
+        fs.rename("/c", "/d").await,
+        Err(RenameError::NotEmpty)
+    ));
+    assert!(fs.exists("/a").await);
+    assert!(fs.exists("/c").await);
+
+    fs.rename("/a", "/b").await?;
+    fs.rename("/c", "/f").await?;
+
+    assert!(!fs.exists("/a").await);
+    assert!(!fs.exists("/c").await);
+    assert_eq!(fs.count().await?, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_quota_tree() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().max_nodes(4));
+
+    fs.get_file_default("/a/b/c").await?;
+    fs.get_dir_default("/d").await?;
+    fs.rename("/a/b", "/d/b").await?;
+
+    assert!(fs.exists("/d/b/c").await);
+    assert!(fs.get_file_default("/e").await.is_err());
+
+    fs.rename("/d/b", "/a/b").await?;
+
+    assert!(fs.exists("/a/b/c").await);
+    assert!(fs.get_file_default("/e").await.is_err());
+
+    Ok(())
+}
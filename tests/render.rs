@@ -0,0 +1,37 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetExt,
+        Render,
+    },
+    memfs,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn render_tree() -> Result<()> {
+    let fs: FileSystem<(), String> = memfs! {
+        "etc" => {
+            "conf.d" => {
+                "a.conf" => file(String::from("a = 1")),
+            },
+            "hosts" => file(),
+        },
+        "readme" => file(String::from("hello")),
+    }
+    .await?;
+
+    assert_eq!(
+        fs.render_tree().await,
+        "/\n├── etc\n│   ├── conf.d\n│   │   └── a.conf\n│   └── hosts\n└── readme\n"
+    );
+    assert_eq!(
+        fs.get_dir_default("/etc")
+            .await?
+            .render_tree_with(|value| format!("{} bytes", value.len()))
+            .await,
+        "etc\n├── conf.d\n│   └── a.conf (5 bytes)\n└── hosts (0 bytes)\n"
+    );
+
+    Ok(())
+}
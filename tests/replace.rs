@@ -0,0 +1,61 @@
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    node::{
+        DataExt,
+        Permissions,
+        WriteError,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn replace() -> Result<()> {
+    let fs = FileSystem::<u32, String>::default();
+    let file = fs.get_file_or_create_with("/a", || "one".into()).await?;
+
+    assert_eq!(file.replace("two".into()).await?, "one");
+    assert_eq!(file.read(|value| value.clone()).await, "two");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_moves_value() -> Result<()> {
+    let fs = FileSystem::<u32, String>::default();
+    let file = fs.get_file_default("/a").await?;
+    let value = String::from("moved");
+
+    file.write(|mut current| *current = value).await?;
+
+    assert_eq!(file.read(|value| value.clone()).await, "moved");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn take() -> Result<()> {
+    let fs = FileSystem::<u32, String>::default();
+    let file = fs.get_file_or_create_with("/a", || "one".into()).await?;
+
+    assert_eq!(file.take().await?, "one");
+    assert_eq!(file.read(|value| value.clone()).await, "");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn replace_readonly() -> Result<()> {
+    let fs = FileSystem::<u32, String>::default();
+    let file = fs.get_file_or_create_with("/a", || "one".into()).await?;
+
+    file.set_readonly(true).await;
+
+    assert!(matches!(
+        file.take().await,
+        Err(WriteError::PermissionDenied)
+    ));
+    assert_eq!(file.read(|value| value.clone()).await, "one");
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Get,
+        GetError,
+        GetErrorKind,
+        GetExt,
+        GetFileError,
+        GetType,
+    },
+    file_system::Options,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn max_components() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::with_options(Options::default().max_components(4));
+
+    fs.get_file_default("/a/b/c").await?;
+
+    assert!(fs.get_file("/a/b/c").await?.is_some());
+    assert!(fs.get_file("/a/../a/b/c").await.is_err());
+
+    let path = "a/".repeat(1000);
+
+    assert!(matches!(
+        fs.get(&path, GetType::Directory).await,
+        Err(err) if err.kind() == GetErrorKind::ComponentLimitExceeded && err.index() == 4
+    ));
+    assert!(matches!(
+        fs.get_file_default(&path).await,
+        Err(GetFileError::Get(err)) if err.kind() == GetErrorKind::ComponentLimitExceeded
+    ));
+    assert!(fs.get_dir("/a/x").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn max_traversal() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::with_options(Options::default().max_traversal(2));
+
+    fs.get_file_default("/a/b").await?;
+
+    assert!(fs.get_file("/a/b").await?.is_some());
+    assert!(fs.get_dir("/a/../a/../a").await?.is_some());
+
+    let check = |result: Result<_, GetError>| {
+        matches!(result, Err(err) if err.kind() == GetErrorKind::TraversalLimitExceeded)
+    };
+
+    let a = fs.get_dir("/a").await?.expect("directory");
+
+    a.get_dir_default("c/d").await?;
+
+    assert!(a.get_dir("c/d").await?.is_some());
+    assert!(check(a.get("c/d/e", GetType::Directory).await));
+    assert!(check(fs.get("/a/c/d", GetType::Directory).await));
+    assert!(check(fs.get_default("/a/c/x", GetType::File).await.map(Some)));
+
+    Ok(())
+}
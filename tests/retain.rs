@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Fold,
+        GetExt,
+        Retain,
+    },
+    FileSystem,
+    Node,
+};
+
+async fn fixture() -> Result<FileSystem<(), ()>> {
+    let fs = FileSystem::new();
+
+    fs.get_file_default("/a/b/c").await?;
+    fs.get_file_default("/a/d").await?;
+    fs.get_file_default("/e/f").await?;
+    fs.get_dir_default("/g").await?;
+
+    Ok(fs)
+}
+
+async fn paths(fs: &FileSystem<(), ()>) -> Vec<PathBuf> {
+    let mut paths = fs
+        .fold(Vec::new(), |mut paths, path, _| {
+            paths.push(path.to_path_buf());
+            paths
+        })
+        .await;
+
+    paths.sort();
+    paths
+}
+
+#[tokio::test]
+async fn retain() -> Result<()> {
+    let fs = fixture().await?;
+
+    assert_eq!(
+        fs.retain(|path, _| !path.ends_with("c") && !path.ends_with("f"))
+            .await,
+        2
+    );
+    assert_eq!(
+        paths(&fs).await,
+        ["/a", "/a/b", "/a/d", "/e", "/g"]
+            .map(PathBuf::from)
+            .to_vec()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retain_and_prune() -> Result<()> {
+    let fs = fixture().await?;
+
+    assert_eq!(
+        fs.retain_and_prune(|path, _| !path.ends_with("c") && !path.ends_with("f"))
+            .await,
+        4
+    );
+    assert_eq!(
+        paths(&fs).await,
+        ["/a", "/a/d", "/g"].map(PathBuf::from).to_vec()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retain_directory() -> Result<()> {
+    let fs = fixture().await?;
+
+    assert_eq!(
+        fs.retain(|path, node| !matches!(node, Node::Directory(_)) || !path.ends_with("a"))
+            .await,
+        1
+    );
+    assert_eq!(
+        paths(&fs).await,
+        ["/e", "/e/f", "/g"].map(PathBuf::from).to_vec()
+    );
+
+    Ok(())
+}
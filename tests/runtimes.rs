@@ -0,0 +1,79 @@
+use anyhow::Result;
+use futures::{
+    future,
+    AsyncReadExt,
+    AsyncWriteExt,
+};
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+    },
+    file::Open,
+    node::DataExt,
+    FileSystem,
+};
+
+async fn exercise() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+
+    future::try_join_all((0..8).map(|i| {
+        let fs = &fs;
+
+        async move {
+            fs.get_file_default(format!("/a/b/{i}"))
+                .await?
+                .write(|mut value| *value = vec![i])
+                .await?;
+
+            anyhow::Ok(())
+        }
+    }))
+    .await?;
+
+    assert_eq!(fs.get_dir_default("/a/b").await?.count().await?, 8);
+
+    let file = fs.get_file_default("/a/c").await?;
+    let mut writer = file.open_writer().await?;
+
+    writer.write_all(b"hello").await?;
+    writer.close().await?;
+
+    drop(writer);
+
+    let mut content = String::new();
+
+    file.open_reader()
+        .await
+        .read_to_string(&mut content)
+        .await?;
+
+    assert_eq!(content, "hello");
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn async_std() -> Result<()> {
+    exercise().await
+}
+
+#[test]
+fn futures() -> Result<()> {
+    futures::executor::block_on(exercise())
+}
+
+#[test]
+fn smol() -> Result<()> {
+    smol::block_on(exercise())
+}
+
+#[tokio::test]
+async fn tokio() -> Result<()> {
+    exercise().await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tokio_multi_thread() -> Result<()> {
+    exercise().await
+}
@@ -0,0 +1,77 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetExt,
+        Probe,
+        Scoped,
+    },
+    node::Located,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn scope_resolves_within() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+
+    fs.get_file_default("/jail/a/b").await?;
+    fs.get_file_default("/secret").await?;
+
+    let scope = fs.scope("/jail").await?;
+
+    assert!(scope.is_some());
+
+    if let Some(scope) = scope {
+        assert!(scope.exists("/a/b").await);
+        assert!(scope.exists("a/b").await);
+        assert!(scope.exists("a/../a/./b").await);
+        assert!(!scope.exists("/secret").await);
+        assert!(!scope.exists("../secret").await);
+        assert!(!scope.exists("/../../secret").await);
+        assert!(scope.exists("/../a").await);
+        assert_eq!(scope.root().path().await.to_str(), Some("/jail"));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn scope_creates_within() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+
+    fs.get_dir_default("/jail/nested").await?;
+
+    if let Some(scope) = fs.scope("/jail").await? {
+        let file = scope.get_file_default("../../escape").await?;
+
+        assert_eq!(file.path().await.to_str(), Some("/jail/escape"));
+
+        if let Some(nested) = scope.scope("/nested").await? {
+            nested.get_file_default("/../x").await?;
+        }
+    }
+
+    assert!(!fs.exists("/escape").await);
+    assert!(!fs.exists("/x").await);
+    assert!(fs.exists("/jail/escape").await);
+    assert!(fs.exists("/jail/nested/x").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn scope_handles_are_unconfined() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+
+    fs.get_file_default("/jail/a").await?;
+    fs.get_file_default("/secret").await?;
+
+    if let Some(scope) = fs.scope("/jail").await? {
+        let file = scope.get_file_default("/a").await?;
+
+        assert_eq!(file.path().await.to_str(), Some("/jail/a"));
+        assert!(!scope.exists("/../secret").await);
+        assert!(scope.root().exists("../secret").await);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use futures::{
+    FutureExt,
+    StreamExt,
+};
+use memfs::{
+    directory::{
+        GetExt,
+        Search,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn search() -> Result<()> {
+    let fs = FileSystem::<(), String>::new();
+
+    for (path, contents) in [
+        ("/a.txt", "one\ntwo needle\n"),
+        ("/b/c.txt", "needle\n"),
+        ("/b/d.txt", "haystack\n"),
+    ] {
+        fs.get_file_default(path)
+            .await?
+            .write(|mut value| *value = String::from(contents))
+            .await?;
+    }
+
+    let mut matches = fs
+        .search(|_, value: &String| {
+            async move {
+                tokio::task::yield_now().await;
+
+                value
+                    .lines()
+                    .position(|line| line.contains("needle"))
+            }
+            .boxed()
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+    matches.sort();
+
+    assert_eq!(
+        matches,
+        [(PathBuf::from("/a.txt"), 1), (PathBuf::from("/b/c.txt"), 0)]
+    );
+
+    let b = fs.get_dir_default("/b").await?;
+    let mut paths = b
+        .search(|path, _| async move { Some(path.to_path_buf()) }.boxed())
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>()
+        .await;
+
+    paths.sort();
+
+    assert_eq!(paths, [PathBuf::from("/c.txt"), PathBuf::from("/d.txt")]);
+
+    Ok(())
+}
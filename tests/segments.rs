@@ -0,0 +1,38 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetErrorKind,
+        GetExt,
+        GetSegments,
+        GetType,
+    },
+    FileSystem,
+    Node,
+};
+
+#[tokio::test]
+async fn segments() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let file = fs.get_file_default("/a/b/file").await?;
+
+    let found = fs.get_segments(["a", "b", "file"]).await?;
+
+    assert!(matches!(found, Some(Node::File(found)) if found == file));
+    assert!(fs
+        .get_segments(vec![String::from("a"), String::from("missing")])
+        .await?
+        .is_none());
+    assert!(fs.get_segments(["a", ".", "b", "..", "b"]).await?.is_some());
+
+    let created = fs.get_segments_default(["c", "d"], GetType::File).await?;
+
+    assert!(matches!(created, Node::File(_)));
+    assert!(fs.get_dir("/c").await?.is_some());
+
+    let err = fs.get_segments(["a", "b", "file", "x"]).await.unwrap_err();
+
+    assert_eq!(err.kind(), GetErrorKind::UnexpectedFile);
+    assert_eq!(err.index(), 3);
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "tower")]
+
+use anyhow::Result;
+use bytes::Bytes;
+use http::{
+    header,
+    Method,
+    Request,
+    StatusCode,
+};
+use http_body_util::BodyExt;
+use memfs::{
+    directory::GetExt,
+    node::DataExt,
+    service::ServeDir,
+    FileSystem,
+};
+use tower_service::Service;
+
+#[tokio::test]
+async fn serve_files() -> Result<()> {
+    let fs: FileSystem<(), Bytes> = FileSystem::new();
+
+    fs.get_file_default("/static/index.html")
+        .await?
+        .write(|mut value| *value = Bytes::from_static(b"<h1>hello</h1>"))
+        .await?;
+
+    let mut service = ServeDir::new(&fs);
+    let response = service
+        .call(Request::get("/static/index.html").body(())?)
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "text/html");
+    assert_eq!(response.headers()[header::CONTENT_LENGTH], "14");
+    assert_eq!(
+        response.into_body().collect().await?.to_bytes(),
+        "<h1>hello</h1>"
+    );
+
+    let response = service
+        .call(
+            Request::builder()
+                .method(Method::HEAD)
+                .uri("/static/index.html")
+                .body(())?,
+        )
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.into_body().collect().await?.to_bytes().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serve_errors() -> Result<()> {
+    let fs: FileSystem<(), Bytes> = FileSystem::new();
+
+    fs.get_dir_default("/static").await?;
+
+    let mut service = ServeDir::new(&fs);
+
+    for path in ["/missing", "/static", "/static/missing"] {
+        let response = service.call(Request::get(path).body(())?).await?;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    let response = service
+        .call(
+            Request::builder()
+                .method(Method::POST)
+                .uri("/static")
+                .body(())?,
+        )
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serve_subdirectory() -> Result<()> {
+    let fs: FileSystem<(), Bytes> = FileSystem::new();
+
+    fs.get_file_default("/secret.txt").await?;
+
+    for path in ["/site/index.html", "/site/a b.txt"] {
+        fs.get_file_default(path)
+            .await?
+            .write(|mut value| *value = Bytes::from_static(b"ok"))
+            .await?;
+    }
+
+    let site = fs.get_dir("/site").await?.expect("directory");
+    let mut service = ServeDir::new(&site);
+
+    for path in ["/index.html", "/./index.html", "//index.html", "/a%20b.txt"] {
+        let response = service.call(Request::get(path).body(())?).await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.into_body().collect().await?.to_bytes(), "ok");
+    }
+
+    for path in ["/../secret.txt", "/%2E%2E/secret.txt", "/a%2Fb", "/a%zz"] {
+        let response = service.call(Request::get(path).body(())?).await?;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use std::ffi::OsStr;
+
+use anyhow::Result;
+use futures::future;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+    },
+    file_system::Options,
+    fs,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn sharded_children() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::with_options(Options::default().shards(8));
+
+    future::try_join_all((0..64).map(|i| fs.get_file_default(format!("/file-{i:02}")))).await?;
+
+    assert_eq!(fs.count_file().await?, 64);
+    assert_eq!(
+        fs::read_dir(&fs, "/")
+            .await?
+            .iter()
+            .map(|entry| entry.file_name())
+            .min(),
+        Some(OsStr::new("file-00"))
+    );
+    assert!(fs.get_file("/file-42").await?.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sharded_case_insensitive() -> Result<()> {
+    let fs: FileSystem<(), ()> =
+        FileSystem::with_options(Options::default().shards(4).case_insensitive(true));
+    let file = fs.get_file_default("/File").await?;
+
+    for name in ["/file", "/FILE", "/fILE"] {
+        assert!(fs.get_file(name).await?.is_some_and(|found| found == file));
+    }
+
+    Ok(())
+}
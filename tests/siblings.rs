@@ -0,0 +1,29 @@
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    node::Siblings,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn siblings() -> Result<()> {
+    let fs = FileSystem::<(), ()>::default();
+
+    fs.get_file_default("/a/b").await?;
+    fs.get_dir_default("/a/c").await?;
+
+    let file = fs.get_file_default("/a/d").await?;
+    let mut names = file
+        .siblings()
+        .await
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+
+    names.sort();
+
+    assert_eq!(names, ["b", "c"]);
+    assert!(fs.siblings().await.is_empty());
+
+    Ok(())
+}
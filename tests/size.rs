@@ -0,0 +1,30 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetExt,
+        Size,
+    },
+    file::Len,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn size() -> Result<()> {
+    let fs: FileSystem<(), String> = FileSystem::new();
+    let a = fs.get_file_default("/dir/a").await?;
+    let b = fs.get_file_default("/dir/sub/b").await?;
+    let c = fs.get_file_default("/c").await?;
+
+    assert!(a.is_empty().await);
+
+    a.write(|mut value| value.push_str("abc")).await?;
+    b.write(|mut value| value.push_str("de")).await?;
+    c.write(|mut value| value.push_str("fg")).await?;
+
+    assert_eq!(a.len().await, 3);
+    assert_eq!(fs.get_dir_default("/dir").await?.size().await, 5);
+    assert_eq!(fs.total_size().await, 7);
+
+    Ok(())
+}
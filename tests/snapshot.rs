@@ -0,0 +1,88 @@
+#![cfg(feature = "snapshot")]
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        GetExt,
+        SaveSnapshot,
+        SnapshotError,
+    },
+    node::{
+        WriteError,
+        DataExt,
+        Permissions,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn snapshot_round_trip() -> Result<()> {
+    let fs: FileSystem<String, Vec<u8>> = FileSystem::new();
+
+    fs.get_dir_default("/empty")
+        .await?
+        .write(|mut value| *value = String::from("meta"))
+        .await?;
+    fs.get_file_default("/a/b/file")
+        .await?
+        .write(|mut value| *value = b"content".to_vec())
+        .await?;
+    fs.get_file_default("/a/locked")
+        .await?
+        .set_readonly(true)
+        .await;
+
+    let bytes = fs.save_snapshot(Vec::new()).await?;
+    let loaded: FileSystem<String, Vec<u8>> = FileSystem::load_snapshot(bytes.as_slice()).await?;
+
+    assert_eq!(loaded.count().await?, fs.count().await?);
+
+    let empty = loaded.get_dir_default("/empty").await?;
+    let file = loaded.get_file_default("/a/b/file").await?;
+    let locked = loaded.get_file_default("/a/locked").await?;
+
+    assert_eq!(empty.read(|value| value.clone()).await, "meta");
+    assert_eq!(file.read(|value| value.clone()).await, b"content");
+    assert!(locked.readonly().await);
+    assert!(!file.readonly().await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn snapshot_invalid() {
+    let loaded = FileSystem::<(), Vec<u8>>::load_snapshot(b"not a snapshot".as_slice()).await;
+
+    assert!(matches!(
+        loaded,
+        Err(SnapshotError::InvalidFormat | SnapshotError::Encoding(_))
+    ));
+}
+
+#[tokio::test]
+async fn snapshot_invalid_names() -> Result<()> {
+    for name in ["..", "a/b", "/etc"] {
+        let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+        let placeholder = "~".repeat(name.len());
+
+        fs.get_file_default(format!("/{placeholder}")).await?;
+
+        let mut bytes = fs.save_snapshot(Vec::new()).await?;
+        let at = bytes
+            .windows(name.len())
+            .position(|window| window == placeholder.as_bytes())
+            .unwrap();
+
+        bytes[at..at + name.len()].copy_from_slice(name.as_bytes());
+
+        let loaded = FileSystem::<(), Vec<u8>>::load_snapshot(&bytes).await;
+
+        assert!(
+            matches!(loaded, Err(SnapshotError::Write(WriteError::InvalidName))),
+            "{name}"
+        );
+    }
+
+    Ok(())
+}
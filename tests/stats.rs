@@ -0,0 +1,43 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetExt,
+        Statistics,
+        Stats,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn stats() -> Result<()> {
+    let fs: FileSystem<(), String> = FileSystem::new();
+
+    fs.get_dir_default("/a/b/c").await?;
+    fs.get_file_default("/a/file")
+        .await?
+        .write(|mut value| value.push_str("hello"))
+        .await?;
+    fs.get_file_default("/file")
+        .await?
+        .write(|mut value| value.push_str("hi"))
+        .await?;
+
+    assert_eq!(
+        fs.stats().await,
+        Stats {
+            bytes: None,
+            directories: 3,
+            files: 2,
+            max_depth: 3,
+            nodes: 5,
+        }
+    );
+    assert_eq!(fs.stats_with_len().await.bytes, Some(7));
+    assert_eq!(
+        fs.get_dir_default("/a/b/c").await?.stats().await,
+        Stats::default()
+    );
+
+    Ok(())
+}
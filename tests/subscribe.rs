@@ -0,0 +1,62 @@
+use anyhow::Result;
+use futures::{
+    AsyncWriteExt,
+    StreamExt,
+};
+use memfs::{
+    directory::GetExt,
+    file::{
+        Open,
+        Subscribe,
+    },
+    node::{
+        DataExt,
+        Permissions,
+        Versioned,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn subscribe() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let file = fs.get_file_default("/a").await?;
+    let mut generations = file.subscribe().await;
+    let mut values = file.subscribe_values().await;
+
+    file.write(|mut value| *value = 1).await?;
+
+    assert_eq!(generations.next().await, Some(file.generation().await));
+    assert_eq!(values.next().await, Some(1));
+
+    file.set_readonly(true).await;
+
+    assert!(file.write(|mut value| *value = 2).await.is_err());
+
+    file.set_readonly(false).await;
+    file.write(|mut value| *value = 3).await?;
+
+    assert_eq!(values.next().await, Some(3));
+
+    drop(generations);
+    drop(values);
+
+    file.write(|mut value| *value = 4).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn subscribe_writer() -> Result<()> {
+    let fs = FileSystem::<(), Vec<u8>>::new();
+    let file = fs.get_file_default("/a").await?;
+    let mut values = file.subscribe_values().await;
+    let mut writer = file.open_writer().await?;
+
+    writer.write_all(b"hello").await?;
+    writer.close().await?;
+
+    assert_eq!(values.next().await, Some(b"hello".to_vec()));
+
+    Ok(())
+}
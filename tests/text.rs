@@ -0,0 +1,34 @@
+use anyhow::Result;
+use futures::StreamExt;
+use memfs::{
+    directory::GetExt,
+    node::TextFileExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn text_read_write() -> Result<()> {
+    let fs = FileSystem::<u32, String>::default();
+    let file = fs.get_file_default("/a.txt").await?;
+
+    file.write_str("hello").await?;
+
+    assert_eq!(file.read_to_string().await, "hello");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn text_lines() -> Result<()> {
+    let fs = FileSystem::<u32, String>::default();
+    let file = fs.get_file_default("/a.txt").await?;
+
+    file.write_str("one\ntwo\r\nthree\n").await?;
+
+    assert_eq!(
+        file.lines().collect::<Vec<_>>().await,
+        vec!["one", "two", "three"]
+    );
+
+    Ok(())
+}
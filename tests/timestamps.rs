@@ -0,0 +1,56 @@
+use std::{
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+    },
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    node::{
+        DataExt,
+        Timestamps,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn timestamps() -> Result<()> {
+    let ticks = Arc::new(AtomicU64::new(0));
+    let clock = {
+        let ticks = ticks.clone();
+
+        move || SystemTime::UNIX_EPOCH + Duration::from_secs(ticks.load(Ordering::SeqCst))
+    };
+    let at = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+
+    let fs: FileSystem<(), u32> = FileSystem::with_clock(clock);
+    let file = fs.get_file_default("/file").await?;
+
+    assert_eq!(file.created().await, at(0));
+    assert_eq!(file.modified().await, at(0));
+
+    ticks.store(1, Ordering::SeqCst);
+    file.read(|value| *value).await;
+
+    assert_eq!(file.accessed().await, at(1));
+    assert_eq!(file.modified().await, at(0));
+
+    ticks.store(2, Ordering::SeqCst);
+    file.write(|mut value| *value = 1).await?;
+
+    assert_eq!(file.accessed().await, at(2));
+    assert_eq!(file.modified().await, at(2));
+    assert_eq!(file.created().await, at(0));
+    assert_eq!(fs.modified().await, at(0));
+
+    Ok(())
+}
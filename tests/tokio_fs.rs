@@ -0,0 +1,78 @@
+#![cfg(feature = "tokio")]
+
+use std::io::ErrorKind;
+
+use anyhow::Result;
+use memfs::{
+    compat::tokio_fs::Handle,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn tokio_fs() -> Result<()> {
+    let memfs = FileSystem::<(), Vec<u8>>::new();
+    let fs = Handle::new(&memfs);
+
+    fs.create_dir_all("/a/b").await?;
+    fs.create_dir("/a/c").await?;
+    fs.write("/a/b/file", "content").await?;
+
+    assert_eq!(fs.read("/a/b/file").await?, b"content");
+    assert_eq!(fs.read_to_string("/a/b/file").await?, "content");
+    assert_eq!(fs.copy("/a/b/file", "/a/c/copy").await?, 7);
+    assert!(fs.metadata("/a/c").await?.is_dir());
+
+    fs.rename("/a/c/copy", "/a/moved").await?;
+
+    let mut entries = fs.read_dir("/a").await?;
+    let mut names = Vec::new();
+
+    while let Some(entry) = entries.next_entry().await? {
+        names.push(entry.file_name());
+    }
+
+    names.sort();
+
+    assert_eq!(names, ["b", "c", "moved"]);
+
+    fs.remove_file("/a/moved").await?;
+    fs.remove_dir("/a/c").await?;
+
+    assert!(!fs.try_exists("/a/moved").await?);
+    assert_eq!(
+        fs.remove_dir("/a/b").await.map_err(|err| err.kind()),
+        Err(ErrorKind::DirectoryNotEmpty)
+    );
+    assert_eq!(
+        fs.remove_file("/a/b").await.map_err(|err| err.kind()),
+        Err(ErrorKind::IsADirectory)
+    );
+    assert_eq!(
+        fs.create_dir("/x/y").await.map_err(|err| err.kind()),
+        Err(ErrorKind::NotFound)
+    );
+
+    fs.remove_dir_all("/a").await?;
+
+    assert!(!fs.try_exists("/a").await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tokio_fs_spawned() -> Result<()> {
+    let memfs = FileSystem::<(), Vec<u8>>::new();
+    let fs = Handle::new(&memfs);
+
+    tokio::spawn({
+        let fs = fs.clone();
+
+        async move { fs.write("/file", "spawned").await }
+    })
+    .await??;
+
+    assert_eq!(fs.read_to_string("/file").await?, "spawned");
+    assert!(fs.read("/missing").await.is_err());
+
+    Ok(())
+}
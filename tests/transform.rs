@@ -0,0 +1,75 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        GetExt,
+        Transform,
+    },
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn transform() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.get_dir_or_create_with("/a", || 1).await?;
+    fs.get_file_or_create_with("/a/b", || 2).await?;
+    fs.get_file_or_create_with("/c", || 3).await?;
+
+    let mapped = fs
+        .transform(ToString::to_string, |value| u64::from(*value) * 10)
+        .await?;
+
+    assert_eq!(mapped.read(|value| value.clone()).await, "0");
+    assert_eq!(
+        mapped
+            .get_dir_default("/a")
+            .await?
+            .read(|value| value.clone())
+            .await,
+        "1"
+    );
+
+    for (path, expected) in [("/a/b", 20), ("/c", 30)] {
+        let file = mapped.get_file_default(path).await?;
+
+        assert_eq!(file.read(|value| *value).await, expected);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn map_files() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+
+    fs.get_dir_or_create_with("/a", || 1).await?;
+    fs.get_file_or_create_with("/a/b", || 2).await?;
+
+    let mapped = fs.map_files(|value| value.to_string()).await?;
+
+    mapped
+        .get_file_default("/a/b")
+        .await?
+        .write(|mut value| *value = "changed".into())
+        .await?;
+
+    assert_eq!(
+        mapped
+            .get_dir_default("/a")
+            .await?
+            .read(|value| *value)
+            .await,
+        1
+    );
+    assert_eq!(
+        fs.get_file_default("/a/b")
+            .await?
+            .read(|value| *value)
+            .await,
+        2
+    );
+    assert!(mapped.get_file("/c").await?.is_none());
+
+    Ok(())
+}
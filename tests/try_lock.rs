@@ -0,0 +1,45 @@
+use anyhow::Result;
+use futures::FutureExt;
+use memfs::{
+    directory::GetExt,
+    node::DataExt,
+    FileSystem,
+};
+
+#[tokio::test]
+async fn try_read_write() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+    let file = fs.get_file_default("/a").await?;
+
+    assert_eq!(file.try_write(|mut value| *value = 1).await?, Some(()));
+    assert_eq!(file.try_read(|value| *value).await, Some(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn try_read_write_contended() -> Result<()> {
+    let fs = FileSystem::<u32, u32>::default();
+    let file = fs.get_file_default("/a").await?;
+
+    let (read, write) = file
+        .read(|_| {
+            (
+                file.try_read(|value| *value).now_or_never(),
+                file.try_write(|mut value| *value = 1).now_or_never(),
+            )
+        })
+        .await;
+
+    assert_eq!(read, Some(Some(0)));
+    assert!(matches!(write, Some(Ok(None))));
+
+    let read = file
+        .write(|_| file.try_read(|value| *value).now_or_never())
+        .await?;
+
+    assert_eq!(read, Some(None));
+    assert_eq!(file.read(|value| *value).await, 0);
+
+    Ok(())
+}
@@ -0,0 +1,297 @@
+use std::sync::{
+    atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    Arc,
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Entry,
+        GetExt,
+        Probe,
+        Rename,
+    },
+    file_system::{
+        Action,
+        Decision,
+        Options,
+        UndoError,
+    },
+    node::{
+        DataExt,
+        Permissions,
+        WriteError,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn undo_disabled() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::new();
+
+    fs.get_dir_default("/a").await?;
+
+    assert!(!fs.undo().await?);
+    assert!(fs.exists("/a").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn undo_redo_create() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().undo(16));
+
+    fs.get_file_default("/a/b").await?;
+
+    assert!(fs.undo().await?);
+    assert!(!fs.exists("/a/b").await);
+    assert!(fs.exists("/a").await);
+    assert!(fs.undo().await?);
+    assert!(!fs.exists("/a").await);
+    assert!(!fs.undo().await?);
+    assert!(fs.redo().await?);
+    assert!(fs.redo().await?);
+    assert!(fs.exists("/a/b").await);
+    assert!(!fs.redo().await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn undo_redo_remove() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().undo(16));
+
+    fs.get_file_default("/a/b").await?;
+
+    if let Entry::Occupied(entry) = fs.entry("a").await {
+        entry.remove().await?;
+    }
+
+    assert!(!fs.exists("/a").await);
+    assert!(fs.undo().await?);
+    assert!(fs.exists("/a/b").await);
+    assert!(fs.redo().await?);
+    assert!(!fs.exists("/a").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn undo_redo_rename() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().undo(16));
+
+    fs.get_file_default("/a/b").await?;
+    fs.get_dir_default("/c").await?;
+    fs.rename("/a/b", "/c/d").await?;
+
+    assert!(fs.undo().await?);
+    assert!(fs.exists("/a/b").await);
+    assert!(!fs.exists("/c/d").await);
+    assert!(fs.redo().await?);
+    assert!(!fs.exists("/a/b").await);
+    assert!(fs.exists("/c/d").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn undo_redo_rename_replaced() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().undo(16));
+
+    fs.get_file_default("/a")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+    fs.get_file_default("/b")
+        .await?
+        .write(|mut value| *value = 2)
+        .await?;
+    fs.rename("/a", "/b").await?;
+
+    assert!(!fs.exists("/a").await);
+    assert_eq!(
+        fs.get_file_default("/b")
+            .await?
+            .read(|value| *value)
+            .await,
+        1
+    );
+    assert!(fs.undo().await?);
+    assert_eq!(
+        fs.get_file_default("/a")
+            .await?
+            .read(|value| *value)
+            .await,
+        1
+    );
+    assert_eq!(
+        fs.get_file_default("/b")
+            .await?
+            .read(|value| *value)
+            .await,
+        2
+    );
+    assert!(fs.redo().await?);
+    assert!(!fs.exists("/a").await);
+    assert_eq!(
+        fs.get_file_default("/b")
+            .await?
+            .read(|value| *value)
+            .await,
+        1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn undo_limit() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().undo(1));
+
+    fs.get_dir_default("/a").await?;
+    fs.get_dir_default("/b").await?;
+
+    assert!(fs.undo().await?);
+    assert!(!fs.undo().await?);
+    assert!(fs.exists("/a").await);
+    assert!(!fs.exists("/b").await);
+
+    fs.get_dir_default("/c").await?;
+
+    assert!(!fs.redo().await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn undo_error() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().undo(16));
+    let dir = fs.get_dir_default("/a").await?;
+
+    fs.get_file_default("/a/b").await?;
+
+    if let Entry::Occupied(entry) = dir.entry("b").await {
+        entry.remove().await?;
+    }
+
+    dir.set_readonly(true).await;
+
+    assert!(matches!(
+        fs.undo().await,
+        Err(UndoError::Write(WriteError::PermissionDenied))
+    ));
+
+    dir.set_readonly(false).await;
+
+    assert!(fs.undo().await?);
+    assert!(fs.exists("/a/b").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn undo_rename_rollback() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().undo(16));
+
+    fs.get_file_default("/a/f").await?;
+
+    let b = fs.get_dir_default("/b").await?;
+
+    fs.rename("/a/f", "/b/f").await?;
+
+    assert!(fs.undo().await?);
+
+    b.set_readonly(true).await;
+
+    assert!(matches!(
+        fs.redo().await,
+        Err(UndoError::Write(WriteError::PermissionDenied))
+    ));
+    assert!(fs.exists("/a/f").await);
+    assert!(!fs.exists("/b/f").await);
+
+    b.set_readonly(false).await;
+
+    assert!(fs.redo().await?);
+    assert!(!fs.exists("/a/f").await);
+    assert!(fs.exists("/b/f").await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn undo_rename_diverged() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().undo(16));
+    let a = fs.get_dir_default("/a").await?;
+
+    fs.get_file_or_create_with("/a/f", || 1).await?;
+    fs.get_dir_default("/b").await?;
+    fs.rename("/a/f", "/b/f").await?;
+
+    fs.on_remove(move |path, _| {
+        let a = a.clone();
+
+        async move {
+            if path.ends_with("b/f") {
+                let _ = a.entry("f").await.or_insert_file(2).await;
+            }
+        }
+    });
+
+    assert!(matches!(fs.undo().await, Err(UndoError::Diverged)));
+    assert_eq!(
+        fs.get_file_default("/a/f")
+            .await?
+            .read(|value| *value)
+            .await,
+        2
+    );
+    assert_eq!(
+        fs.get_file_default("/b/f")
+            .await?
+            .read(|value| *value)
+            .await,
+        1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn undo_hooks() -> Result<()> {
+    let fs: FileSystem<(), u32> = FileSystem::with_options(Options::default().undo(16));
+    let removed = Arc::new(AtomicUsize::new(0));
+
+    fs.on_remove({
+        let removed = removed.clone();
+
+        move |_, _| {
+            removed.fetch_add(1, Ordering::SeqCst);
+            async {}
+        }
+    });
+
+    fs.get_file_default("/locked/f").await?;
+
+    assert!(fs.undo().await?);
+    assert_eq!(removed.load(Ordering::SeqCst), 1);
+    assert!(fs.redo().await?);
+
+    fs.intercept(|action, path| async move {
+        match (action, path.starts_with("/locked")) {
+            (Action::Remove, true) => Decision::Deny,
+            _ => Decision::Allow,
+        }
+    });
+
+    assert!(matches!(
+        fs.undo().await,
+        Err(UndoError::Write(WriteError::AccessDenied))
+    ));
+    assert!(fs.exists("/locked/f").await);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Attach,
+        GetExt,
+    },
+    node::{
+        DataExt,
+        Permissions,
+        Versioned,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn versioned() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let config = fs.get_dir_default("/config").await?;
+    let file = fs.get_file_default("/config/a").await?;
+    let other = fs.get_file_default("/other").await?;
+    let generation = config.generation().await;
+
+    other.write(|mut value| *value = 1).await?;
+
+    assert!(!config.changed_since(generation).await);
+    assert!(fs.changed_since(generation).await);
+
+    file.write(|mut value| *value = 1).await?;
+
+    assert!(file.changed_since(generation).await);
+    assert!(config.changed_since(generation).await);
+
+    let generation = config.generation().await;
+
+    file.set_readonly(true).await;
+
+    assert!(config.changed_since(generation).await);
+
+    let generation = config.generation().await;
+
+    config.detach("a").await?;
+
+    assert!(config.changed_since(generation).await);
+    assert!(fs.generation().await >= config.generation().await);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Flow,
+        GetExt,
+        Visit,
+    },
+    file_system::{
+        Options,
+        Order,
+    },
+    FileSystem,
+};
+
+async fn fixture() -> Result<FileSystem<(), ()>> {
+    let fs = FileSystem::with_options(Options::default().order(Order::Sorted));
+
+    fs.get_file_default("/a/b/c").await?;
+    fs.get_file_default("/a/d").await?;
+    fs.get_file_default("/e/f").await?;
+    fs.get_file_default("/g").await?;
+
+    Ok(fs)
+}
+
+#[tokio::test]
+async fn visit_all() -> Result<()> {
+    let fs = fixture().await?;
+    let mut paths = Vec::new();
+
+    assert!(
+        fs.visit(|path, _| {
+            paths.push(path.to_path_buf());
+            Flow::Continue
+        })
+        .await
+    );
+    assert_eq!(
+        paths,
+        ["/a", "/a/b", "/a/b/c", "/a/d", "/e", "/e/f", "/g"]
+            .map(PathBuf::from)
+            .to_vec()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn visit_skip_subtree() -> Result<()> {
+    let fs = fixture().await?;
+    let mut paths = Vec::new();
+
+    fs.visit(|path, _| {
+        paths.push(path.to_path_buf());
+
+        match path.starts_with("/a") {
+            true => Flow::SkipSubtree,
+            _ => Flow::Continue,
+        }
+    })
+    .await;
+
+    assert_eq!(
+        paths,
+        ["/a", "/e", "/e/f", "/g"].map(PathBuf::from).to_vec()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn visit_stop() -> Result<()> {
+    let fs = fixture().await?;
+    let mut found = None;
+
+    assert!(
+        !fs.visit(|path, _| match path.ends_with("f") {
+            true => {
+                found = Some(path.to_path_buf());
+                Flow::Stop
+            }
+            _ => Flow::Continue,
+        })
+        .await
+    );
+    assert_eq!(found, Some(PathBuf::from("/e/f")));
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use futures::TryStreamExt;
+use memfs::{
+    directory::{
+        GetExt,
+        Walk,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn walk_parallel() -> Result<()> {
+    let fs = FileSystem::<(), ()>::new();
+
+    fs.get_file_default("/a/b/c").await?;
+    fs.get_file_default("/a/d").await?;
+    fs.get_file_default("/e/f").await?;
+    fs.get_file_default("/g").await?;
+
+    for concurrency in [0, 1, 4] {
+        let mut paths = fs
+            .walk_parallel(concurrency)
+            .map_ok(|(path, _)| path)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        paths.sort();
+
+        assert_eq!(
+            paths,
+            ["/a", "/a/b", "/a/b/c", "/a/d", "/e", "/e/f", "/g"]
+                .map(PathBuf::from)
+                .to_vec()
+        );
+    }
+
+    Ok(())
+}
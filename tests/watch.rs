@@ -0,0 +1,112 @@
+use std::{
+    path::PathBuf,
+    time::Duration,
+};
+
+use anyhow::Result;
+use futures::StreamExt;
+use memfs::{
+    directory::{
+        Attach,
+        GetExt,
+    },
+    file_system::{
+        Change,
+        Debounce,
+        Event,
+    },
+    node::DataExt,
+    FileSystem,
+};
+use tokio::time;
+
+fn change(kind: Event, path: &str) -> Change {
+    Change {
+        kind,
+        path: PathBuf::from(path),
+    }
+}
+
+#[tokio::test]
+async fn watch() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let mut changes = fs.watch();
+
+    fs.get_file_default("/a")
+        .await?
+        .write(|mut value| *value = 1)
+        .await?;
+    fs.detach("a").await?;
+
+    assert_eq!(changes.next().await, Some(change(Event::Create, "/a")));
+    assert_eq!(changes.next().await, Some(change(Event::Write, "/a")));
+    assert_eq!(changes.next().await, Some(change(Event::Remove, "/a")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn debounce() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let mut changes = fs.watch().debounce(Duration::from_millis(50), time::sleep);
+
+    let a = fs.get_file_default("/a").await?;
+
+    for i in 0..5 {
+        a.write(|mut value| *value = i).await?;
+    }
+
+    let b = fs.get_file_default("/b").await?;
+
+    assert_eq!(changes.next().await, Some(change(Event::Create, "/a")));
+    assert_eq!(changes.next().await, Some(change(Event::Create, "/b")));
+
+    b.write(|mut value| *value = 1).await?;
+    b.write(|mut value| *value = 2).await?;
+
+    assert_eq!(changes.next().await, Some(change(Event::Write, "/b")));
+
+    fs.get_file_default("/c").await?;
+    fs.detach("c").await?;
+    a.write(|mut value| *value = 9).await?;
+
+    assert_eq!(changes.next().await, Some(change(Event::Write, "/a")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn debounce_per_path() -> Result<()> {
+    let fs = FileSystem::<(), u32>::new();
+    let changes = fs.watch().debounce(Duration::from_millis(50), time::sleep);
+    let changes = tokio::spawn(changes.take(2).collect::<Vec<_>>());
+
+    let a = fs.get_file_default("/a").await?;
+
+    fs.get_file_default("/b").await?;
+
+    for i in 0..4 {
+        time::sleep(Duration::from_millis(30)).await;
+        a.write(|mut value| *value = i).await?;
+    }
+
+    assert_eq!(changes.await?, [change(Event::Create, "/b"), change(Event::Create, "/a")]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn debounce_flushes_on_end() {
+    let changes = futures::stream::iter([
+        change(Event::Write, "/a"),
+        change(Event::Write, "/b"),
+        change(Event::Remove, "/a"),
+    ]);
+
+    let changes = changes
+        .debounce(Duration::from_secs(60), time::sleep)
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(changes, [change(Event::Remove, "/a"), change(Event::Write, "/b")]);
+}
@@ -0,0 +1,136 @@
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::GetExt,
+    file_system::{
+        Action,
+        Decision,
+        Options,
+        Record,
+    },
+    node::{
+        Permissions,
+        WriteError,
+        Xattrs,
+    },
+    FileSystem,
+};
+
+#[tokio::test]
+async fn xattrs() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::new();
+    let file = fs.get_file_default("/file").await?;
+
+    assert_eq!(file.set_xattr("user.b", b"b".to_vec()).await?, None);
+    assert_eq!(file.set_xattr("user.a", b"a".to_vec()).await?, None);
+    assert_eq!(
+        file.set_xattr("user.a", b"c".to_vec()).await?,
+        Some(b"a".to_vec())
+    );
+    assert_eq!(file.get_xattr("user.a").await, Some(b"c".to_vec()));
+    assert_eq!(file.list_xattrs().await, vec!["user.a", "user.b"]);
+    assert_eq!(file.remove_xattr("user.b").await?, Some(b"b".to_vec()));
+    assert_eq!(file.get_xattr("user.b").await, None);
+    assert!(fs.list_xattrs().await.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn xattrs_denied() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::new();
+    let file = fs.get_file_default("/file").await?;
+    let dir = fs.get_dir_default("/dir").await?;
+
+    file.set_readonly(true).await;
+
+    assert!(matches!(
+        file.set_xattr("user.a", b"a".to_vec()).await,
+        Err(WriteError::PermissionDenied)
+    ));
+
+    fs.intercept(|action, path| async move {
+        match (action, path.ends_with("dir")) {
+            (Action::Write, true) => Decision::Deny,
+            _ => Decision::Allow,
+        }
+    });
+
+    assert!(matches!(
+        dir.set_xattr("user.a", b"a".to_vec()).await,
+        Err(WriteError::AccessDenied)
+    ));
+    assert!(file.list_xattrs().await.is_empty());
+    assert!(dir.list_xattrs().await.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn xattrs_journal() -> Result<()> {
+    let records = Arc::new(Mutex::new(Vec::<Record<u32, u32>>::new()));
+    let fs = FileSystem::with_journal(Options::default(), records.clone());
+    let file = fs.get_file_default("/file").await?;
+
+    file.set_xattr("user.a", b"a".to_vec()).await?;
+    file.remove_xattr("user.a").await?;
+
+    let records = records
+        .lock()
+        .map(|records| records.clone())
+        .unwrap_or_default();
+
+    assert_eq!(
+        records[1..],
+        [
+            Record::SetXattr {
+                path: PathBuf::from("/file"),
+                name: String::from("user.a"),
+                value: Some(b"a".to_vec()),
+            },
+            Record::SetXattr {
+                path: PathBuf::from("/file"),
+                name: String::from("user.a"),
+                value: None,
+            },
+        ]
+    );
+
+    let replayed = FileSystem::<u32, u32>::replay(records[..2].to_vec()).await?;
+
+    assert_eq!(
+        replayed
+            .get_file_default("/file")
+            .await?
+            .get_xattr("user.a")
+            .await,
+        Some(b"a".to_vec())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn xattrs_undo() -> Result<()> {
+    let fs: FileSystem<(), ()> = FileSystem::with_options(Options::default().undo(16));
+    let file = fs.get_file_default("/file").await?;
+
+    file.set_xattr("user.a", b"a".to_vec()).await?;
+    file.set_xattr("user.a", b"b".to_vec()).await?;
+
+    assert!(fs.undo().await?);
+    assert_eq!(file.get_xattr("user.a").await, Some(b"a".to_vec()));
+    assert!(fs.undo().await?);
+    assert_eq!(file.get_xattr("user.a").await, None);
+    assert!(fs.redo().await?);
+    assert_eq!(file.get_xattr("user.a").await, Some(b"a".to_vec()));
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "zip")]
+
+use std::io::{
+    Cursor,
+    Write,
+};
+
+use anyhow::Result;
+use memfs::{
+    directory::{
+        Count,
+        ExportZip,
+        GetExt,
+        ZipError,
+    },
+    node::DataExt,
+    FileSystem,
+};
+use zip::{
+    write::SimpleFileOptions,
+    ZipWriter,
+};
+
+#[tokio::test]
+async fn zip_round_trip() -> Result<()> {
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+
+    fs.get_dir_default("/empty").await?;
+    fs.get_file_default("/a/b/file")
+        .await?
+        .write(|mut value| *value = b"content".to_vec())
+        .await?;
+
+    let archive = fs.export_zip(Cursor::new(Vec::new())).await?;
+    let imported: FileSystem<(), Vec<u8>> = FileSystem::from_zip(archive).await?;
+
+    assert_eq!(imported.count().await?, 2);
+    assert!(imported.get_dir("/empty").await?.is_some());
+
+    let file = imported.get_file("/a/b/file").await?;
+    let value = match file {
+        Some(file) => file.read(|value| value.clone()).await,
+        _ => panic!("expected imported file"),
+    };
+
+    assert_eq!(value, b"content");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn zip_non_default() -> Result<()> {
+    #[derive(Debug)]
+    struct Payload(Vec<u8>);
+
+    impl From<Vec<u8>> for Payload {
+        fn from(bytes: Vec<u8>) -> Self {
+            Self(bytes)
+        }
+    }
+
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+
+    fs.get_file_or_create_with("/a/file", || b"content".to_vec())
+        .await?;
+
+    let archive = fs.export_zip(Cursor::new(Vec::new())).await?;
+    let imported: FileSystem<(), Payload> = FileSystem::from_zip(archive).await?;
+
+    let value = match imported.get_file("/a/file").await? {
+        Some(file) => file.read(|value| value.0.clone()).await,
+        _ => panic!("expected imported file"),
+    };
+
+    assert_eq!(value, b"content");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn zip_non_default_dirs() -> Result<()> {
+    #[derive(Debug)]
+    struct Handle(u32);
+
+    let fs: FileSystem<(), Vec<u8>> = FileSystem::new();
+
+    fs.get_file_or_create_with("/a/b/file", || b"content".to_vec())
+        .await?;
+
+    let archive = fs.export_zip(Cursor::new(Vec::new())).await?;
+    let imported: FileSystem<Handle, Vec<u8>> =
+        FileSystem::from_zip_with(archive, || Handle(1)).await?;
+
+    let value = match imported.get_dir("/a/b").await? {
+        Some(dir) => dir.read(|value| value.0).await,
+        _ => panic!("expected imported directory"),
+    };
+
+    assert_eq!(imported.read(|value| value.0).await, 1);
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn zip_duplicate() -> Result<()> {
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+
+    writer.start_file("a/file", SimpleFileOptions::default())?;
+    writer.write_all(b"first")?;
+    writer.start_file("a//file", SimpleFileOptions::default())?;
+    writer.write_all(b"second")?;
+
+    let archive = writer.finish()?;
+    let imported = FileSystem::<(), Vec<u8>>::from_zip(archive).await;
+
+    assert!(matches!(imported, Err(ZipError::Duplicate(name)) if name == "a//file"));
+
+    Ok(())
+}